@@ -0,0 +1,231 @@
+//!
+//! Decision whether an AGV accepts a received order, following the order acceptance rules of the specification.
+//!
+use alloc::format;
+
+use crate::checked::Raw;
+use crate::deviation::allowed_deviation;
+use crate::order::{Node, Order};
+use crate::state::{ActionStatus, Error, State};
+
+/// Decision of an AGV about a received order.
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum Decision {
+    /// The order is a new order or an update of the current order and is to be executed.
+    Accept,
+    /// The order has the same `orderId` and `orderUpdateId` as the current order, i.e. it has been resent, and is to be
+    /// discarded.
+    Discard,
+    /// The order is to be rejected and the error is to be reported in the state.
+    Reject(Error),
+}
+
+/// Decides whether an AGV in `current_state` accepts `new_order`.
+///
+/// An order with the `orderId` of the current order
+/// - is rejected with an `orderUpdateError` if its `orderUpdateId` is lower than the current one,
+/// - is discarded if its `orderUpdateId` equals the current one,
+/// - is rejected with an `orderUpdateError` if its first node does not match the last base node of the current
+///   order, which is the last released node state or the last node if all nodes have been traversed,
+/// - is accepted otherwise.
+///
+/// An order with a different `orderId`
+/// - is rejected with an `orderError` if the AGV is still executing the current order, i.e. there are node states
+///   left or actions which are neither finished nor failed,
+/// - is rejected with a `noRouteError` if its first node neither is the last node of the AGV nor lies on the same map
+///   within the allowed deviation of the node, or the deviation range of the AGV if the deviation of the node is zero
+///   or not defined,
+/// - is accepted otherwise.
+pub fn check(current_state: &State, new_order: &Order) -> Decision {
+    let Some(first) = new_order.nodes.iter().min_by_key(|node| node.sequence_id) else {
+        return Decision::Reject(Error::order_error(new_order, "order does not contain any node"));
+    };
+    if !current_state.order_id.is_empty() && current_state.order_id == new_order.order_id {
+        if new_order.order_update_id < current_state.order_update_id {
+            return Decision::Reject(Error::order_update_error(new_order, format!(
+                "order update {} is older than the current order update {}", new_order.order_update_id, current_state.order_update_id)));
+        }
+        if new_order.order_update_id == current_state.order_update_id {
+            return Decision::Discard;
+        }
+        let (node_id, sequence_id) = current_state.node_states.iter()
+            .filter(|node| node.released)
+            .max_by_key(|node| node.sequence_id)
+            .map_or((&current_state.last_node_id, current_state.last_node_sequence_id), |node| (&node.node_id, node.sequence_id));
+        if first.node_id != *node_id || first.sequence_id != sequence_id {
+            return Decision::Reject(Error::order_update_error(new_order, format!(
+                "first node `{}` ({}) does not match the end of the current base `{node_id}` ({sequence_id})", first.node_id, first.sequence_id)));
+        }
+        return Decision::Accept;
+    }
+
+    let executing = !current_state.node_states.is_empty()
+        || current_state.action_states.iter().any(|action| !matches!(action.action_status, ActionStatus::Finished | ActionStatus::Failed));
+    if executing {
+        return Decision::Reject(Error::order_error(new_order, format!("order `{}` is still being executed", current_state.order_id)));
+    }
+    if first.node_id != current_state.last_node_id && !is_within_reach(current_state, first) {
+        return Decision::Reject(Error::no_route_error(new_order, format!("first node `{}` is not within reach of the AGV", first.node_id)));
+    }
+    Decision::Accept
+}
+
+/// Returns `true` if the AGV is located on the same map as `node` within its allowed deviation, where an allowed
+/// deviation of zero or none means that the deviation range of the AGV applies, see
+/// [`NodePosition::contains_within`](crate::common::NodePosition::contains_within).
+fn is_within_reach(state: &State, node: &Node) -> bool {
+    let (Some(agv_position), Some(node_position)) = (&state.agv_position, &node.node_position) else {
+        return false;
+    };
+    let tolerance = agv_position.deviation_range.map_or(0.0, Raw::raw);
+    let deviation = allowed_deviation(node_position.allowed_deviation_xy.map(Raw::raw), tolerance);
+    let (dx, dy) = (node_position.x.raw() - agv_position.x.raw(), node_position.y.raw() - agv_position.y.raw());
+    agv_position.map_id == node_position.map_id && dx * dx + dy * dy <= deviation * deviation
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::common::{AgvPosition, NodePosition};
+    use crate::error::StandardErrorType;
+    use crate::order::{Node, Order};
+    use crate::state::{ActionState, ActionStatus, BatteryState, EStop, NodeState, OperatingMode, SafetyState, State};
+    use crate::timestamp::from_unix_millis;
+    use super::{check, Decision};
+
+    fn node(node_id: &str, sequence_id: u64, position: Option<(f32, f32)>) -> Node {
+        Node {
+            node_id: node_id.into(),
+            sequence_id,
+            node_description: None,
+            released: true,
+            node_position: position.map(|(x, y)| NodePosition {
+                x: checked(x),
+                y: checked(y),
+                theta: None,
+                allowed_deviation_xy: Some(checked(0.5)),
+                allowed_deviation_theta: None,
+                map_id: "map".into(),
+                map_description: None,
+            }),
+            actions: vec![],
+        }
+    }
+
+    fn order(order_id: &str, order_update_id: u64, first: Node) -> Order {
+        Order {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            order_id: order_id.into(),
+            order_update_id,
+            zone_set_id: None,
+            nodes: vec![first],
+            edges: vec![],
+        }
+    }
+
+    fn state(order_id: &str, order_update_id: u64, node_states: &[(&str, u64, bool)], action_status: Option<ActionStatus>) -> State {
+        State {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            order_id: order_id.into(),
+            order_update_id,
+            zone_set_id: None,
+            last_node_id: "n1".into(),
+            last_node_sequence_id: 2,
+            driving: false,
+            paused: None,
+            new_base_request: None,
+            distance_since_last_node: None,
+            operating_mode: OperatingMode::Automatic,
+            node_states: node_states.iter().map(|(node_id, sequence_id, released)| NodeState {
+                node_id: (*node_id).into(),
+                sequence_id: *sequence_id,
+                node_description: None,
+                node_position: None,
+                released: *released,
+            }).collect(),
+            edge_states: vec![],
+            agv_position: Some(AgvPosition {
+                x: checked(1.0),
+                y: checked(1.0),
+                theta: checked(0.0),
+                map_id: "map".into(),
+                map_description: None,
+                position_initialized: true,
+                localization_score: None,
+                deviation_range: None,
+            }),
+            velocity: None,
+            loads: vec![],
+            action_states: action_status.into_iter().map(|action_status| ActionState {
+                action_id: "a1".into(),
+                action_type: None,
+                action_description: None,
+                action_status,
+                result_description: None,
+            }).collect(),
+            battery_state: BatteryState { battery_charge: checked(80.0), battery_voltage: None, battery_health: None, charging: false, reach: None },
+            errors: vec![],
+            information: vec![],
+            safety_state: SafetyState { e_stop: EStop::None, field_violation: false },
+        }
+    }
+
+    #[rstest]
+    #[case::update_stitching_base(state("o1", 1, &[("n3", 4, true), ("n4", 6, false)], None), order("o1", 2, node("n3", 4, None)))]
+    #[case::update_after_base_traversed(state("o1", 1, &[], None), order("o1", 2, node("n1", 2, None)))]
+    #[case::new_order_at_last_node(state("o1", 1, &[], Some(ActionStatus::Finished)), order("o2", 0, node("n1", 0, None)))]
+    #[case::new_order_within_reach(state("", 0, &[], None), order("o2", 0, node("n9", 0, Some((1.3, 1.3)))))]
+    fn test_check_accepts(#[case] state: State, #[case] order: Order) {
+        assert_that!(check(&state, &order), matches_pattern!(Decision::Accept));
+    }
+
+    #[rstest]
+    #[case::normal_tolerance(Some(0.0), Some(0.5), (1.3, 1.0), true)]
+    #[case::normal_tolerance_exceeded(Some(0.0), Some(0.5), (1.6, 1.0), false)]
+    #[case::zero_without_deviation_range(Some(0.0), None, (1.001, 1.0), false)]
+    #[case::absent_at_node(None, None, (1.0, 1.0), true)]
+    #[case::absent_off_node(None, None, (1.001, 1.0), false)]
+    fn test_check_falls_back_to_deviation_range(#[case] allowed_deviation_xy: Option<f32>, #[case] deviation_range: Option<f32>, #[case] position: (f32, f32), #[case] accepted: bool) {
+        let mut state = state("", 0, &[], None);
+        state.agv_position.as_mut().unwrap().deviation_range = deviation_range.map(checked);
+        let mut order = order("o2", 0, node("n9", 0, Some(position)));
+        order.nodes[0].node_position.as_mut().unwrap().allowed_deviation_xy = allowed_deviation_xy.map(checked);
+
+        assert_that!(matches!(check(&state, &order), Decision::Accept), eq(accepted));
+    }
+
+    #[rstest]
+    fn test_check_discards_resent_order() {
+        assert_that!(check(&state("o1", 1, &[], None), &order("o1", 1, node("n1", 2, None))), matches_pattern!(Decision::Discard));
+    }
+
+    #[rstest]
+    #[case::outdated_update(state("o1", 2, &[], None), order("o1", 1, node("n1", 2, None)), StandardErrorType::OrderUpdateError)]
+    #[case::update_not_stitching(state("o1", 1, &[("n3", 4, true)], None), order("o1", 2, node("n1", 2, None)), StandardErrorType::OrderUpdateError)]
+    #[case::still_driving(state("o1", 1, &[("n3", 4, true)], None), order("o2", 0, node("n1", 0, None)), StandardErrorType::OrderError)]
+    #[case::actions_running(state("o1", 1, &[], Some(ActionStatus::Running)), order("o2", 0, node("n1", 0, None)), StandardErrorType::OrderError)]
+    #[case::out_of_reach(state("o1", 1, &[], None), order("o2", 0, node("n9", 0, Some((2.0, 1.0)))), StandardErrorType::NoRouteError)]
+    #[case::without_position(state("o1", 1, &[], None), order("o2", 0, node("n9", 0, None)), StandardErrorType::NoRouteError)]
+    fn test_check_rejects(#[case] state: State, #[case] order: Order, #[case] error_type: StandardErrorType) {
+        let decision = check(&state, &order);
+
+        assert_that!(decision, matches_pattern!(Decision::Reject(matches_pattern!(crate::state::Error {
+            error_type: eq(error_type.as_str()),
+            error_references: contains(matches_pattern!(crate::state::ErrorReference { reference_key: eq("orderId"), reference_value: eq(order.order_id.as_str()) })),
+        }))));
+    }
+}
@@ -1,9 +1,18 @@
+#[cfg(feature = "serde")]
 use alloc::borrow::ToOwned;
 use alloc::string::String;
 use alloc::vec::Vec;
 
+use crate::finite::Double;
+use crate::id::ActionId;
+#[cfg(feature = "serde")]
+use crate::finite::RawDouble;
+
 /// Node Action Object
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -12,7 +21,7 @@ pub struct Action {
     ///  Name of action as described in the first column of "Actions and Parameters" Identifies the function of the action.
     pub action_type: String,
     ///  ID to distinguish between multiple actions, either instant or with the same type on the same node/edge.
-    pub action_id: String,
+    pub action_id: ActionId,
     ///  Additional information on the action.
     pub action_description: Option<String>,
     ///  Regulates if the action is allowed to be executed during movement and/or parallel to other actions.
@@ -22,7 +31,12 @@ pub struct Action {
 }
 
 /// Regulates if the action is allowed to be executed during movement and/or parallel to other actions.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+///
+/// Blocking types are ordered by their restrictiveness, i.e. `NONE` < `SOFT` < `HARD`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -36,8 +50,24 @@ pub enum BlockingType {
     Hard
 }
 
+impl BlockingType {
+
+    /// Returns the more restrictive of both blocking types, which applies while actions of both types are running.
+    pub fn combine(self, other: BlockingType) -> BlockingType {
+        self.max(other)
+    }
+
+    /// Returns the most restrictive of the blocking types, or `NONE` if there are none, e.g. of all running actions.
+    pub fn combine_all(blocking_types: impl IntoIterator<Item = BlockingType>) -> BlockingType {
+        blocking_types.into_iter().fold(BlockingType::None, BlockingType::combine)
+    }
+}
+
 /// ActionParameter Object
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -50,7 +80,10 @@ pub struct ActionParameter {
     pub value: ActionParameterValue
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(untagged)
@@ -59,8 +92,10 @@ pub struct ActionParameter {
 pub enum ActionParameterValue {
     Null,
     Boolean(bool),
+    #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
     Integer(i64),
-    Float(f64),
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize"))]
+    Float(Double),
     String(String),
 }
 
@@ -119,7 +154,10 @@ where
         }
 
         fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<Self::Value, E> {
-            Ok(ActionParameterValue::Float(value))
+            match Double::try_from_raw(value) {
+                Ok(float) if value.is_finite() || !cfg!(feature = "strict-floats") => Ok(ActionParameterValue::Float(float)),
+                _ => Err(E::invalid_value(serde::de::Unexpected::Float(value), &"a finite floating point number")),
+            }
         }
 
         fn visit_char<E: serde::de::Error>(self, value: char) -> Result<Self::Value, E> {
@@ -149,11 +187,32 @@ where
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod tests {
+    #[cfg(feature = "serde")]
     use alloc::string::String;
+    #[cfg(feature = "serde")]
     use super::{ActionParameter, ActionParameterValue};
+    use super::BlockingType;
+    #[cfg(feature = "serde")]
+    use crate::finite::double;
     use googletest::prelude::*;
     use rstest::rstest;
 
+    #[rstest]
+    #[case::none(BlockingType::None, BlockingType::None, BlockingType::None)]
+    #[case::soft(BlockingType::None, BlockingType::Soft, BlockingType::Soft)]
+    #[case::hard(BlockingType::Hard, BlockingType::Soft, BlockingType::Hard)]
+    fn test_BlockingType_combine(#[case] first: BlockingType, #[case] second: BlockingType, #[case] expected: BlockingType) {
+        assert_that!(first.combine(second), eq(expected));
+        assert_that!(second.combine(first), eq(expected));
+    }
+
+    #[rstest]
+    fn test_BlockingType_combine_all() {
+        assert_that!(BlockingType::combine_all([]), eq(BlockingType::None));
+        assert_that!(BlockingType::combine_all([BlockingType::Soft, BlockingType::None]), eq(BlockingType::Soft));
+        assert_that!(BlockingType::None < BlockingType::Soft && BlockingType::Soft < BlockingType::Hard, eq(true));
+    }
+
     #[cfg(feature = "serde")]
     #[rstest]
     fn test_serde_ActionParameter_with_null_value() {
@@ -166,7 +225,7 @@ mod tests {
         let json = r#"{"key":"my-null","value":null}"#;
 
         let to = serde_json::to_string(&parameter);
-        let from = serde_json::from_str::<ActionParameter>(&json);
+        let from = serde_json::from_str::<ActionParameter>(json);
 
         assert_that!(to, ok(eq(json)));
 
@@ -190,7 +249,7 @@ mod tests {
         let json = r#"{"key":"my-bool","value":true}"#;
 
         let to = serde_json::to_string(&parameter);
-        let from = serde_json::from_str::<ActionParameter>(&json);
+        let from = serde_json::from_str::<ActionParameter>(json);
 
         assert_that!(to, ok(eq(json)));
 
@@ -214,7 +273,7 @@ mod tests {
         let json = r#"{"key":"my-integer","value":42}"#;
 
         let to = serde_json::to_string(&parameter);
-        let from = serde_json::from_str::<ActionParameter>(&json);
+        let from = serde_json::from_str::<ActionParameter>(json);
 
         assert_that!(to, ok(eq(json)));
 
@@ -232,20 +291,20 @@ mod tests {
 
         let parameter = ActionParameter {
             key: String::from("my-float"),
-            value: ActionParameterValue::Float(42.73),
+            value: ActionParameterValue::Float(double(42.73)),
         };
 
         let json = r#"{"key":"my-float","value":42.73}"#;
 
         let to = serde_json::to_string(&parameter);
-        let from = serde_json::from_str::<ActionParameter>(&json);
+        let from = serde_json::from_str::<ActionParameter>(json);
 
         assert_that!(to, ok(eq(json)));
 
         assert_that!(from, ok(matches_pattern!(
             ActionParameter {
                 key: eq("my-float"),
-                value: eq(&ActionParameterValue::Float(42.73))
+                value: eq(&ActionParameterValue::Float(double(42.73)))
             }
         )));
     }
@@ -262,7 +321,7 @@ mod tests {
         let json = r#"{"key":"my-string","value":"Hello World"}"#;
 
         let to = serde_json::to_string(&parameter);
-        let from = serde_json::from_str::<ActionParameter>(&json);
+        let from = serde_json::from_str::<ActionParameter>(json);
 
         assert_that!(to, ok(eq(json)));
 
@@ -0,0 +1,219 @@
+//!
+//! Normalization of angles into the range `[-π, π]` required by the specification, differences between angles and
+//! conversions between degrees and radians.
+//!
+use core::f32::consts::{PI, TAU};
+
+use crate::checked::{NormalizedAngle, Raw};
+use crate::common::{AgvPosition, ControlPoint, NodePosition};
+use crate::finite::Finite;
+use crate::order::Edge;
+
+/// Returns the angle equivalent to `angle` within `[-π, π]`. Non-finite angles are returned unchanged.
+pub fn normalize(angle: f32) -> f32 {
+    if !angle.is_finite() || (-PI..=PI).contains(&angle) {
+        return angle;
+    }
+    // Truncating cast instead of `f32::rem_euclid`, which is not available in `core`.
+    let angle = angle - TAU * (angle / TAU) as i64 as f32;
+    if angle > PI {
+        angle - TAU
+    }
+    else if angle < -PI {
+        angle + TAU
+    }
+    else {
+        angle
+    }
+}
+
+/// Returns the angle within `[-π, π]` to rotate by from `b` to `a`, i.e. the signed difference `a - b` taking the
+/// wrap-around at `±π` into account.
+pub fn diff(a: f32, b: f32) -> f32 {
+    normalize(a - b)
+}
+
+/// Returns the angle `degrees` in radians.
+pub fn to_radians(degrees: f32) -> f32 {
+    degrees.to_radians()
+}
+
+/// Returns the angle `radians` in degrees.
+pub fn to_degrees(radians: f32) -> f32 {
+    radians.to_degrees()
+}
+
+/// Angle in radians, which is not necessarily normalized, e.g. the orientation of an AGV or a difference between two
+/// orientations.
+///
+/// All orientation fields, i.e. `theta` and `orientation`, can be converted into radians regardless of the features
+/// `checked-types` and `finite-floats`.
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct Radians(pub f32);
+
+impl Radians {
+
+    /// Returns the angle `degrees` in radians.
+    pub fn from_degrees(degrees: f32) -> Self {
+        Radians(to_radians(degrees))
+    }
+
+    /// Returns the angle in degrees.
+    pub fn to_degrees(self) -> f32 {
+        to_degrees(self.0)
+    }
+
+    /// Returns the equivalent angle within `[-π, π]`, see [`normalize`].
+    pub fn normalized(self) -> Self {
+        Radians(normalize(self.0))
+    }
+
+    /// Returns the angle within `[-π, π]` to rotate by from `other` to this angle, see [`diff`].
+    pub fn diff(self, other: Radians) -> Self {
+        Radians(diff(self.0, other.0))
+    }
+}
+
+impl From<f32> for Radians {
+    fn from(radians: f32) -> Self {
+        Radians(radians)
+    }
+}
+
+impl From<Finite<f32>> for Radians {
+    fn from(radians: Finite<f32>) -> Self {
+        Radians(radians.raw())
+    }
+}
+
+impl From<NormalizedAngle> for Radians {
+    fn from(radians: NormalizedAngle) -> Self {
+        Radians(radians.raw())
+    }
+}
+
+impl From<Radians> for f32 {
+    fn from(radians: Radians) -> Self {
+        radians.0
+    }
+}
+
+/// Normalizes an angle field, which is already normalized if the feature `checked-types` is enabled.
+fn normalize_field<T: Raw>(angle: T) -> T {
+    T::try_from_raw(normalize(angle.raw())).unwrap_or(angle)
+}
+
+impl AgvPosition {
+
+    /// Normalizes `theta` into `[-π, π]`.
+    pub fn normalize_angles(&mut self) {
+        self.theta = normalize_field(self.theta);
+    }
+}
+
+impl NodePosition {
+
+    /// Normalizes `theta` into `[-π, π]`.
+    pub fn normalize_angles(&mut self) {
+        self.theta = self.theta.map(normalize_field);
+    }
+}
+
+impl ControlPoint {
+
+    /// Normalizes `orientation` into `[-π, π]`.
+    pub fn normalize_angles(&mut self) {
+        self.orientation = self.orientation.map(normalize_field);
+    }
+}
+
+impl Edge {
+
+    /// Normalizes `orientation` and the orientations of the control points of the trajectory into `[-π, π]`.
+    pub fn normalize_angles(&mut self) {
+        self.orientation = self.orientation.map(normalize_field);
+        if let Some(trajectory) = &mut self.trajectory {
+            trajectory.control_points.iter_mut().for_each(ControlPoint::normalize_angles);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::f32::consts::{FRAC_PI_2, PI, TAU};
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    #[cfg(not(feature = "checked-types"))]
+    use crate::checked::Raw;
+    use super::{diff, normalize, to_degrees, Radians};
+
+    #[rstest]
+    #[case(0.0, 0.0)]
+    #[case(PI, PI)]
+    #[case(-PI, -PI)]
+    #[case(3.0 * FRAC_PI_2, -FRAC_PI_2)]
+    #[case(-3.0 * FRAC_PI_2, FRAC_PI_2)]
+    #[case(5.0 * TAU + 1.0, 1.0)]
+    #[case(-5.0 * TAU - 1.0, -1.0)]
+    fn test_normalize(#[case] angle: f32, #[case] expected: f32) {
+        assert_that!(normalize(angle), near(expected, 1e-4));
+    }
+
+    #[rstest]
+    fn test_normalize_keeps_non_finite_angles() {
+        assert_that!(normalize(f32::NAN).is_nan(), eq(true));
+        assert_that!(normalize(f32::INFINITY), eq(f32::INFINITY));
+    }
+
+    #[rstest]
+    #[case(1.0, 0.5, 0.5)]
+    #[case(0.5, 1.0, -0.5)]
+    #[case(-3.0, 3.0, TAU - 6.0)]
+    #[case(3.0, -3.0, 6.0 - TAU)]
+    fn test_diff(#[case] a: f32, #[case] b: f32, #[case] expected: f32) {
+        assert_that!(diff(a, b), near(expected, 1e-6));
+    }
+
+    #[rstest]
+    #[case(180.0, PI)]
+    #[case(-90.0, -FRAC_PI_2)]
+    #[case(720.0, 2.0 * TAU)]
+    fn test_Radians_from_degrees(#[case] degrees: f32, #[case] expected: f32) {
+        let radians = Radians::from_degrees(degrees);
+
+        assert_that!(f32::from(radians), near(expected, 1e-5));
+        assert_that!(radians.to_degrees(), near(degrees, 1e-3));
+        assert_that!(to_degrees(expected), near(degrees, 1e-3));
+    }
+
+    #[rstest]
+    fn test_Radians_of_orientation_fields() {
+        let theta: crate::checked::Angle = checked(FRAC_PI_2);
+        let orientation: crate::finite::Float = checked(-FRAC_PI_2);
+
+        assert_that!(Radians::from(theta).diff(Radians::from(orientation)).normalized(), eq(Radians(PI)));
+    }
+
+    #[cfg(not(feature = "checked-types"))]
+    #[rstest]
+    fn test_NodePosition_normalize_angles() {
+        let mut position = crate::common::NodePosition {
+            x: checked(0.0),
+            y: checked(0.0),
+            theta: Some(checked(TAU)),
+            allowed_deviation_xy: None,
+            allowed_deviation_theta: Some(checked(TAU)),
+            map_id: "map".into(),
+            map_description: None,
+        };
+
+        position.normalize_angles();
+
+        assert_that!(position.theta.map(Raw::raw), some(near(0.0, 1e-6)));
+        assert_that!(position.allowed_deviation_theta, some(eq(TAU)));
+    }
+}
@@ -0,0 +1,99 @@
+//!
+//! Predicates and estimates on the battery state of an AGV.
+//!
+use core::time::Duration;
+
+use crate::checked::Raw;
+use crate::state::BatteryState;
+
+/// Policy deciding when an AGV needs to be charged, see [`BatteryState::needs_charging`].
+#[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct ChargingPolicy {
+    /// State of charge in percent below which the AGV needs to be charged.
+    pub min_charge: f32,
+    /// Estimated reach in meters below which the AGV needs to be charged, if the AGV reports its reach.
+    pub min_reach: Option<f32>,
+}
+
+/// Energy consumption of an AGV, see [`BatteryState::estimated_time_to_empty`].
+#[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct ConsumptionModel {
+    /// Capacity of a new battery in Wh.
+    pub nominal_capacity: f32,
+    /// Average power drawn from the battery in W.
+    pub power: f32,
+}
+
+impl BatteryState {
+
+    /// Returns `true` if the state of charge is below `threshold` percent.
+    pub fn is_low(&self, threshold: f32) -> bool {
+        self.battery_charge.raw() < threshold
+    }
+
+    /// Returns `true` if the AGV is not charging and either its state of charge or, if reported, its reach is below
+    /// the minimum of the `policy`.
+    pub fn needs_charging(&self, policy: &ChargingPolicy) -> bool {
+        !self.charging && (self.is_low(policy.min_charge) || matches!((self.reach, policy.min_reach), (Some(reach), Some(min_reach)) if reach.raw() < min_reach))
+    }
+
+    /// Returns the estimated time until the battery is empty if the AGV keeps drawing the power of the `model`, or
+    /// `None` if the AGV is charging or draws no power.
+    ///
+    /// The capacity of the battery is the nominal capacity reduced by its state of health, which is assumed to be 100%
+    /// unless reported.
+    pub fn estimated_time_to_empty(&self, model: &ConsumptionModel) -> Option<Duration> {
+        if self.charging || model.power <= 0.0 {
+            return None;
+        }
+        let health = self.battery_health.map_or(1.0, |health| health.min(100) as f32 / 100.0);
+        let energy = model.nominal_capacity * health * self.battery_charge.raw().clamp(0.0, 100.0) / 100.0;
+        Duration::try_from_secs_f32(energy / model.power * 3600.0).ok()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::time::Duration;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::state::BatteryState;
+    use super::{ChargingPolicy, ConsumptionModel};
+
+    fn battery_state(battery_charge: f32, battery_health: Option<u32>, charging: bool, reach: Option<f32>) -> BatteryState {
+        BatteryState { battery_charge: checked(battery_charge), battery_voltage: None, battery_health, charging, reach: reach.map(checked) }
+    }
+
+    #[rstest]
+    #[case::charged(battery_state(50.0, None, false, None), false)]
+    #[case::low_charge(battery_state(10.0, None, false, None), true)]
+    #[case::low_charge_charging(battery_state(10.0, None, true, None), false)]
+    #[case::low_reach(battery_state(50.0, None, false, Some(50.0)), true)]
+    #[case::sufficient_reach(battery_state(50.0, None, false, Some(500.0)), false)]
+    fn test_BatteryState_needs_charging(#[case] battery_state: BatteryState, #[case] expected: bool) {
+        let policy = ChargingPolicy { min_charge: 20.0, min_reach: Some(100.0) };
+
+        assert_that!(battery_state.needs_charging(&policy), eq(expected));
+    }
+
+    #[rstest]
+    fn test_BatteryState_is_low() {
+        assert_that!(battery_state(19.5, None, true, None).is_low(20.0), eq(true));
+        assert_that!(battery_state(20.0, None, false, None).is_low(20.0), eq(false));
+    }
+
+    #[rstest]
+    #[case::healthy(battery_state(50.0, None, false, None), Some(Duration::from_secs(3600)))]
+    #[case::degraded(battery_state(50.0, Some(50), false, None), Some(Duration::from_secs(1800)))]
+    #[case::charging(battery_state(50.0, None, true, None), None)]
+    fn test_BatteryState_estimated_time_to_empty(#[case] battery_state: BatteryState, #[case] expected: Option<Duration>) {
+        let model = ConsumptionModel { nominal_capacity: 2000.0, power: 1000.0 };
+
+        assert_that!(battery_state.estimated_time_to_empty(&model), eq(expected));
+    }
+}
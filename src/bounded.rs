@@ -0,0 +1,483 @@
+//!
+//! Deserialization with bounded array and string lengths.
+//!
+//! Limits are checked while parsing, thus a message exceeding them is rejected before it is completely read into memory.
+//!
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt;
+
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+
+use crate::factsheet::MaxArrayLens;
+
+/// Limits applied during deserialization.
+///
+/// ```
+/// # use vda5050_types::v2_0::bounded::Limits;
+/// # use vda5050_types::v2_0::state::State;
+/// # let json = "{}";
+/// let limits = Limits {
+///     max_array_len: Some(1000),
+///     max_string_len: Some(256),
+///     max_array_lens: None,
+/// };
+/// let state = limits.deserialize::<State, _>(&mut serde_json::Deserializer::from_str(json));
+/// ```
+#[derive(Clone, Default)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct Limits {
+    /// Maximum number of elements of any array. No limit if `None`.
+    pub max_array_len: Option<usize>,
+    /// Maximum length of any string in bytes. No limit if `None`.
+    pub max_string_len: Option<usize>,
+    /// Maximum number of elements of specific arrays, e.g. the nodes of an order, as reported by the factsheet of an AGV. A length of zero means there is no explicit limit.
+    pub max_array_lens: Option<MaxArrayLens>,
+}
+
+impl Limits {
+
+    /// Deserializes a value using the given deserializer and fails as soon as a limit is exceeded.
+    pub fn deserialize<'de, T, D>(&self, deserializer: D) -> Result<T, D::Error>
+    where
+        T: serde::Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let context = Context {
+            limits: self,
+            path: RefCell::new(Vec::new()),
+            key: RefCell::new(None),
+        };
+        T::deserialize(Bounded { inner: deserializer, context: &context, capture: false })
+    }
+
+    /// Returns the maximum number of elements of the array located at the given path of JSON field names.
+    fn max_array_len(&self, path: &[String]) -> Option<usize> {
+        let specific = self.max_array_lens.as_ref().and_then(|lens| {
+            let key = path.last()?.as_str();
+            let parent = path.len().checked_sub(2).map(|index| path[index].as_str());
+            let len = match (parent, key) {
+                (_, "nodes") => lens.order_nodes,
+                (_, "edges") => lens.order_edges,
+                (Some("nodes"), "actions") => lens.node_actions,
+                (Some("edges"), "actions") => lens.edge_actions,
+                (_, "actionParameters") => lens.actions_actions_parameters,
+                (_, "instantActions") => lens.instant_actions,
+                (_, "knotVector") => lens.trajectory_knot_vector,
+                (_, "controlPoints") => lens.trajectory_control_points,
+                (_, "nodeStates") => lens.state_node_states,
+                (_, "edgeStates") => lens.state_edge_states,
+                (_, "loads") => lens.state_loads,
+                (_, "actionStates") => lens.state_action_states,
+                (_, "errors") => lens.state_errors,
+                (_, "information") => lens.state_information,
+                (_, "errorReferences") => lens.error_error_references,
+                (_, "infoReferences") => lens.information_info_references,
+                _ => 0,
+            };
+            (len > 0).then_some(len as usize)
+        });
+        match (specific, self.max_array_len) {
+            (Some(specific), Some(general)) => Some(specific.min(general)),
+            (specific, general) => specific.or(general),
+        }
+    }
+}
+
+struct Context<'l> {
+    limits: &'l Limits,
+    /// JSON field names leading to the value being deserialized.
+    path: RefCell<Vec<String>>,
+    /// The most recently deserialized key of a map.
+    key: RefCell<Option<String>>,
+}
+
+impl Context<'_> {
+
+    fn check_string<E: de::Error>(&self, value: &str) -> Result<(), E> {
+        match self.limits.max_string_len {
+            Some(max) if value.len() > max => Err(E::custom(format!("string at `{}` exceeds the maximum length of {}", self.path(), max))),
+            _ => Ok(()),
+        }
+    }
+
+    fn path(&self) -> String {
+        self.path.borrow().join(".")
+    }
+}
+
+/// Deserializer checking the limits of a [`Context`] while delegating to an inner deserializer.
+struct Bounded<'c, 'l, D> {
+    inner: D,
+    context: &'c Context<'l>,
+    /// If `true`, the deserialized string is recorded as the current key of the context.
+    capture: bool,
+}
+
+struct BoundedVisitor<'c, 'l, V> {
+    inner: V,
+    context: &'c Context<'l>,
+    capture: bool,
+}
+
+struct BoundedSeed<'c, 'l, S> {
+    inner: S,
+    context: &'c Context<'l>,
+    capture: bool,
+}
+
+struct BoundedSeqAccess<'c, 'l, A> {
+    inner: A,
+    context: &'c Context<'l>,
+    max: Option<usize>,
+    count: usize,
+}
+
+struct BoundedMapAccess<'c, 'l, A> {
+    inner: A,
+    context: &'c Context<'l>,
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $type:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V>(self, $($arg: $type,)* visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let visitor = BoundedVisitor { inner: visitor, context: self.context, capture: self.capture };
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl <'de, D> Deserializer<'de> for Bounded<'_, '_, D>
+where
+    D: Deserializer<'de>,
+{
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+impl <'c, 'l, 'de, V> BoundedVisitor<'c, 'l, V>
+where
+    V: Visitor<'de>,
+{
+    fn checked_str<E: de::Error>(&self, value: &str) -> Result<(), E> {
+        self.context.check_string(value)?;
+        if self.capture {
+            *self.context.key.borrow_mut() = Some(value.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl <'de, V> Visitor<'de> for BoundedVisitor<'_, '_, V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        self.inner.visit_bool(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        self.inner.visit_i64(value)
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E> {
+        self.inner.visit_i128(value)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        self.inner.visit_u64(value)
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+        self.inner.visit_u128(value)
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        self.inner.visit_f64(value)
+    }
+
+    fn visit_char<E: de::Error>(self, value: char) -> Result<Self::Value, E> {
+        self.inner.visit_char(value)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        self.checked_str(value)?;
+        self.inner.visit_str(value)
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<Self::Value, E> {
+        self.checked_str(value)?;
+        self.inner.visit_borrowed_str(value)
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        self.checked_str(&value)?;
+        self.inner.visit_string(value)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        self.inner.visit_bytes(value)
+    }
+
+    fn visit_borrowed_bytes<E: de::Error>(self, value: &'de [u8]) -> Result<Self::Value, E> {
+        self.inner.visit_borrowed_bytes(value)
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        self.inner.visit_byte_buf(value)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.visit_some(Bounded { inner: deserializer, context: self.context, capture: self.capture })
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.visit_newtype_struct(Bounded { inner: deserializer, context: self.context, capture: self.capture })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let max = self.context.limits.max_array_len(&self.context.path.borrow());
+        if let (Some(max), Some(hint)) = (max, seq.size_hint()) {
+            if hint > max {
+                return Err(de::Error::custom(format!("array `{}` exceeds the maximum length of {}", self.context.path(), max)));
+            }
+        }
+        self.inner.visit_seq(BoundedSeqAccess { inner: seq, context: self.context, max, count: 0 })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_map(BoundedMapAccess { inner: map, context: self.context })
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_enum(data)
+    }
+}
+
+impl <'de, S> DeserializeSeed<'de> for BoundedSeed<'_, '_, S>
+where
+    S: DeserializeSeed<'de>,
+{
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner.deserialize(Bounded { inner: deserializer, context: self.context, capture: self.capture })
+    }
+}
+
+impl <'de, A> SeqAccess<'de> for BoundedSeqAccess<'_, '_, A>
+where
+    A: SeqAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> {
+        let element = self.inner.next_element_seed(BoundedSeed { inner: seed, context: self.context, capture: false })?;
+        if element.is_some() {
+            self.count += 1;
+            if let Some(max) = self.max.filter(|max| self.count > *max) {
+                return Err(de::Error::custom(format!("array `{}` exceeds the maximum length of {}", self.context.path(), max)));
+            }
+        }
+        Ok(element)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl <'de, A> MapAccess<'de> for BoundedMapAccess<'_, '_, A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        self.inner.next_key_seed(BoundedSeed { inner: seed, context: self.context, capture: true })
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        let key = self.context.key.borrow_mut().take().unwrap_or_default();
+        self.context.path.borrow_mut().push(key);
+        let value = self.inner.next_value_seed(BoundedSeed { inner: seed, context: self.context, capture: false });
+        self.context.path.borrow_mut().pop();
+        value
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::factsheet::MaxArrayLens;
+    use crate::instant_actions::InstantActions;
+    use crate::order::Order;
+    use super::Limits;
+
+    fn max_array_lens(order_nodes: u32, node_actions: u32) -> MaxArrayLens {
+        MaxArrayLens {
+            order_nodes,
+            order_edges: 0,
+            node_actions,
+            edge_actions: 0,
+            actions_actions_parameters: 0,
+            instant_actions: 0,
+            trajectory_knot_vector: 0,
+            trajectory_control_points: 0,
+            state_node_states: 0,
+            state_edge_states: 0,
+            state_loads: 0,
+            state_action_states: 0,
+            state_errors: 0,
+            state_information: 0,
+            error_error_references: 0,
+            information_info_references: 0,
+        }
+    }
+
+    fn node(id: usize, actions: usize) -> String {
+        let actions = (0..actions)
+            .map(|index| format!(r#"{{"actionType":"pick","actionId":"{id}-{index}","blockingType":"HARD","actionParameters":[]}}"#))
+            .collect::<std::vec::Vec<_>>()
+            .join(",");
+        format!(r#"{{"nodeId":"n{id}","sequenceId":{},"released":true,"actions":[{actions}]}}"#, 2 * id)
+    }
+
+    fn order(nodes: usize, actions: usize) -> String {
+        let nodes = (0..nodes).map(|id| node(id, actions)).collect::<std::vec::Vec<_>>().join(",");
+        format!(r#"{{"headerId":1,"timestamp":"2017-04-15T11:40:03.120Z","version":"2.0.0","manufacturer":"Fubar Co.","serialNumber":"1234","orderId":"o1","orderUpdateId":0,"nodes":[{nodes}],"edges":[]}}"#)
+    }
+
+    fn deserialize_order(limits: &Limits, json: &str) -> serde_json::Result<Order> {
+        limits.deserialize(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[rstest]
+    fn test_Limits_accepts_order_within_limits() {
+        let limits = Limits {
+            max_array_len: Some(3),
+            max_string_len: Some(24),
+            max_array_lens: Some(max_array_lens(3, 2)),
+        };
+
+        assert_that!(deserialize_order(&limits, &order(3, 2)), ok(matches_pattern!(
+            Order {
+                nodes: len(eq(3))
+            }
+        )));
+    }
+
+    #[rstest]
+    fn test_Limits_rejects_too_many_nodes() {
+        let limits = Limits {
+            max_array_lens: Some(max_array_lens(2, 0)),
+            ..Limits::default()
+        };
+
+        assert_that!(deserialize_order(&limits, &order(3, 0)), err(displays_as(contains_substring("array `nodes` exceeds the maximum length of 2"))));
+    }
+
+    #[rstest]
+    fn test_Limits_rejects_too_many_node_actions() {
+        let limits = Limits {
+            max_array_lens: Some(max_array_lens(0, 1)),
+            ..Limits::default()
+        };
+
+        assert_that!(deserialize_order(&limits, &order(1, 2)), err(displays_as(contains_substring("array `nodes.actions` exceeds the maximum length of 1"))));
+    }
+
+    #[rstest]
+    fn test_Limits_applies_general_array_limit() {
+        let limits = Limits {
+            max_array_len: Some(4),
+            ..Limits::default()
+        };
+
+        let json = format!(r#"{{"headerId":1,"timestamp":"2017-04-15T11:40:03.120Z","version":"2.0.0","manufacturer":"Fubar Co.","serialNumber":"1234","instantActions":[{}]}}"#,
+            (0..5).map(|index| format!(r#"{{"actionType":"pause","actionId":"{index}","blockingType":"HARD","actionParameters":[]}}"#)).collect::<std::vec::Vec<_>>().join(","));
+
+        let result = limits.deserialize::<InstantActions, _>(&mut serde_json::Deserializer::from_str(&json));
+
+        assert_that!(result, err(displays_as(contains_substring("array `instantActions` exceeds the maximum length of 4"))));
+    }
+
+    #[rstest]
+    fn test_Limits_rejects_too_long_string() {
+        let limits = Limits {
+            max_string_len: Some(24),
+            ..Limits::default()
+        };
+
+        let json = order(1, 0).replace(r#""orderId":"o1""#, r#""orderId":"an-order-id-exceeding-the-limit""#);
+
+        assert_that!(deserialize_order(&limits, &json), err(displays_as(contains_substring("string at `orderId` exceeds the maximum length of 24"))));
+    }
+}
@@ -0,0 +1,165 @@
+//!
+//! Axis-aligned bounding boxes of trajectories and envelopes for cheap checks before exact geometry tests.
+//!
+use crate::checked::Raw;
+use crate::common::Trajectory;
+use crate::factsheet::Envelopes2d;
+
+/// Axis-aligned rectangle in meters, e.g. around a trajectory on the map or an envelope in vehicle coordinates.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct BoundingBox {
+    /// Minimum x coordinate.
+    pub min_x: f32,
+    /// Minimum y coordinate.
+    pub min_y: f32,
+    /// Maximum x coordinate.
+    pub max_x: f32,
+    /// Maximum y coordinate.
+    pub max_y: f32,
+}
+
+impl BoundingBox {
+
+    /// Returns the smallest bounding box containing all `points`, or `None` if there are no points or a coordinate is
+    /// not finite.
+    pub fn from_points(points: impl IntoIterator<Item = (f32, f32)>) -> Option<Self> {
+        points.into_iter()
+            .map(|(x, y)| (x.is_finite() && y.is_finite()).then_some(BoundingBox { min_x: x, min_y: y, max_x: x, max_y: y }))
+            .reduce(|bounds, point| Some(bounds?.union(&point?)))
+            .flatten()
+    }
+
+    /// Returns the smallest bounding box containing this bounding box and `other`.
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    /// Returns the bounding box grown by `margin` in meters on every side, e.g. by a safety distance.
+    pub fn expanded(&self, margin: f32) -> BoundingBox {
+        BoundingBox {
+            min_x: self.min_x - margin,
+            min_y: self.min_y - margin,
+            max_x: self.max_x + margin,
+            max_y: self.max_y + margin,
+        }
+    }
+
+    /// Returns `true` if the point `(x, y)` lies within the bounding box or on its boundary.
+    pub fn contains(&self, (x, y): (f32, f32)) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    /// Returns `true` if the bounding box and `other` share at least a point of their boundaries.
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.min_x <= other.max_x && other.min_x <= self.max_x && self.min_y <= other.max_y && other.min_y <= self.max_y
+    }
+}
+
+impl Trajectory {
+
+    /// Returns a bounding box containing the trajectory, or `None` if the trajectory is malformed or has a control
+    /// point of a weight of zero or less.
+    ///
+    /// The bounding box is the one of the control points, which contains the trajectory due to the convex hull property
+    /// of NURBS, but may be larger than the trajectory, e.g. around the middle control point of an arc.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.domain()?;
+        if self.control_points.iter().any(|point| point.weight.is_some_and(|weight| weight.raw() <= 0.0)) {
+            return None;
+        }
+        BoundingBox::from_points(self.control_points.iter().map(|point| (point.x.raw(), point.y.raw())))
+    }
+}
+
+impl Envelopes2d {
+
+    /// Returns the bounding box of the polygon of the envelope in vehicle coordinates, or `None` if the polygon has no
+    /// points.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        BoundingBox::from_points(self.polygon_points.iter().map(|point| (point.x.raw(), point.y.raw())))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::common::Trajectory;
+    use crate::factsheet::{Envelopes2d, PolygonPoint};
+    use crate::trajectory::tests::{quarter_circle, trajectory};
+    use super::BoundingBox;
+
+    fn bounds(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> BoundingBox {
+        BoundingBox { min_x, min_y, max_x, max_y }
+    }
+
+    #[rstest]
+    #[case::points(vec![(1.0, 2.0), (-1.0, 4.0), (3.0, 0.0)], Some(bounds(-1.0, 0.0, 3.0, 4.0)))]
+    #[case::empty(vec![], None)]
+    #[case::not_finite(vec![(1.0, 2.0), (f32::NAN, 0.0)], None)]
+    fn test_BoundingBox_from_points(#[case] points: Vec<(f32, f32)>, #[case] expected: Option<BoundingBox>) {
+        assert_that!(BoundingBox::from_points(points), eq(expected));
+    }
+
+    #[rstest]
+    #[case::overlapping(bounds(1.0, 1.0, 3.0, 3.0), true)]
+    #[case::touching(bounds(2.0, 0.0, 3.0, 1.0), true)]
+    #[case::apart(bounds(2.5, 0.0, 3.0, 1.0), false)]
+    fn test_BoundingBox_intersects(#[case] other: BoundingBox, #[case] expected: bool) {
+        let bounding_box = bounds(0.0, 0.0, 2.0, 2.0);
+
+        assert_that!(bounding_box.intersects(&other), eq(expected));
+        assert_that!(other.intersects(&bounding_box), eq(expected));
+    }
+
+    #[rstest]
+    fn test_BoundingBox_expanded_and_contains() {
+        let expanded = bounds(0.0, 0.0, 2.0, 2.0).expanded(0.5);
+
+        assert_that!(expanded, eq(bounds(-0.5, -0.5, 2.5, 2.5)));
+        assert_that!(expanded.contains((2.5, -0.5)), eq(true));
+        assert_that!(expanded.contains((2.6, 0.0)), eq(false));
+    }
+
+    #[rstest]
+    fn test_Trajectory_bounding_box_contains_trajectory() {
+        let circle = quarter_circle();
+
+        let bounding_box = circle.bounding_box().unwrap();
+
+        assert_that!(bounding_box, eq(bounds(0.0, 0.0, 1.0, 1.0)));
+        for index in 0..=10 {
+            assert_that!(bounding_box.contains(circle.point_at(index as f32 / 10.0).unwrap()), eq(true));
+        }
+    }
+
+    #[rstest]
+    #[case::malformed(trajectory(1, vec![0.0, 1.0], &[(0.0, 0.0, None), (1.0, 1.0, None)]))]
+    #[case::non_positive_weight(trajectory(1, vec![0.0, 0.0, 1.0, 1.0], &[(0.0, 0.0, Some(0.0)), (1.0, 1.0, None)]))]
+    fn test_Trajectory_bounding_box_returns_none(#[case] trajectory: Trajectory) {
+        assert_that!(trajectory.bounding_box(), none());
+    }
+
+    #[rstest]
+    fn test_Envelopes2d_bounding_box() {
+        let envelope = |points: &[(f32, f32)]| Envelopes2d {
+            set: "default".into(),
+            polygon_points: points.iter().map(|(x, y)| PolygonPoint { x: checked(*x), y: checked(*y) }).collect(),
+            description: None,
+        };
+
+        assert_that!(envelope(&[(-0.5, -0.4), (1.2, -0.4), (1.2, 0.4), (-0.5, 0.4)]).bounding_box(), some(eq(bounds(-0.5, -0.4, 1.2, 0.4))));
+        assert_that!(envelope(&[]).bounding_box(), none());
+    }
+}
@@ -0,0 +1,1119 @@
+//!
+//! Builders of messages assigning sequence ids and references between elements automatically.
+//!
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::time::Duration;
+
+use crate::action::Action;
+use crate::checked::{Angle, Magnitude, Raw};
+use crate::common::{AgvPosition, HeaderId, NodePosition, Timestamp, Trajectory, Velocity};
+use crate::factsheet::{ActionScope, AgvAction, AgvClass, AgvGeometry, AgvKinematic, Factsheet, LoadSpecification, LocalizationType, NavigationType, PhysicalParameters, ProtocolFeatures, ProtocolLimits, Timing, TypeSpecification};
+use crate::finite::Float;
+use crate::id::{EdgeId, MapId, NodeId, OrderId, ZoneSetId};
+use crate::order::{Edge, Node, Order, OrientationType};
+use crate::state::{ActionState, BatteryState, EdgeState, Error, Information, Load, NodeState, OperatingMode, SafetyState, State};
+use crate::validation::{IdPolicy, Violation};
+
+impl Order {
+
+    /// Returns a builder of the order `order_id` for protocol version `2.0.0`, whose header id, order update id and
+    /// first sequence id are zero unless specified otherwise.
+    pub fn builder(order_id: impl Into<OrderId>, timestamp: Timestamp) -> OrderBuilder {
+        OrderBuilder {
+            order: Order {
+                header_id: 0,
+                timestamp,
+                version: "2.0.0".into(),
+                manufacturer: String::new(),
+                serial_number: String::new(),
+                order_id: order_id.into(),
+                order_update_id: 0,
+                zone_set_id: None,
+                nodes: Vec::new(),
+                edges: Vec::new(),
+            },
+            next_sequence_id: 0,
+            released: true,
+            id_policy: None,
+        }
+    }
+}
+
+/// Builder of an [`Order`] along a path of nodes and edges, see [`Order::builder`].
+///
+/// Sequence ids are assigned in the order nodes and edges are added, edges connect the previous node with the next
+/// one and all nodes and edges are released until the first horizon node.
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct OrderBuilder {
+    order: Order,
+    next_sequence_id: u64,
+    released: bool,
+    id_policy: Option<IdPolicy>,
+}
+
+impl OrderBuilder {
+
+    /// Sets the header id of the order.
+    pub fn header_id(mut self, header_id: HeaderId) -> Self {
+        self.order.header_id = header_id;
+        self
+    }
+
+    /// Sets the protocol version of the order, e.g. `2.0.0`.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.order.version = version.into();
+        self
+    }
+
+    /// Sets the manufacturer and the serial number of the AGV the order is issued to.
+    pub fn agv(mut self, manufacturer: impl Into<String>, serial_number: impl Into<String>) -> Self {
+        self.order.manufacturer = manufacturer.into();
+        self.order.serial_number = serial_number.into();
+        self
+    }
+
+    /// Sets the order update id and the sequence id of the first node, which is the last base node of the previous
+    /// order update.
+    pub fn order_update(mut self, order_update_id: u64, first_sequence_id: u64) -> Self {
+        self.order.order_update_id = order_update_id;
+        self.next_sequence_id = first_sequence_id;
+        self
+    }
+
+    /// Sets the zone set the AGV has to use.
+    pub fn zone_set_id(mut self, zone_set_id: impl Into<ZoneSetId>) -> Self {
+        self.order.zone_set_id = Some(zone_set_id.into());
+        self
+    }
+
+    /// Sets the policy the `orderId` and the ids of nodes, edges and actions have to comply with, which is checked by
+    /// [`OrderBuilder::build`], e.g. the policy of the factsheet of the AGV.
+    pub fn id_policy(mut self, id_policy: IdPolicy) -> Self {
+        self.id_policy = Some(id_policy);
+        self
+    }
+
+    /// Appends a node, which is the first node of the order, e.g. `"n1"` or a [`NodeBuilder`].
+    pub fn node(mut self, node: impl Into<NodeBuilder>) -> Self {
+        let mut node = node.into().node;
+        node.sequence_id = self.sequence_id();
+        node.released = self.released;
+        self.order.nodes.push(node);
+        self
+    }
+
+    /// Appends an edge from the last node to `node`, e.g. `("e1", "n2")` or builders of the edge and the node.
+    pub fn edge_to(mut self, edge: impl Into<EdgeBuilder>, node: impl Into<NodeBuilder>) -> Self {
+        let node = node.into();
+        let mut edge = edge.into().edge;
+        edge.sequence_id = self.sequence_id();
+        edge.released = self.released;
+        edge.start_node_id = self.order.nodes.last().map(|node| node.node_id.clone()).unwrap_or_default();
+        edge.end_node_id.clone_from(&node.node.node_id);
+        self.order.edges.push(edge);
+        self.node(node)
+    }
+
+    /// Appends an edge from the last node to `node`, both not released. All following nodes and edges belong to the
+    /// horizon as well.
+    pub fn horizon_node(mut self, edge: impl Into<EdgeBuilder>, node: impl Into<NodeBuilder>) -> Self {
+        self.released = false;
+        self.edge_to(edge, node)
+    }
+
+    /// Appends `action` to the actions of the last node.
+    pub fn node_action(mut self, action: Action) -> Self {
+        if let Some(node) = self.order.nodes.last_mut() {
+            node.actions.push(action);
+        }
+        self
+    }
+
+    /// Appends `action` to the actions of the last edge.
+    pub fn edge_action(mut self, action: Action) -> Self {
+        if let Some(edge) = self.order.edges.last_mut() {
+            edge.actions.push(action);
+        }
+        self
+    }
+
+    /// Returns the order or the violations of [`Order::validate_structure`], [`Order::validate_sequence`],
+    /// [`Order::validate_base`] and, if set, [`Order::check_ids`] with the [id policy](OrderBuilder::id_policy), e.g.
+    /// if no node was added or [`OrderBuilder::node`] was called twice in a row.
+    pub fn build(self) -> Result<Order, Vec<Violation>> {
+        let mut violations = self.order.validate_structure();
+        violations.extend(self.order.validate_sequence());
+        violations.extend(self.order.validate_base());
+        if let Some(id_policy) = &self.id_policy {
+            violations.extend(self.order.check_ids(id_policy));
+        }
+        if violations.is_empty() {
+            Ok(self.order)
+        }
+        else {
+            Err(violations)
+        }
+    }
+
+    /// Returns a builder of the path of the order, which ensures at compile time that nodes and edges alternate and
+    /// the path ends on a node. Nodes added before are ignored by the path.
+    pub fn path(self) -> OrderPath<PathStart> {
+        OrderPath { builder: self, edge: None, state: PhantomData }
+    }
+
+    fn sequence_id(&mut self) -> u64 {
+        let sequence_id = self.next_sequence_id;
+        self.next_sequence_id += 1;
+        sequence_id
+    }
+}
+
+/// State of an [`OrderPath`] without node.
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct PathStart;
+
+/// State of an [`OrderPath`] ending on a node, which can be built.
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct PathAtNode;
+
+/// State of an [`OrderPath`] ending on an edge, which has to be followed by a node.
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct PathAtEdge;
+
+/// Builder of the path of an [`Order`] whose state `S` ensures that nodes and edges alternate and the path ends on a
+/// node, see [`OrderBuilder::path`].
+///
+/// ```
+/// # use vda5050_types::v2_0::common::timestamp_from_unix_millis;
+/// # use vda5050_types::v2_0::order::Order;
+/// let order = Order::builder("o1", timestamp_from_unix_millis(0).unwrap())
+///     .path()
+///     .node("n1")
+///     .edge("e1")
+///     .node("n2")
+///     .horizon()
+///     .edge("e2")
+///     .node("n3")
+///     .build();
+/// ```
+///
+/// An edge following an edge or a path ending on an edge does not compile:
+///
+/// ```compile_fail
+/// # use vda5050_types::v2_0::common::timestamp_from_unix_millis;
+/// # use vda5050_types::v2_0::order::Order;
+/// let order = Order::builder("o1", timestamp_from_unix_millis(0).unwrap())
+///     .path()
+///     .node("n1")
+///     .edge("e1")
+///     .build();
+/// ```
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct OrderPath<S> {
+    builder: OrderBuilder,
+    edge: Option<EdgeBuilder>,
+    state: PhantomData<S>,
+}
+
+impl<S> OrderPath<S> {
+
+    fn into_state<T>(self) -> OrderPath<T> {
+        OrderPath { builder: self.builder, edge: self.edge, state: PhantomData }
+    }
+}
+
+impl OrderPath<PathStart> {
+
+    /// Appends the first node, e.g. `"n1"` or a [`NodeBuilder`].
+    pub fn node(mut self, node: impl Into<NodeBuilder>) -> OrderPath<PathAtNode> {
+        self.builder.order.nodes.clear();
+        self.builder.order.edges.clear();
+        self.builder = self.builder.node(node);
+        self.into_state()
+    }
+}
+
+impl OrderPath<PathAtNode> {
+
+    /// Appends an edge starting at the last node, e.g. `"e1"` or an [`EdgeBuilder`].
+    pub fn edge(mut self, edge: impl Into<EdgeBuilder>) -> OrderPath<PathAtEdge> {
+        self.edge = Some(edge.into());
+        self.into_state()
+    }
+
+    /// Appends `action` to the actions of the last node.
+    pub fn action(mut self, action: Action) -> Self {
+        self.builder = self.builder.node_action(action);
+        self
+    }
+
+    /// Releases none of the following nodes and edges, which belong to the horizon.
+    pub fn horizon(mut self) -> Self {
+        self.builder.released = false;
+        self
+    }
+
+    /// Returns the order, whose sequence ids are valid by construction. The [id policy](OrderBuilder::id_policy) is not
+    /// checked, see [`Order::check_ids`].
+    pub fn build(self) -> Order {
+        self.builder.order
+    }
+}
+
+impl OrderPath<PathAtEdge> {
+
+    /// Appends the node the last edge ends at, e.g. `"n2"` or a [`NodeBuilder`].
+    pub fn node(mut self, node: impl Into<NodeBuilder>) -> OrderPath<PathAtNode> {
+        let edge = self.edge.take().unwrap_or_else(|| Edge::builder(""));
+        self.builder = self.builder.edge_to(edge, node);
+        self.into_state()
+    }
+
+    /// Appends `action` to the actions of the last edge.
+    pub fn action(mut self, action: Action) -> Self {
+        self.edge = self.edge.take().map(|edge| edge.action(action));
+        self
+    }
+}
+
+impl Node {
+
+    /// Returns a builder of the node `node_id`, which is released and has neither position nor actions unless
+    /// specified otherwise.
+    pub fn builder(node_id: impl Into<NodeId>) -> NodeBuilder {
+        NodeBuilder {
+            node: Node {
+                node_id: node_id.into(),
+                sequence_id: 0,
+                node_description: None,
+                released: true,
+                node_position: None,
+                actions: Vec::new(),
+            },
+        }
+    }
+}
+
+/// Builder of a [`Node`], see [`Node::builder`].
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct NodeBuilder {
+    node: Node,
+}
+
+impl NodeBuilder {
+
+    /// Sets the sequence id of the node, which is assigned by [`OrderBuilder`] otherwise.
+    pub fn sequence_id(mut self, sequence_id: u64) -> Self {
+        self.node.sequence_id = sequence_id;
+        self
+    }
+
+    /// Sets whether the node is part of the base.
+    pub fn released(mut self, released: bool) -> Self {
+        self.node.released = released;
+        self
+    }
+
+    /// Sets the verbose description of the node.
+    pub fn description(mut self, node_description: impl Into<String>) -> Self {
+        self.node.node_description = Some(node_description.into());
+        self
+    }
+
+    /// Sets the position of the node on the map `map_id`.
+    pub fn position(mut self, x: Float, y: Float, map_id: impl Into<MapId>) -> Self {
+        self.node.node_position = Some(NodePosition {
+            x,
+            y,
+            theta: None,
+            allowed_deviation_xy: None,
+            allowed_deviation_theta: None,
+            map_id: map_id.into(),
+            map_description: None,
+        });
+        self
+    }
+
+    /// Sets the orientation the AGV has to assume on the node. Has no effect without position.
+    pub fn theta(mut self, theta: Angle) -> Self {
+        if let Some(position) = &mut self.node.node_position {
+            position.theta = Some(theta);
+        }
+        self
+    }
+
+    /// Sets the allowed deviations of the position and the orientation on the node. Has no effect without position.
+    pub fn allowed_deviation(mut self, xy: Magnitude, theta: Float) -> Self {
+        if let Some(position) = &mut self.node.node_position {
+            position.allowed_deviation_xy = Some(xy);
+            position.allowed_deviation_theta = Some(theta);
+        }
+        self
+    }
+
+    /// Appends `action` to the actions of the node.
+    pub fn action(mut self, action: Action) -> Self {
+        self.node.actions.push(action);
+        self
+    }
+
+    /// Returns the node.
+    pub fn build(self) -> Node {
+        self.node
+    }
+}
+
+impl From<&str> for NodeBuilder {
+
+    fn from(node_id: &str) -> Self {
+        Node::builder(node_id)
+    }
+}
+
+impl From<String> for NodeBuilder {
+
+    fn from(node_id: String) -> Self {
+        Node::builder(node_id)
+    }
+}
+
+impl Edge {
+
+    /// Returns a builder of the edge `edge_id`, which is released and has neither constraints nor actions unless
+    /// specified otherwise.
+    pub fn builder(edge_id: impl Into<EdgeId>) -> EdgeBuilder {
+        EdgeBuilder {
+            edge: Edge {
+                edge_id: edge_id.into(),
+                sequence_id: 0,
+                edge_description: None,
+                released: true,
+                start_node_id: NodeId::default(),
+                end_node_id: NodeId::default(),
+                max_speed: None,
+                max_height: None,
+                min_height: None,
+                orientation: None,
+                orientation_type: None,
+                direction: None,
+                rotation_allowed: None,
+                max_rotation_speed: None,
+                length: None,
+                trajectory: None,
+                actions: Vec::new(),
+            },
+        }
+    }
+}
+
+/// Builder of an [`Edge`], see [`Edge::builder`].
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct EdgeBuilder {
+    edge: Edge,
+}
+
+impl EdgeBuilder {
+
+    /// Sets the sequence id of the edge, which is assigned by [`OrderBuilder`] otherwise.
+    pub fn sequence_id(mut self, sequence_id: u64) -> Self {
+        self.edge.sequence_id = sequence_id;
+        self
+    }
+
+    /// Sets whether the edge is part of the base.
+    pub fn released(mut self, released: bool) -> Self {
+        self.edge.released = released;
+        self
+    }
+
+    /// Sets the nodes the edge starts and ends at, which are assigned by [`OrderBuilder`] otherwise.
+    pub fn nodes(mut self, start_node_id: impl Into<NodeId>, end_node_id: impl Into<NodeId>) -> Self {
+        self.edge.start_node_id = start_node_id.into();
+        self.edge.end_node_id = end_node_id.into();
+        self
+    }
+
+    /// Sets the verbose description of the edge.
+    pub fn description(mut self, edge_description: impl Into<String>) -> Self {
+        self.edge.edge_description = Some(edge_description.into());
+        self
+    }
+
+    /// Sets the maximum speed in m/s on the edge.
+    pub fn max_speed(mut self, max_speed: Magnitude) -> Self {
+        self.edge.max_speed = Some(max_speed);
+        self
+    }
+
+    /// Sets the minimum and maximum height in meters of the load handling device on the edge.
+    pub fn height(mut self, min_height: Magnitude, max_height: Magnitude) -> Self {
+        self.edge.min_height = Some(min_height);
+        self.edge.max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the orientation of the AGV on the edge and relative to what it is given.
+    pub fn orientation(mut self, orientation: Angle, orientation_type: OrientationType) -> Self {
+        self.edge.orientation = Some(orientation);
+        self.edge.orientation_type = Some(orientation_type);
+        self
+    }
+
+    /// Sets the direction at junctions, e.g. `left`.
+    pub fn direction(mut self, direction: impl Into<String>) -> Self {
+        self.edge.direction = Some(direction.into());
+        self
+    }
+
+    /// Sets whether the AGV may rotate on the edge.
+    pub fn rotation_allowed(mut self, rotation_allowed: bool) -> Self {
+        self.edge.rotation_allowed = Some(rotation_allowed);
+        self
+    }
+
+    /// Sets the maximum rotation speed in rad/s on the edge.
+    pub fn max_rotation_speed(mut self, max_rotation_speed: Magnitude) -> Self {
+        self.edge.max_rotation_speed = Some(max_rotation_speed);
+        self
+    }
+
+    /// Sets the length of the edge in meters.
+    pub fn length(mut self, length: Magnitude) -> Self {
+        self.edge.length = Some(length);
+        self
+    }
+
+    /// Sets the trajectory the AGV has to follow on the edge.
+    pub fn trajectory(mut self, trajectory: Trajectory) -> Self {
+        self.edge.trajectory = Some(trajectory);
+        self
+    }
+
+    /// Appends `action` to the actions of the edge.
+    pub fn action(mut self, action: Action) -> Self {
+        self.edge.actions.push(action);
+        self
+    }
+
+    /// Returns the edge.
+    pub fn build(self) -> Edge {
+        self.edge
+    }
+}
+
+impl From<&str> for EdgeBuilder {
+
+    fn from(edge_id: &str) -> Self {
+        Edge::builder(edge_id)
+    }
+}
+
+impl From<String> for EdgeBuilder {
+
+    fn from(edge_id: String) -> Self {
+        Edge::builder(edge_id)
+    }
+}
+
+impl State {
+
+    /// Returns a builder of the first state of the AGV `serial_number` of `manufacturer` for protocol version `2.0.0`.
+    ///
+    /// Unless specified otherwise, the header id is zero, the AGV has no order, is neither driving nor paused and
+    /// operates `AUTOMATIC`, the battery is empty and not charging, no emergency stop is active and all lists are
+    /// empty.
+    pub fn builder(manufacturer: impl Into<String>, serial_number: impl Into<String>, timestamp: Timestamp) -> StateBuilder {
+        StateBuilder {
+            state: State {
+                header_id: 0,
+                timestamp,
+                version: "2.0.0".into(),
+                manufacturer: manufacturer.into(),
+                serial_number: serial_number.into(),
+                order_id: OrderId::default(),
+                order_update_id: 0,
+                zone_set_id: None,
+                last_node_id: NodeId::default(),
+                last_node_sequence_id: 0,
+                driving: false,
+                paused: None,
+                new_base_request: None,
+                distance_since_last_node: None,
+                operating_mode: OperatingMode::Automatic,
+                node_states: Vec::new(),
+                edge_states: Vec::new(),
+                agv_position: None,
+                velocity: None,
+                loads: Vec::new(),
+                action_states: Vec::new(),
+                battery_state: BatteryState::default(),
+                errors: Vec::new(),
+                information: Vec::new(),
+                safety_state: SafetyState::default(),
+            },
+        }
+    }
+
+    /// Returns a builder of the state following this one, which has the next header id and `timestamp` and keeps all
+    /// other fields unless specified otherwise.
+    pub fn into_next(mut self, timestamp: Timestamp) -> StateBuilder {
+        self.header_id = self.header_id.wrapping_add(1);
+        self.timestamp = timestamp;
+        StateBuilder { state: self }
+    }
+}
+
+/// Builder of a [`State`], see [`State::builder`] and [`State::into_next`].
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct StateBuilder {
+    state: State,
+}
+
+impl StateBuilder {
+
+    /// Sets the header id of the state.
+    pub fn header_id(mut self, header_id: HeaderId) -> Self {
+        self.state.header_id = header_id;
+        self
+    }
+
+    /// Sets the protocol version of the state, e.g. `2.0.0`.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.state.version = version.into();
+        self
+    }
+
+    /// Sets the current order and order update.
+    pub fn order(mut self, order_id: impl Into<OrderId>, order_update_id: u64) -> Self {
+        self.state.order_id = order_id.into();
+        self.state.order_update_id = order_update_id;
+        self
+    }
+
+    /// Sets the zone set used by the AGV.
+    pub fn zone_set_id(mut self, zone_set_id: impl Into<ZoneSetId>) -> Self {
+        self.state.zone_set_id = Some(zone_set_id.into());
+        self
+    }
+
+    /// Sets the last node the AGV reached or traversed.
+    pub fn last_node(mut self, last_node_id: impl Into<NodeId>, last_node_sequence_id: u64) -> Self {
+        self.state.last_node_id = last_node_id.into();
+        self.state.last_node_sequence_id = last_node_sequence_id;
+        self
+    }
+
+    /// Sets whether the AGV is driving.
+    pub fn driving(mut self, driving: bool) -> Self {
+        self.state.driving = driving;
+        self
+    }
+
+    /// Sets whether the AGV is paused.
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.state.paused = Some(paused);
+        self
+    }
+
+    /// Sets whether the AGV requests an update of the base.
+    pub fn new_base_request(mut self, new_base_request: bool) -> Self {
+        self.state.new_base_request = Some(new_base_request);
+        self
+    }
+
+    /// Sets the distance in meters driven since the last node.
+    pub fn distance_since_last_node(mut self, distance_since_last_node: Float) -> Self {
+        self.state.distance_since_last_node = Some(distance_since_last_node);
+        self
+    }
+
+    /// Sets the operating mode of the AGV.
+    pub fn operating_mode(mut self, operating_mode: OperatingMode) -> Self {
+        self.state.operating_mode = operating_mode;
+        self
+    }
+
+    /// Sets the states of the nodes the AGV still has to traverse.
+    pub fn node_states(mut self, node_states: Vec<NodeState>) -> Self {
+        self.state.node_states = node_states;
+        self
+    }
+
+    /// Sets the states of the edges the AGV still has to traverse.
+    pub fn edge_states(mut self, edge_states: Vec<EdgeState>) -> Self {
+        self.state.edge_states = edge_states;
+        self
+    }
+
+    /// Sets the position of the AGV.
+    pub fn agv_position(mut self, agv_position: AgvPosition) -> Self {
+        self.state.agv_position = Some(agv_position);
+        self
+    }
+
+    /// Sets the velocity of the AGV.
+    pub fn velocity(mut self, velocity: Velocity) -> Self {
+        self.state.velocity = Some(velocity);
+        self
+    }
+
+    /// Sets the loads carried by the AGV.
+    pub fn loads(mut self, loads: Vec<Load>) -> Self {
+        self.state.loads = loads;
+        self
+    }
+
+    /// Sets the states of the actions of the current order and of instant actions.
+    pub fn action_states(mut self, action_states: Vec<ActionState>) -> Self {
+        self.state.action_states = action_states;
+        self
+    }
+
+    /// Sets the state of the battery.
+    pub fn battery_state(mut self, battery_state: BatteryState) -> Self {
+        self.state.battery_state = battery_state;
+        self
+    }
+
+    /// Sets the active errors.
+    pub fn errors(mut self, errors: Vec<Error>) -> Self {
+        self.state.errors = errors;
+        self
+    }
+
+    /// Sets the information for visualization or debugging.
+    pub fn information(mut self, information: Vec<Information>) -> Self {
+        self.state.information = information;
+        self
+    }
+
+    /// Sets the safety state of the AGV.
+    pub fn safety_state(mut self, safety_state: SafetyState) -> Self {
+        self.state.safety_state = safety_state;
+        self
+    }
+
+    /// Returns the state.
+    pub fn build(self) -> State {
+        self.state
+    }
+}
+
+impl Factsheet {
+
+    /// Returns a builder of the factsheet of the AGV `serial_number` of `manufacturer` for protocol version `2.0.0`,
+    /// whose header id is zero and whose sections are absent unless specified otherwise.
+    pub fn builder(manufacturer: impl Into<String>, serial_number: impl Into<String>, timestamp: Timestamp) -> FactsheetBuilder {
+        FactsheetBuilder {
+            factsheet: Factsheet {
+                header_id: 0,
+                timestamp,
+                version: "2.0.0".into(),
+                manufacturer: manufacturer.into(),
+                serial_number: serial_number.into(),
+                type_specification: None,
+                physical_parameters: None,
+                protocol_limits: None,
+                protocol_features: None,
+                agv_geometry: None,
+                load_specification: None,
+                localization_parameters: None,
+            },
+        }
+    }
+}
+
+/// Builder of a [`Factsheet`], see [`Factsheet::builder`].
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct FactsheetBuilder {
+    factsheet: Factsheet,
+}
+
+impl FactsheetBuilder {
+
+    /// Sets the header id of the factsheet.
+    pub fn header_id(mut self, header_id: HeaderId) -> Self {
+        self.factsheet.header_id = header_id;
+        self
+    }
+
+    /// Sets the protocol version of the factsheet, e.g. `2.0.0`.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.factsheet.version = version.into();
+        self
+    }
+
+    /// Fills the type specification, physical parameters, protocol features and load specification with typical
+    /// values of an AGV of `agv_class` of the series `series_name`, which are meant to be adjusted afterwards.
+    ///
+    /// The protocol features list the instant actions `cancelOrder`, `startPause`, `stopPause`, `stateRequest` and
+    /// `factsheetRequest` and, for AGVs with load handling device, the node actions `pick` and `drop`.
+    pub fn preset(self, agv_class: AgvClass, series_name: impl Into<String>) -> Self {
+        let (agv_kinematic, max_load_mass, [speed_max, acceleration_max, height_max, width, length], load_position) = match agv_class {
+            AgvClass::Forklift => (AgvKinematic::ThreeWheel, 1200.0, [2.0, 0.5, 2.2, 1.2, 2.0], Some("fork")),
+            AgvClass::Conveyor => (AgvKinematic::Diff, 500.0, [1.5, 1.0, 1.0, 0.8, 1.2], Some("conveyor")),
+            AgvClass::Tugger => (AgvKinematic::Diff, 3000.0, [2.0, 0.3, 1.5, 1.0, 2.5], None),
+            AgvClass::Carrier => (AgvKinematic::Omni, 1000.0, [1.5, 0.5, 0.5, 1.0, 1.5], Some("lift")),
+        };
+        let action = |action_type: &str, action_scopes: Vec<ActionScope>| AgvAction {
+            action_type: action_type.into(),
+            action_description: None,
+            action_scopes,
+            action_parameters: Vec::new(),
+            result_description: None,
+        };
+        let mut agv_actions = ["cancelOrder", "startPause", "stopPause", "stateRequest", "factsheetRequest"].into_iter()
+            .map(|action_type| action(action_type, alloc::vec![ActionScope::Instant]))
+            .collect::<Vec<_>>();
+        if load_position.is_some() {
+            agv_actions.extend(["pick", "drop"].into_iter().map(|action_type| action(action_type, alloc::vec![ActionScope::Node])));
+        }
+        self.type_specification(TypeSpecification {
+                series_name: series_name.into(),
+                series_description: None,
+                agv_kinematic,
+                agv_class,
+                max_load_mass: float(max_load_mass),
+                localization_types: alloc::vec![LocalizationType::Natural],
+                navigation_types: alloc::vec![NavigationType::Autonomous],
+            })
+            .physical_parameters(PhysicalParameters {
+                speed_min: float(0.01),
+                speed_max: float(speed_max),
+                acceleration_max: float(acceleration_max),
+                deceleration_max: float(acceleration_max),
+                height_min: None,
+                height_max: float(height_max),
+                width: float(width),
+                length: float(length),
+            })
+            .protocol_features(ProtocolFeatures { optional_parameters: Vec::new(), agv_actions })
+            .load_specification(LoadSpecification {
+                load_positions: load_position.into_iter().map(String::from).collect(),
+                load_sets: Vec::new(),
+            })
+    }
+
+    /// Sets the class and capabilities of the AGV.
+    pub fn type_specification(mut self, type_specification: TypeSpecification) -> Self {
+        self.factsheet.type_specification = Some(type_specification);
+        self
+    }
+
+    /// Sets the physical properties of the AGV.
+    pub fn physical_parameters(mut self, physical_parameters: PhysicalParameters) -> Self {
+        self.factsheet.physical_parameters = Some(physical_parameters);
+        self
+    }
+
+    /// Sets the protocol limitations of the AGV.
+    pub fn protocol_limits(mut self, protocol_limits: ProtocolLimits) -> Self {
+        self.factsheet.protocol_limits = Some(protocol_limits);
+        self
+    }
+
+    /// Sets the supported optional parameters and actions.
+    pub fn protocol_features(mut self, protocol_features: ProtocolFeatures) -> Self {
+        self.factsheet.protocol_features = Some(protocol_features);
+        self
+    }
+
+    /// Appends `agv_action` to the actions of the protocol features, which are created if absent.
+    pub fn agv_action(mut self, agv_action: AgvAction) -> Self {
+        self.factsheet.protocol_features
+            .get_or_insert_with(|| ProtocolFeatures { optional_parameters: Vec::new(), agv_actions: Vec::new() })
+            .agv_actions
+            .push(agv_action);
+        self
+    }
+
+    /// Sets the geometry of the AGV.
+    pub fn agv_geometry(mut self, agv_geometry: AgvGeometry) -> Self {
+        self.factsheet.agv_geometry = Some(agv_geometry);
+        self
+    }
+
+    /// Sets the load capabilities of the AGV.
+    pub fn load_specification(mut self, load_specification: LoadSpecification) -> Self {
+        self.factsheet.load_specification = Some(load_specification);
+        self
+    }
+
+    /// Returns the factsheet.
+    pub fn build(self) -> Factsheet {
+        self.factsheet
+    }
+}
+
+impl Timing {
+
+    /// Returns a builder of the timing with the minimum intervals between order messages and between state messages,
+    /// whose default intervals are absent unless specified otherwise.
+    pub fn builder(min_order_interval: Duration, min_state_interval: Duration) -> TimingBuilder {
+        TimingBuilder {
+            timing: Timing {
+                min_order_interval: float(min_order_interval.as_secs_f32()),
+                min_state_interval: float(min_state_interval.as_secs_f32()),
+                default_state_interval: None,
+                visualization_interval: None,
+            },
+        }
+    }
+}
+
+/// Builder of a [`Timing`], see [`Timing::builder`].
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct TimingBuilder {
+    timing: Timing,
+}
+
+impl TimingBuilder {
+
+    /// Sets the default interval between state messages.
+    pub fn default_state_interval(mut self, default_state_interval: Duration) -> Self {
+        self.timing.default_state_interval = Some(float(default_state_interval.as_secs_f32()));
+        self
+    }
+
+    /// Sets the default interval between messages on the visualization topic.
+    pub fn visualization_interval(mut self, visualization_interval: Duration) -> Self {
+        self.timing.visualization_interval = Some(float(visualization_interval.as_secs_f32()));
+        self
+    }
+
+    /// Returns the timing.
+    pub fn build(self) -> Timing {
+        self.timing
+    }
+}
+
+/// Returns `value` as field, which is finite for all presets and durations.
+fn float<T: Raw + Default>(value: f32) -> T {
+    T::try_from_raw(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::time::Duration;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::action::{Action, BlockingType};
+    use crate::checked::{checked, Angle, Magnitude};
+    use crate::common::NodePosition;
+    use crate::factsheet::{ActionScope, AgvAction, AgvClass, Factsheet, Timing};
+    use crate::finite::Float;
+    use crate::order::{Edge, Node, Order};
+    use crate::state::{OperatingMode, State};
+    use crate::timestamp::from_unix_millis;
+    use crate::validation::{IdPolicy, RULE_DISCONNECTED, RULE_NO_BASE_NODE, RULE_NOT_NUMERICAL, RULE_SEQUENCE_PARITY, Validate};
+
+    fn action(action_id: &str) -> Action {
+        Action {
+            action_type: "pick".into(),
+            action_id: action_id.into(),
+            action_description: None,
+            blocking_type: BlockingType::Hard,
+            action_parameters: vec![],
+        }
+    }
+
+    #[rstest]
+    fn test_OrderBuilder_build() {
+        let order = Order::builder("o1", from_unix_millis(0).unwrap())
+            .agv("Fubar Co.", "1234")
+            .node("n1")
+            .edge_to("e1", "n2")
+            .node_action(action("a1"))
+            .edge_action(action("a2"))
+            .horizon_node("e2", "n3")
+            .edge_to("e3", "n4")
+            .build()
+            .unwrap();
+
+        assert_that!(order.nodes.iter().map(|node| (node.node_id.as_str(), node.sequence_id, node.released)).collect::<Vec<_>>(), eq(&vec![
+            ("n1", 0, true),
+            ("n2", 2, true),
+            ("n3", 4, false),
+            ("n4", 6, false),
+        ]));
+        assert_that!(order.edges.iter().map(|edge| (edge.edge_id.as_str(), edge.sequence_id, edge.released, edge.start_node_id.as_str(), edge.end_node_id.as_str())).collect::<Vec<_>>(), eq(&vec![
+            ("e1", 1, true, "n1", "n2"),
+            ("e2", 3, false, "n2", "n3"),
+            ("e3", 5, false, "n3", "n4"),
+        ]));
+        assert_that!(order.nodes[1].actions.iter().map(|action| action.action_id.as_str()).collect::<Vec<_>>(), eq(&vec!["a1"]));
+        assert_that!(order.edges[0].actions.iter().map(|action| action.action_id.as_str()).collect::<Vec<_>>(), eq(&vec!["a2"]));
+        assert_that!(order.validate(), empty());
+    }
+
+    #[rstest]
+    fn test_OrderBuilder_order_update() {
+        let order = Order::builder("o1", from_unix_millis(0).unwrap())
+            .order_update(1, 4)
+            .node("n3")
+            .edge_to("e3", "n4")
+            .build()
+            .unwrap();
+
+        assert_that!(order.order_update_id, eq(1));
+        assert_that!(order.nodes.iter().map(|node| node.sequence_id).collect::<Vec<_>>(), eq(&vec![4, 6]));
+        assert_that!(order.edges[0].sequence_id, eq(5));
+    }
+
+    #[rstest]
+    fn test_NodeBuilder_build() {
+        let node = Node::builder("n1")
+            .position(checked(1.0), checked(2.0), "map")
+            .theta(checked(0.5))
+            .allowed_deviation(checked(0.1), checked(0.2))
+            .action(action("a1"))
+            .build();
+
+        assert_that!(node.node_position, some(matches_pattern!(NodePosition {
+            x: eq(&checked::<Float>(1.0)),
+            theta: some(eq(&checked::<Angle>(0.5))),
+            allowed_deviation_xy: some(eq(&checked::<Magnitude>(0.1))),
+            map_id: eq("map"),
+        })));
+        assert_that!(node.actions.len(), eq(1));
+    }
+
+    #[rstest]
+    fn test_OrderBuilder_with_node_and_edge_builders() {
+        let order = Order::builder("o1", from_unix_millis(0).unwrap())
+            .node(Node::builder("n1").position(checked(0.0), checked(0.0), "map"))
+            .edge_to(Edge::builder("e1").max_speed(checked(1.5)).nodes("x", "y").action(action("a1")), Node::builder("n2").action(action("a2")))
+            .build()
+            .unwrap();
+
+        assert_that!(order.edges[0], matches_pattern!(Edge {
+            sequence_id: eq(&1),
+            start_node_id: eq("n1"),
+            end_node_id: eq("n2"),
+            max_speed: some(eq(&checked::<Magnitude>(1.5))),
+        }));
+        assert_that!(order.edges[0].actions.len(), eq(1));
+        assert_that!(order.nodes[1].actions.len(), eq(1));
+    }
+
+    #[rstest]
+    fn test_StateBuilder_defaults_are_valid() {
+        let state = State::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap()).build();
+
+        assert_that!(state.validate(), empty());
+        assert_that!(state.operating_mode, matches_pattern!(OperatingMode::Automatic));
+    }
+
+    #[rstest]
+    fn test_State_into_next_keeps_fields() {
+        let state = State::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap())
+            .header_id(7)
+            .order("o1", 2)
+            .last_node("n1", 0)
+            .operating_mode(OperatingMode::Manual)
+            .build();
+
+        let next = state.into_next(from_unix_millis(1000).unwrap()).driving(true).build();
+
+        assert_that!(next, matches_pattern!(State {
+            header_id: eq(&8),
+            timestamp: eq(&from_unix_millis(1000).unwrap()),
+            order_id: eq("o1"),
+            order_update_id: eq(&2),
+            last_node_id: eq("n1"),
+            driving: eq(&true),
+            operating_mode: matches_pattern!(OperatingMode::Manual),
+        }));
+    }
+
+    #[rstest]
+    #[case::forklift(AgvClass::Forklift, 7)]
+    #[case::conveyor(AgvClass::Conveyor, 7)]
+    #[case::tugger(AgvClass::Tugger, 5)]
+    #[case::carrier(AgvClass::Carrier, 7)]
+    fn test_FactsheetBuilder_preset(#[case] agv_class: AgvClass, #[case] actions: usize) {
+        let factsheet = Factsheet::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap())
+            .preset(agv_class, "Fubar 1")
+            .agv_action(AgvAction {
+                action_type: "beep".into(),
+                action_description: None,
+                action_scopes: vec![ActionScope::Instant],
+                action_parameters: vec![],
+                result_description: None,
+            })
+            .build();
+
+        assert_that!(factsheet.validate(), empty());
+        assert_that!(factsheet.completeness().missing_sections, eq(&vec!["protocolLimits", "agvGeometry"]));
+        assert_that!(factsheet.protocol_features.map(|features| features.agv_actions.len()), some(eq(actions + 1)));
+    }
+
+    #[rstest]
+    fn test_TimingBuilder_build() {
+        let timing = Timing::builder(Duration::from_millis(500), Duration::from_secs(1))
+            .visualization_interval(Duration::from_millis(100))
+            .build();
+
+        assert_that!(timing.min_order_interval_duration(), some(eq(Duration::from_millis(500))));
+        assert_that!(timing.min_state_interval_duration(), some(eq(Duration::from_secs(1))));
+        assert_that!(timing.default_state_interval_duration(), none());
+        assert_that!(timing.visualization_interval_duration(), some(eq(Duration::from_millis(100))));
+    }
+
+    #[rstest]
+    fn test_OrderPath_build() {
+        let order = Order::builder("o1", from_unix_millis(0).unwrap())
+            .agv("Fubar Co.", "1234")
+            .path()
+            .node("n1")
+            .action(action("a1"))
+            .edge("e1")
+            .action(action("a2"))
+            .node("n2")
+            .horizon()
+            .edge("e2")
+            .node("n3")
+            .build();
+
+        assert_that!(order.nodes.iter().map(|node| (node.node_id.as_str(), node.sequence_id, node.released, node.actions.len())).collect::<Vec<_>>(), eq(&vec![
+            ("n1", 0, true, 1),
+            ("n2", 2, true, 0),
+            ("n3", 4, false, 0),
+        ]));
+        assert_that!(order.edges.iter().map(|edge| (edge.edge_id.as_str(), edge.sequence_id, edge.released, edge.actions.len())).collect::<Vec<_>>(), eq(&vec![
+            ("e1", 1, true, 1),
+            ("e2", 3, false, 0),
+        ]));
+        assert_that!(order.validate(), empty());
+    }
+
+    #[rstest]
+    #[case::empty(Order::builder("o1", from_unix_millis(0).unwrap()), vec![("/nodes", RULE_NO_BASE_NODE)])]
+    #[case::consecutive_nodes(Order::builder("o1", from_unix_millis(0).unwrap()).node("n1").node("n2"), vec![("/nodes/1", RULE_DISCONNECTED), ("/nodes/1/sequenceId", RULE_SEQUENCE_PARITY)])]
+    #[case::id_policy(Order::builder("o1", from_unix_millis(0).unwrap()).id_policy(IdPolicy { numerical_only: true, ..IdPolicy::default() }).node("1").edge_to("2", "n3"), vec![
+        ("/orderId", RULE_NOT_NUMERICAL),
+        ("/nodes/1/nodeId", RULE_NOT_NUMERICAL),
+        ("/edges/0/endNodeId", RULE_NOT_NUMERICAL),
+    ])]
+    fn test_OrderBuilder_build_reports_violations(#[case] builder: super::OrderBuilder, #[case] expected: Vec<(&str, &str)>) {
+        let violations = builder.build().unwrap_err();
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&expected));
+    }
+}
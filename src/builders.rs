@@ -0,0 +1,274 @@
+//!
+//! Generated builders of the structs with many fields which have no curated builder, to construct partially filled
+//! values without spelling out every field.
+//!
+//! Each builder has a setter per field, where setters of optional fields take the value without `Some`, and returns
+//! a [`MissingField`] error on [`build`](PhysicalParametersBuilder::build) if a required field has not been set.
+//! Optional fields default to `None` and lists to an empty list. Structs with a curated builder, e.g. [`Order`],
+//! [`Edge`], [`State`] and [`Factsheet`], keep it.
+//!
+//! [`Order`]: crate::order::Order
+//! [`Edge`]: crate::order::Edge
+//! [`State`]: crate::state::State
+//! [`Factsheet`]: crate::factsheet::Factsheet
+//!
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::checked::{Angle, Magnitude};
+use crate::common::{AgvPosition, BoundingBoxReference, HeaderId, LoadDimensions, NodePosition, Timestamp, Velocity};
+use crate::factsheet::{AgvClass, AgvKinematic, LoadSet, LocalizationType, MaxArrayLens, MaxStringLens, NavigationType, PhysicalParameters, TypeSpecification};
+use crate::finite::{Double, Float};
+use crate::id::MapId;
+use crate::standard_action::LoadHandlingParameters;
+use crate::units::{Acceleration, Length, Mass, Speed};
+use crate::visualization::Visualization;
+
+/// Error returned when building a value whose required field has not been set, e.g. `speed_max`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct MissingField(pub &'static str);
+
+impl core::fmt::Display for MissingField {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "required field `{}` is not set", self.0)
+    }
+}
+
+macro_rules! builder {
+    (
+        $(#[$doc:meta])* $name:ident => $builder:ident {
+            required { $($required:ident: $required_type:ty),* $(,)? }
+            optional { $($optional:ident: $optional_type:ty),* $(,)? }
+            default { $($default:ident: $default_type:ty),* $(,)? }
+        }
+    ) => {
+
+        impl $name {
+
+            #[doc = concat!("Returns a builder of a [`", stringify!($name), "`], see [`", stringify!($builder), "`].")]
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        $(#[$doc])*
+        #[derive(Clone, Default)]
+        #[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+        pub struct $builder {
+            $($required: Option<$required_type>,)*
+            $($optional: Option<$optional_type>,)*
+            $($default: Option<$default_type>,)*
+        }
+
+        impl $builder {
+            $(
+                #[doc = concat!("Sets the required field `", stringify!($required), "`.")]
+                pub fn $required(mut self, $required: impl Into<$required_type>) -> Self {
+                    self.$required = Some($required.into());
+                    self
+                }
+            )*
+            $(
+                #[doc = concat!("Sets the optional field `", stringify!($optional), "`.")]
+                pub fn $optional(mut self, $optional: impl Into<$optional_type>) -> Self {
+                    self.$optional = Some($optional.into());
+                    self
+                }
+            )*
+            $(
+                #[doc = concat!("Sets the field `", stringify!($default), "`, which is empty unless set.")]
+                pub fn $default(mut self, $default: impl Into<$default_type>) -> Self {
+                    self.$default = Some($default.into());
+                    self
+                }
+            )*
+
+            #[doc = concat!("Returns the [`", stringify!($name), "`] or an error if a required field has not been set.")]
+            pub fn build(self) -> Result<$name, MissingField> {
+                Ok($name {
+                    $($required: self.$required.ok_or(MissingField(stringify!($required)))?,)*
+                    $($optional: self.$optional,)*
+                    $($default: self.$default.unwrap_or_default(),)*
+                })
+            }
+        }
+    };
+}
+
+builder! {
+    /// Builder of an [`AgvPosition`], see [`AgvPosition::builder`].
+    AgvPosition => AgvPositionBuilder {
+        required { x: Float, y: Float, theta: Angle, map_id: MapId, position_initialized: bool }
+        optional { map_description: String, localization_score: Float, deviation_range: Magnitude }
+        default { }
+    }
+}
+
+builder! {
+    /// Builder of a [`NodePosition`], see [`NodePosition::builder`].
+    NodePosition => NodePositionBuilder {
+        required { x: Float, y: Float, map_id: MapId }
+        optional { theta: Angle, allowed_deviation_xy: Magnitude, allowed_deviation_theta: Float, map_description: String }
+        default { }
+    }
+}
+
+builder! {
+    /// Builder of a [`TypeSpecification`], see [`TypeSpecification::builder`].
+    TypeSpecification => TypeSpecificationBuilder {
+        required { series_name: String, agv_kinematic: AgvKinematic, agv_class: AgvClass, max_load_mass: Mass }
+        optional { series_description: String }
+        default { localization_types: Vec<LocalizationType>, navigation_types: Vec<NavigationType> }
+    }
+}
+
+builder! {
+    /// Builder of [`PhysicalParameters`], see [`PhysicalParameters::builder`].
+    PhysicalParameters => PhysicalParametersBuilder {
+        required {
+            speed_min: Speed,
+            speed_max: Speed,
+            acceleration_max: Acceleration,
+            deceleration_max: Acceleration,
+            height_max: Length,
+            width: Length,
+            length: Length,
+        }
+        optional { height_min: Length }
+        default { }
+    }
+}
+
+builder! {
+    /// Builder of [`MaxStringLens`], see [`MaxStringLens::builder`].
+    MaxStringLens => MaxStringLensBuilder {
+        required { }
+        optional {
+            msg_len: u64,
+            topic_serial_len: u64,
+            topic_elem_len: u64,
+            id_len: u64,
+            id_numerical_only: bool,
+            enum_len: u64,
+            load_id_len: u64,
+        }
+        default { }
+    }
+}
+
+builder! {
+    /// Builder of [`MaxArrayLens`], see [`MaxArrayLens::builder`].
+    MaxArrayLens => MaxArrayLensBuilder {
+        required {
+            order_nodes: u32,
+            order_edges: u32,
+            node_actions: u32,
+            edge_actions: u32,
+            actions_actions_parameters: u32,
+            instant_actions: u32,
+            trajectory_knot_vector: u32,
+            trajectory_control_points: u32,
+            state_node_states: u32,
+            state_edge_states: u32,
+            state_loads: u32,
+            state_action_states: u32,
+            state_errors: u32,
+            state_information: u32,
+            error_error_references: u32,
+            information_info_references: u32,
+        }
+        optional { }
+        default { }
+    }
+}
+
+builder! {
+    /// Builder of a [`LoadSet`], see [`LoadSet::builder`].
+    LoadSet => LoadSetBuilder {
+        required { set_name: String, load_type: String }
+        optional {
+            bounding_box_reference: BoundingBoxReference,
+            load_dimensions: LoadDimensions,
+            max_weigth: Mass,
+            min_loadhandling_height: Length,
+            max_loadhandling_height: Length,
+            min_loadhandling_depth: Length,
+            max_loadhandling_depth: Length,
+            min_loadhandling_tilt: Float,
+            max_loadhandling_tilt: Float,
+            agv_speed_limit: Speed,
+            agv_acceleration_limit: Acceleration,
+            agv_deceleration_limit: Acceleration,
+            pick_time: Float,
+            drop_time: Float,
+            description: Float,
+        }
+        default { load_positions: Vec<String> }
+    }
+}
+
+builder! {
+    /// Builder of [`LoadHandlingParameters`], see [`LoadHandlingParameters::builder`].
+    LoadHandlingParameters => LoadHandlingParametersBuilder {
+        required { station_type: String, load_type: String }
+        optional { lhd: String, station_name: String, load_id: String, height: Double, depth: Double, side: String }
+        default { }
+    }
+}
+
+builder! {
+    /// Builder of a [`Visualization`], see [`Visualization::builder`].
+    Visualization => VisualizationBuilder {
+        required { header_id: HeaderId, timestamp: Timestamp, version: String, manufacturer: String, serial_number: String }
+        optional { agv_position: AgvPosition, velocity: Velocity }
+        default { }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::{checked, Raw};
+    use crate::factsheet::{LoadSet, MaxStringLens, PhysicalParameters};
+    use super::MissingField;
+
+    #[rstest]
+    fn test_PhysicalParametersBuilder_build() {
+        let physical_parameters = PhysicalParameters::builder()
+            .speed_min(checked::<crate::units::Speed>(0.1))
+            .speed_max(checked::<crate::units::Speed>(2.0))
+            .acceleration_max(checked::<crate::units::Acceleration>(0.5))
+            .deceleration_max(checked::<crate::units::Acceleration>(0.8))
+            .height_max(checked::<crate::units::Length>(1.5))
+            .width(checked::<crate::units::Length>(1.0))
+            .length(checked::<crate::units::Length>(2.0))
+            .build();
+
+        assert_that!(physical_parameters.as_ref().map(|parameters| (parameters.speed_max.raw(), parameters.height_min.is_none())), ok(eq((2.0, true))));
+    }
+
+    #[rstest]
+    fn test_builder_rejects_missing_required_field() {
+        let load_set = LoadSet::builder()
+            .set_name("default")
+            .load_positions(vec!["front".into()])
+            .build();
+
+        assert_that!(load_set.map(|load_set| load_set.set_name), err(eq(&MissingField("load_type"))));
+    }
+
+    #[rstest]
+    fn test_builder_defaults_optional_fields() {
+        let max_string_lens = MaxStringLens::builder().id_len(64u64).build().unwrap();
+        let load_set = LoadSet::builder().set_name("default").load_type("EPAL").build().unwrap();
+
+        assert_that!((max_string_lens.id_len, max_string_lens.msg_len), eq((Some(64), None)));
+        assert_that!(load_set.load_positions, empty());
+        assert_that!(load_set.max_weigth.is_none(), eq(true));
+    }
+}
@@ -0,0 +1,251 @@
+//!
+//! Newtypes of floating point numbers restricted to the range required by the specification.
+//!
+//! If the feature `checked-types` is enabled, the following fields use these newtypes instead of `f32`, so that
+//! values outside of their range are unrepresentable and rejected while deserializing:
+//!
+//! | Field                                                                                   | Type                |
+//! | --------------------------------------------------------------------------------------- | ------------------- |
+//! | `BatteryState::battery_charge`                                                          | [`Percentage`]      |
+//! | `AgvPosition::theta`, `NodePosition::theta`, `Edge::orientation`                        | [`NormalizedAngle`] |
+//! | `AgvPosition::deviation_range`, `NodePosition::allowed_deviation_xy`, `Edge::max_speed`, `Edge::max_height`, `Edge::min_height`, `Edge::max_rotation_speed`, `Edge::length` | [`NonNegative`] |
+//!
+//! The aliases [`Percent`], [`Angle`] and [`Magnitude`] resolve to the type used by the fields.
+//!
+/// Type of percentages, which is [`Percentage`] if the feature `checked-types` is enabled and
+/// [`Float`](crate::finite::Float) otherwise.
+#[cfg(feature = "checked-types")]
+pub type Percent = Percentage;
+
+/// Type of percentages, which is [`Percentage`] if the feature `checked-types` is enabled and
+/// [`Float`](crate::finite::Float) otherwise.
+#[cfg(not(feature = "checked-types"))]
+pub type Percent = crate::finite::Float;
+
+/// Type of angles, which is [`NormalizedAngle`] if the feature `checked-types` is enabled and
+/// [`Float`](crate::finite::Float) otherwise.
+#[cfg(feature = "checked-types")]
+pub type Angle = NormalizedAngle;
+
+/// Type of angles, which is [`NormalizedAngle`] if the feature `checked-types` is enabled and
+/// [`Float`](crate::finite::Float) otherwise.
+#[cfg(not(feature = "checked-types"))]
+pub type Angle = crate::finite::Float;
+
+/// Type of distances and speeds, which is [`NonNegative`] if the feature `checked-types` is enabled and
+/// [`Float`](crate::finite::Float) otherwise.
+#[cfg(feature = "checked-types")]
+pub type Magnitude = NonNegative;
+
+/// Type of distances and speeds, which is [`NonNegative`] if the feature `checked-types` is enabled and
+/// [`Float`](crate::finite::Float) otherwise.
+#[cfg(not(feature = "checked-types"))]
+pub type Magnitude = crate::finite::Float;
+
+/// Error returned when a value is outside of the range of a checked type, e.g. a percentage of `120`.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct OutOfRange {
+    /// The rejected value.
+    pub value: f32,
+    /// Lower bound of the range.
+    pub min: f32,
+    /// Upper bound of the range.
+    pub max: f32,
+}
+
+impl core::fmt::Display for OutOfRange {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "{} is outside of [{}, {}]", self.value, self.min, self.max)
+    }
+}
+
+/// Conversion of a field from and into its raw `f32`, regardless of whether the feature `checked-types` is enabled.
+pub(crate) trait Raw: Copy {
+
+    /// Returns the field holding `value` or an error if `value` is outside of the range of the field.
+    fn try_from_raw(value: f32) -> Result<Self, OutOfRange>;
+
+    /// Returns the raw value of the field.
+    fn raw(self) -> f32;
+}
+
+/// Returns the field holding `value`, which has to be within the range of the field.
+#[cfg(test)]
+pub(crate) fn checked<T: Raw>(value: f32) -> T {
+    T::try_from_raw(value).expect("value within range")
+}
+
+impl Raw for f32 {
+
+    fn try_from_raw(value: f32) -> Result<Self, OutOfRange> {
+        Ok(value)
+    }
+
+    fn raw(self) -> f32 {
+        self
+    }
+}
+
+macro_rules! checked_type {
+    ($(#[$doc:meta])* $name:ident, $min:expr, $max:expr) => {
+
+        $(#[$doc])*
+        #[derive(Clone, Copy, Default, PartialEq)]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+        #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+        #[cfg_attr(feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(try_from = "f32", into = "f32")
+        )]
+        pub struct $name(f32);
+
+        impl $name {
+
+            /// Lower bound of the range.
+            pub const MIN: f32 = $min;
+
+            /// Upper bound of the range.
+            pub const MAX: f32 = $max;
+
+            /// Returns `value` or an error if it is outside of the range, including NaN.
+            pub fn new(value: f32) -> Result<Self, OutOfRange> {
+                if (Self::MIN..=Self::MAX).contains(&value) && value.is_finite() {
+                    Ok(Self(if value == 0.0 { 0.0 } else { value }))
+                }
+                else {
+                    Err(OutOfRange { value, min: Self::MIN, max: Self::MAX })
+                }
+            }
+
+            /// Returns the value.
+            pub fn get(self) -> f32 {
+                self.0
+            }
+        }
+
+        impl TryFrom<f32> for $name {
+            type Error = OutOfRange;
+
+            fn try_from(value: f32) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for f32 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl core::hash::Hash for $name {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, formatter)
+            }
+        }
+
+        impl Raw for $name {
+
+            fn try_from_raw(value: f32) -> Result<Self, OutOfRange> {
+                Self::new(value)
+            }
+
+            fn raw(self) -> f32 {
+                self.0
+            }
+        }
+
+        #[cfg(feature = "strict-floats")]
+        impl crate::float::Finite for $name {
+            fn is_finite(&self) -> bool {
+                true
+            }
+        }
+    };
+}
+
+checked_type! {
+    /// Percentage within `[0, 100]`, e.g. the state of charge of a battery.
+    Percentage, 0.0, 100.0
+}
+
+checked_type! {
+    /// Angle in radians within `[-π, π]`, e.g. the orientation of an AGV.
+    NormalizedAngle, -core::f32::consts::PI, core::f32::consts::PI
+}
+
+checked_type! {
+    /// Finite number not less than zero, e.g. a distance in meters or a speed in m/s.
+    NonNegative, 0.0, f32::MAX
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::string::ToString;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::{NonNegative, NormalizedAngle, Percentage};
+
+    #[rstest]
+    #[case(0.0, true)]
+    #[case(100.0, true)]
+    #[case(100.5, false)]
+    #[case(-0.1, false)]
+    #[case(f32::NAN, false)]
+    fn test_Percentage_new(#[case] value: f32, #[case] valid: bool) {
+        assert_that!(Percentage::new(value).is_ok(), eq(valid));
+    }
+
+    #[rstest]
+    #[case(core::f32::consts::PI, true)]
+    #[case(-core::f32::consts::PI, true)]
+    #[case(4.0, false)]
+    fn test_NormalizedAngle_new(#[case] value: f32, #[case] valid: bool) {
+        assert_that!(NormalizedAngle::new(value).is_ok(), eq(valid));
+    }
+
+    #[rstest]
+    #[case(0.0, true)]
+    #[case(1e30, true)]
+    #[case(-1.0, false)]
+    #[case(f32::INFINITY, false)]
+    fn test_NonNegative_new(#[case] value: f32, #[case] valid: bool) {
+        assert_that!(NonNegative::new(value).is_ok(), eq(valid));
+    }
+
+    #[rstest]
+    fn test_OutOfRange_message() {
+        assert_that!(Percentage::new(120.0).unwrap_err().to_string(), eq("120 is outside of [0, 100]"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn test_serde_Percentage() {
+        assert_that!(serde_json::from_str::<Percentage>("80.5"), ok(eq(&Percentage::new(80.5).unwrap())));
+        assert_that!(serde_json::from_str::<Percentage>("120").is_err(), eq(true));
+        assert_that!(serde_json::to_string(&Percentage::new(80.5).unwrap()).unwrap(), eq("80.5"));
+    }
+}
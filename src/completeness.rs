@@ -0,0 +1,190 @@
+//!
+//! Report of the sections and fields a factsheet leaves out, e.g. to onboard a new AGV type series.
+//!
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::checked::Raw;
+use crate::factsheet::Factsheet;
+
+/// Sections and fields of a factsheet which are absent, empty or zero, see [`Factsheet::completeness`].
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct Completeness {
+    /// Names of the absent optional sections, e.g. `agvGeometry`.
+    pub missing_sections: Vec<&'static str>,
+    /// JSON pointer like paths of the fields of present sections which are absent, empty or zero, e.g.
+    /// `/physicalParameters/speedMax`.
+    pub defaulted_fields: Vec<String>,
+}
+
+impl Completeness {
+
+    /// Returns `true` if neither a section nor a field is missing.
+    pub fn is_complete(&self) -> bool {
+        self.missing_sections.is_empty() && self.defaulted_fields.is_empty()
+    }
+
+    fn section<T>(&mut self, name: &'static str, section: &Option<T>, check: impl FnOnce(&mut Fields, &T)) {
+        match section {
+            Some(section) => check(&mut Fields { prefix: name, defaulted: &mut self.defaulted_fields }, section),
+            None => self.missing_sections.push(name),
+        }
+    }
+}
+
+/// Records the defaulted fields of a section.
+struct Fields<'a> {
+    prefix: &'static str,
+    defaulted: &'a mut Vec<String>,
+}
+
+impl Fields<'_> {
+
+    fn defaulted(&mut self, name: &str, defaulted: bool) {
+        if defaulted {
+            self.defaulted.push(format!("/{}/{name}", self.prefix));
+        }
+    }
+}
+
+impl Factsheet {
+
+    /// Returns the optional sections `typeSpecification`, `physicalParameters`, `protocolLimits`, `protocolFeatures`,
+    /// `agvGeometry` and `loadSpecification` which are absent as well as the fields of present sections which are
+    /// absent, empty or zero, e.g. protocol limits without explicit limit or physical parameters left at zero.
+    pub fn completeness(&self) -> Completeness {
+        let mut completeness = Completeness::default();
+        completeness.section("typeSpecification", &self.type_specification, |fields, type_specification| {
+            fields.defaulted("seriesName", type_specification.series_name.is_empty());
+            fields.defaulted("seriesDescription", type_specification.series_description.is_none());
+            fields.defaulted("maxLoadMass", type_specification.max_load_mass.raw() == 0.0);
+            fields.defaulted("localizationTypes", type_specification.localization_types.is_empty());
+            fields.defaulted("navigationTypes", type_specification.navigation_types.is_empty());
+        });
+        completeness.section("physicalParameters", &self.physical_parameters, |fields, physical_parameters| {
+            fields.defaulted("speedMin", physical_parameters.speed_min.raw() == 0.0);
+            fields.defaulted("speedMax", physical_parameters.speed_max.raw() == 0.0);
+            fields.defaulted("accelerationMax", physical_parameters.acceleration_max.raw() == 0.0);
+            fields.defaulted("decelerationMax", physical_parameters.deceleration_max.raw() == 0.0);
+            fields.defaulted("heightMin", physical_parameters.height_min.is_none());
+            fields.defaulted("heightMax", physical_parameters.height_max.raw() == 0.0);
+            fields.defaulted("width", physical_parameters.width.raw() == 0.0);
+            fields.defaulted("length", physical_parameters.length.raw() == 0.0);
+        });
+        completeness.section("protocolLimits", &self.protocol_limits, |fields, protocol_limits| {
+            let strings = &protocol_limits.max_string_lens;
+            for (name, value) in [
+                ("maxStringLens/msgLen", strings.msg_len),
+                ("maxStringLens/topicSerialLen", strings.topic_serial_len),
+                ("maxStringLens/topicElemLen", strings.topic_elem_len),
+                ("maxStringLens/idLen", strings.id_len),
+                ("maxStringLens/enumLen", strings.enum_len),
+                ("maxStringLens/loadIdLen", strings.load_id_len),
+            ] {
+                fields.defaulted(name, value.unwrap_or(0) == 0);
+            }
+            fields.defaulted("maxStringLens/idNumericalOnly", strings.id_numerical_only.is_none());
+            let arrays = &protocol_limits.max_array_lens;
+            for (name, value) in [
+                ("maxArrayLens/order.nodes", arrays.order_nodes),
+                ("maxArrayLens/order.edges", arrays.order_edges),
+                ("maxArrayLens/node.actions", arrays.node_actions),
+                ("maxArrayLens/edge.actions", arrays.edge_actions),
+                ("maxArrayLens/actions.actionsParameters", arrays.actions_actions_parameters),
+                ("maxArrayLens/instantActions", arrays.instant_actions),
+                ("maxArrayLens/trajectory.knotVector", arrays.trajectory_knot_vector),
+                ("maxArrayLens/trajectory.controlPoints", arrays.trajectory_control_points),
+                ("maxArrayLens/state.nodeStates", arrays.state_node_states),
+                ("maxArrayLens/state.edgeStates", arrays.state_edge_states),
+                ("maxArrayLens/state.loads", arrays.state_loads),
+                ("maxArrayLens/state.actionStates", arrays.state_action_states),
+                ("maxArrayLens/state.errors", arrays.state_errors),
+                ("maxArrayLens/state.information", arrays.state_information),
+                ("maxArrayLens/error.errorReferences", arrays.error_error_references),
+                ("maxArrayLens/information.infoReferences", arrays.information_info_references),
+            ] {
+                fields.defaulted(name, value == 0);
+            }
+            let timing = &protocol_limits.timing;
+            fields.defaulted("timing/minOrderInterval", timing.min_order_interval.raw() == 0.0);
+            fields.defaulted("timing/minStateInterval", timing.min_state_interval.raw() == 0.0);
+            fields.defaulted("timing/defaultStateInterval", timing.default_state_interval.is_none());
+            fields.defaulted("timing/visualizationInterval", timing.visualization_interval.is_none());
+        });
+        completeness.section("protocolFeatures", &self.protocol_features, |fields, protocol_features| {
+            fields.defaulted("optionalParameters", protocol_features.optional_parameters.is_empty());
+            fields.defaulted("agvActions", protocol_features.agv_actions.is_empty());
+        });
+        completeness.section("agvGeometry", &self.agv_geometry, |fields, agv_geometry| {
+            fields.defaulted("wheelDefinitions", agv_geometry.wheel_definitions.is_empty());
+            fields.defaulted("envelopes2d", agv_geometry.envelopes2d.is_empty());
+            fields.defaulted("envelopes3d", agv_geometry.envelopes3d.is_empty());
+        });
+        completeness.section("loadSpecification", &self.load_specification, |fields, load_specification| {
+            fields.defaulted("loadPositions", load_specification.load_positions.is_empty());
+            fields.defaulted("loadSets", load_specification.load_sets.is_empty());
+        });
+        completeness
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::factsheet::{Factsheet, LoadSpecification, PhysicalParameters};
+    use crate::timestamp::from_unix_millis;
+
+    fn factsheet() -> Factsheet {
+        Factsheet {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            type_specification: None,
+            physical_parameters: None,
+            protocol_limits: None,
+            protocol_features: None,
+            agv_geometry: None,
+            load_specification: None,
+            localization_parameters: None,
+        }
+    }
+
+    #[rstest]
+    fn test_Factsheet_completeness_reports_missing_sections() {
+        let completeness = factsheet().completeness();
+
+        assert_that!(completeness.missing_sections, eq(&vec!["typeSpecification", "physicalParameters", "protocolLimits", "protocolFeatures", "agvGeometry", "loadSpecification"]));
+        assert_that!(completeness.defaulted_fields, empty());
+        assert_that!(completeness.is_complete(), eq(false));
+    }
+
+    #[rstest]
+    fn test_Factsheet_completeness_reports_defaulted_fields() {
+        let mut factsheet = factsheet();
+        factsheet.physical_parameters = Some(PhysicalParameters {
+            speed_min: checked(0.1),
+            speed_max: checked(2.0),
+            acceleration_max: checked(0.0),
+            deceleration_max: checked(1.0),
+            height_min: Some(checked(0.2)),
+            height_max: checked(2.0),
+            width: checked(1.0),
+            length: checked(1.5),
+        });
+        factsheet.load_specification = Some(LoadSpecification { load_positions: vec!["front".into()], load_sets: vec![] });
+
+        let completeness = factsheet.completeness();
+
+        assert_that!(completeness.missing_sections, eq(&vec!["typeSpecification", "protocolLimits", "protocolFeatures", "agvGeometry"]));
+        assert_that!(completeness.defaulted_fields, eq(&vec!["/physicalParameters/accelerationMax", "/loadSpecification/loadSets"]));
+    }
+}
@@ -1,16 +1,24 @@
 use alloc::string::String;
 use crate::common::{HeaderId, Timestamp};
+use crate::header::AgvIdentity;
 
 /// AGV connection state reported as a last will message. Has to be sent with retain flag. Once the AGV comes online, it has to send this message on its connect topic, with the connection_state enum set to "ONLINE". The last will message is to be configured with the connection state set to "CONNECTIONBROKEN". Thus, if the AGV disconnects from the broker, master control gets notified via the topic "connection". If the AGV is disconnecting in an orderly fashion (e.g. shutting down, sleeping), the AGV is to publish a message on this topic with the connection_state set to "OFFLINE".
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct Connection {
     /// header_id of the message. The header_id is defined per topic and incremented by 1 with each sent (but not necessarily received) message.
+    #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
     pub header_id: HeaderId,
     /// Timestamp (ISO8601, UTC); YYYY-MM-DDTHH:mm:ss.ssZ; e.g. 2017-04-15T11:40:03.12Z
+    #[cfg_attr(feature = "defmt", defmt(Display2Format))]
+    #[cfg_attr(feature = "serde", serde(with = "crate::timestamp"))]
+    #[cfg_attr(feature = "ts-rs", ts(as = "String"))]
     pub timestamp: Timestamp,
     /// Version of the protocol [Major].[Minor].[Patch], e.g. 1.3.2
     pub version: String,
@@ -22,8 +30,43 @@ pub struct Connection {
     pub connection_state: ConnectionState
 }
 
+impl Connection {
+
+    /// Returns the message `header_id` of the AGV `identity` reporting `connection_state`.
+    pub fn new(identity: &AgvIdentity, header_id: HeaderId, timestamp: Timestamp, connection_state: ConnectionState) -> Self {
+        let header = identity.header(header_id, timestamp);
+        Connection {
+            header_id: header.header_id,
+            timestamp: header.timestamp,
+            version: header.version,
+            manufacturer: header.manufacturer,
+            serial_number: header.serial_number,
+            connection_state,
+        }
+    }
+
+    /// Returns the message of the AGV `identity` reporting that it came online.
+    pub fn online(identity: &AgvIdentity, header_id: HeaderId, timestamp: Timestamp) -> Self {
+        Connection::new(identity, header_id, timestamp, ConnectionState::Online)
+    }
+
+    /// Returns the message of the AGV `identity` reporting that it goes offline in an orderly fashion.
+    pub fn offline(identity: &AgvIdentity, header_id: HeaderId, timestamp: Timestamp) -> Self {
+        Connection::new(identity, header_id, timestamp, ConnectionState::Offline)
+    }
+
+    /// Returns the message of the AGV `identity` reporting that its connection broke unexpectedly, which is to be
+    /// registered as last will with the broker.
+    pub fn broken(identity: &AgvIdentity, header_id: HeaderId, timestamp: Timestamp) -> Self {
+        Connection::new(identity, header_id, timestamp, ConnectionState::ConnectionBroken)
+    }
+}
+
 /// Connection state.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -36,3 +79,57 @@ pub enum ConnectionState {
     /// The connection between AGV and broker has unexpectedly ended.
     ConnectionBroken
 }
+
+#[cfg(all(test, feature = "serde"))]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::string::String;
+    use crate::timestamp::from_unix_millis;
+    use googletest::prelude::*;
+    use rstest::rstest;
+    use crate::header::AgvIdentity;
+    use super::{Connection, ConnectionState};
+
+    #[rstest]
+    fn test_serde_Connection() {
+
+        let connection = Connection {
+            header_id: 1,
+            timestamp: from_unix_millis(1_492_256_403_120).unwrap(),
+            version: String::from("2.0.0"),
+            manufacturer: String::from("Fubar Co."),
+            serial_number: String::from("1234"),
+            connection_state: ConnectionState::Online,
+        };
+
+        let json = r#"{"headerId":1,"timestamp":"2017-04-15T11:40:03.120Z","version":"2.0.0","manufacturer":"Fubar Co.","serialNumber":"1234","connectionState":"ONLINE"}"#;
+
+        let to = serde_json::to_string(&connection);
+        let from = serde_json::from_str::<Connection>(json);
+
+        assert_that!(to, ok(eq(json)));
+
+        assert_that!(from, ok(matches_pattern!(
+            Connection {
+                header_id: eq(&1),
+                timestamp: eq(&from_unix_millis(1_492_256_403_120).unwrap()),
+                connection_state: matches_pattern!(ConnectionState::Online)
+            }
+        )));
+    }
+
+    #[rstest]
+    fn test_Connection_broken() {
+        let identity = AgvIdentity::new("Fubar Co.", "1234");
+
+        let connection = Connection::broken(&identity, 7, from_unix_millis(1_492_256_403_120).unwrap());
+
+        assert_that!(connection, matches_pattern!(Connection {
+            header_id: eq(&7),
+            version: eq("2.0.0"),
+            manufacturer: eq("Fubar Co."),
+            serial_number: eq("1234"),
+            connection_state: eq(&ConnectionState::ConnectionBroken)
+        }));
+    }
+}
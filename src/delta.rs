@@ -0,0 +1,177 @@
+//!
+//! Changes between two consecutive states of an AGV.
+//!
+use alloc::vec::Vec;
+
+use crate::checked::Raw;
+use crate::common::AgvPosition;
+use crate::state::{ActionState, ActionStatus, Error, NodeState, State};
+
+/// Changes between two consecutive states of an AGV, see [`StateDelta::between`].
+#[derive(Clone, Default)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct StateDelta<'a> {
+    /// Position of the next state if the AGV moved, i.e. its coordinates, orientation, map or initialization changed,
+    /// or if the position was reported for the first time.
+    pub agv_position: Option<&'a AgvPosition>,
+    /// States of the actions of the next state which have become `FINISHED` since the previous state.
+    pub finished_actions: Vec<&'a ActionState>,
+    /// States of the actions of the next state which have become `FAILED` since the previous state.
+    pub failed_actions: Vec<&'a ActionState>,
+    /// Errors of the next state which were not reported by the previous state.
+    pub new_errors: Vec<&'a Error>,
+    /// Errors of the previous state which are no longer reported by the next state.
+    pub resolved_errors: Vec<&'a Error>,
+    /// Node states of the previous state which have been traversed, i.e. which are no longer contained in the next
+    /// state of the same order.
+    pub traversed_nodes: Vec<&'a NodeState>,
+}
+
+impl<'a> StateDelta<'a> {
+
+    /// Returns the changes from the `previous` to the `next` state of an AGV.
+    ///
+    /// Actions are matched by their ids and errors by their types and references. Nodes are only considered traversed
+    /// if both states refer to the same order, as the node states are replaced when a new order is accepted.
+    pub fn between(previous: &'a State, next: &'a State) -> Self {
+        let agv_position = next.agv_position.as_ref()
+            .filter(|position| previous.agv_position.as_ref().map_or(true, |previous| !same_position(previous, position)));
+        let became = |status: ActionStatus| next.action_states.iter()
+            .filter(|action| action.action_status == status)
+            .filter(|action| !previous.action_states.iter().any(|previous| previous.action_id == action.action_id && previous.action_status == status))
+            .collect::<Vec<_>>();
+        let missing = |errors: &'a [Error], others: &'a [Error]| errors.iter()
+            .filter(|error| !others.iter().any(|other| same_error(error, other)))
+            .collect::<Vec<_>>();
+        let traversed_nodes = if previous.order_id == next.order_id {
+            previous.node_states.iter()
+                .filter(|node| !next.node_states.iter().any(|next| next.sequence_id == node.sequence_id))
+                .collect()
+        }
+        else {
+            Vec::new()
+        };
+
+        StateDelta {
+            agv_position,
+            finished_actions: became(ActionStatus::Finished),
+            failed_actions: became(ActionStatus::Failed),
+            new_errors: missing(&next.errors, &previous.errors),
+            resolved_errors: missing(&previous.errors, &next.errors),
+            traversed_nodes,
+        }
+    }
+
+    /// Returns `true` if none of the changes tracked by the delta occurred.
+    pub fn is_empty(&self) -> bool {
+        self.agv_position.is_none()
+            && self.finished_actions.is_empty()
+            && self.failed_actions.is_empty()
+            && self.new_errors.is_empty()
+            && self.resolved_errors.is_empty()
+            && self.traversed_nodes.is_empty()
+    }
+}
+
+fn same_position(position: &AgvPosition, other: &AgvPosition) -> bool {
+    position.x.raw() == other.x.raw()
+        && position.y.raw() == other.y.raw()
+        && position.theta.raw() == other.theta.raw()
+        && position.map_id == other.map_id
+        && position.position_initialized == other.position_initialized
+}
+
+fn same_error(error: &Error, other: &Error) -> bool {
+    error.error_type == other.error_type
+        && error.error_references.len() == other.error_references.len()
+        && error.error_references.iter().zip(&other.error_references)
+            .all(|(reference, other)| reference.reference_key == other.reference_key && reference.reference_value == other.reference_value)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::{checked, Raw};
+    use crate::common::AgvPosition;
+    use crate::error::StandardErrorType;
+    use crate::state::{ActionState, ActionStatus, Error, NodeState, State};
+    use crate::timestamp::from_unix_millis;
+    use super::StateDelta;
+
+    fn state(node_ids: &[(&str, u64)], actions: &[(&str, ActionStatus)], errors: Vec<Error>) -> State {
+        State::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap())
+            .order("o1", 0)
+            .node_states(node_ids.iter()
+                .map(|(node_id, sequence_id)| NodeState { node_id: (*node_id).into(), sequence_id: *sequence_id, node_description: None, node_position: None, released: true })
+                .collect())
+            .action_states(actions.iter()
+                .map(|(action_id, action_status)| ActionState { action_id: (*action_id).into(), action_type: None, action_description: None, action_status: *action_status, result_description: None })
+                .collect())
+            .errors(errors)
+            .build()
+    }
+
+    fn error(reference_value: &str) -> Error {
+        Error::builder(StandardErrorType::OrderError).reference("orderId", reference_value).build()
+    }
+
+    fn agv_position(x: f32) -> AgvPosition {
+        AgvPosition {
+            x: checked(x),
+            y: checked(0.0),
+            theta: checked(0.0),
+            map_id: "map".into(),
+            map_description: None,
+            position_initialized: true,
+            localization_score: None,
+            deviation_range: None,
+        }
+    }
+
+    #[rstest]
+    fn test_StateDelta_between_equal_states_is_empty() {
+        let state = state(&[("n1", 0)], &[("a1", ActionStatus::Finished)], vec![error("o1")]);
+
+        assert_that!(StateDelta::between(&state, &state.clone()).is_empty(), eq(true));
+    }
+
+    #[rstest]
+    fn test_StateDelta_between_reports_changes() {
+        let previous = state(&[("n1", 0), ("n2", 2)], &[("a1", ActionStatus::Running), ("a2", ActionStatus::Running)], vec![error("o1")]);
+        let next = state(&[("n2", 2)], &[("a1", ActionStatus::Finished), ("a2", ActionStatus::Failed)], vec![error("o2")]);
+
+        let delta = StateDelta::between(&previous, &next);
+
+        assert_that!(delta.agv_position.is_none(), eq(true));
+        assert_that!(delta.finished_actions.iter().map(|action| action.action_id.as_str()).collect::<Vec<_>>(), eq(&vec!["a1"]));
+        assert_that!(delta.failed_actions.iter().map(|action| action.action_id.as_str()).collect::<Vec<_>>(), eq(&vec!["a2"]));
+        assert_that!(delta.new_errors.iter().map(|error| error.error_references[0].reference_value.as_str()).collect::<Vec<_>>(), eq(&vec!["o2"]));
+        assert_that!(delta.resolved_errors.iter().map(|error| error.error_references[0].reference_value.as_str()).collect::<Vec<_>>(), eq(&vec!["o1"]));
+        assert_that!(delta.traversed_nodes.iter().map(|node| node.node_id.as_str()).collect::<Vec<_>>(), eq(&vec!["n1"]));
+    }
+
+    #[rstest]
+    #[case::unchanged(Some(1.0), Some(1.0), None)]
+    #[case::moved(Some(1.0), Some(2.0), Some(2.0))]
+    #[case::first_reported(None, Some(1.0), Some(1.0))]
+    #[case::lost(Some(1.0), None, None)]
+    fn test_StateDelta_between_reports_moved_position(#[case] previous: Option<f32>, #[case] next: Option<f32>, #[case] expected: Option<f32>) {
+        let previous = State { agv_position: previous.map(agv_position), ..state(&[], &[], vec![]) };
+        let next = State { agv_position: next.map(agv_position), ..state(&[], &[], vec![]) };
+
+        assert_that!(StateDelta::between(&previous, &next).agv_position.map(|position| position.x.raw()), eq(expected));
+    }
+
+    #[rstest]
+    fn test_StateDelta_between_orders_does_not_report_traversed_nodes() {
+        let previous = state(&[("n1", 0)], &[], vec![]);
+        let next = State { order_id: "o2".into(), ..state(&[], &[], vec![]) };
+
+        assert_that!(StateDelta::between(&previous, &next).traversed_nodes, empty());
+    }
+}
@@ -0,0 +1,108 @@
+//!
+//! Check whether an AGV traverses a node within the deviation allowed by its node position.
+//!
+use crate::angle::diff;
+use crate::checked::Raw;
+use crate::common::{AgvPosition, NodePosition};
+
+/// Normal tolerance of an AGV, which applies to nodes whose allowed deviation is zero or absent.
+#[derive(Clone, Copy, Default, PartialEq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct VehicleTolerance {
+    /// Deviation radius in meters.
+    pub xy: f32,
+    /// Deviation of the orientation in radians.
+    pub theta: f32,
+}
+
+/// Returns the allowed `deviation` of a node, or `tolerance` if the deviation is zero or less or absent, which means
+/// that the normal tolerance of the AGV applies.
+pub(crate) fn allowed_deviation(deviation: Option<f32>, tolerance: f32) -> f32 {
+    deviation.filter(|deviation| *deviation > 0.0).unwrap_or(tolerance)
+}
+
+impl NodePosition {
+
+    /// Returns `true` if `position` traverses the node without tolerance of the AGV, see
+    /// [`NodePosition::contains_within`].
+    pub fn contains(&self, position: &AgvPosition) -> bool {
+        self.contains_within(position, &VehicleTolerance::default())
+    }
+
+    /// Returns `true` if `position` is on the map of the node, not further away than `allowedDeviationXY` and, if the
+    /// node defines `theta`, its orientation deviates by at most `allowedDeviationTheta`.
+    ///
+    /// An allowed deviation of zero or none means that the normal `tolerance` of the AGV applies.
+    pub fn contains_within(&self, position: &AgvPosition, tolerance: &VehicleTolerance) -> bool {
+        let allowed_xy = allowed_deviation(self.allowed_deviation_xy.map(Raw::raw), tolerance.xy);
+        let (dx, dy) = (position.x.raw() - self.x.raw(), position.y.raw() - self.y.raw());
+        self.map_id == position.map_id
+            && dx * dx + dy * dy <= allowed_xy * allowed_xy
+            && self.theta.map_or(true, |theta| {
+                diff(position.theta.raw(), theta.raw()).abs() <= allowed_deviation(self.allowed_deviation_theta.map(Raw::raw), tolerance.theta)
+            })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::f32::consts::PI;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::common::{AgvPosition, NodePosition};
+    use super::VehicleTolerance;
+
+    fn node_position(theta: Option<f32>, allowed_deviation_xy: Option<f32>, allowed_deviation_theta: Option<f32>) -> NodePosition {
+        NodePosition {
+            x: checked(1.0),
+            y: checked(2.0),
+            theta: theta.map(checked),
+            allowed_deviation_xy: allowed_deviation_xy.map(checked),
+            allowed_deviation_theta: allowed_deviation_theta.map(checked),
+            map_id: "map".into(),
+            map_description: None,
+        }
+    }
+
+    fn agv_position(x: f32, y: f32, theta: f32, map_id: &str) -> AgvPosition {
+        AgvPosition {
+            x: checked(x),
+            y: checked(y),
+            theta: checked(theta),
+            map_id: map_id.into(),
+            map_description: None,
+            position_initialized: true,
+            localization_score: None,
+            deviation_range: None,
+        }
+    }
+
+    #[rstest]
+    #[case::exact(node_position(None, None, None), agv_position(1.0, 2.0, 3.0, "map"), true)]
+    #[case::other_map(node_position(None, Some(0.5), None), agv_position(1.0, 2.0, 0.0, "other"), false)]
+    #[case::within_radius(node_position(None, Some(0.5), None), agv_position(1.3, 2.3, 0.0, "map"), true)]
+    #[case::outside_radius(node_position(None, Some(0.5), None), agv_position(1.4, 2.4, 0.0, "map"), false)]
+    #[case::zero_radius(node_position(None, Some(0.0), None), agv_position(1.01, 2.0, 0.0, "map"), false)]
+    #[case::within_theta(node_position(Some(0.5), None, Some(0.2)), agv_position(1.0, 2.0, 0.6, "map"), true)]
+    #[case::outside_theta(node_position(Some(0.5), None, Some(0.2)), agv_position(1.0, 2.0, 0.8, "map"), false)]
+    #[case::theta_across_pi(node_position(Some(PI - 0.05), None, Some(0.2)), agv_position(1.0, 2.0, -PI + 0.05, "map"), true)]
+    #[case::zero_theta(node_position(Some(0.5), None, Some(0.0)), agv_position(1.0, 2.0, 0.51, "map"), false)]
+    fn test_NodePosition_contains(#[case] node_position: NodePosition, #[case] agv_position: AgvPosition, #[case] expected: bool) {
+        assert_that!(node_position.contains(&agv_position), eq(expected));
+    }
+
+    #[rstest]
+    #[case::zero_radius(node_position(None, Some(0.0), None), agv_position(1.05, 2.0, 0.0, "map"), true)]
+    #[case::absent_radius(node_position(None, None, None), agv_position(1.2, 2.0, 0.0, "map"), false)]
+    #[case::radius_exceeds_tolerance(node_position(None, Some(0.5), None), agv_position(1.4, 2.0, 0.0, "map"), true)]
+    #[case::zero_theta(node_position(Some(0.5), None, Some(0.0)), agv_position(1.0, 2.0, 0.55, "map"), true)]
+    #[case::theta_below_tolerance(node_position(Some(0.5), None, Some(0.01)), agv_position(1.0, 2.0, 0.55, "map"), false)]
+    fn test_NodePosition_contains_within(#[case] node_position: NodePosition, #[case] agv_position: AgvPosition, #[case] expected: bool) {
+        let tolerance = VehicleTolerance { xy: 0.1, theta: 0.1 };
+
+        assert_that!(node_position.contains_within(&agv_position, &tolerance), eq(expected));
+    }
+}
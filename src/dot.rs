@@ -0,0 +1,171 @@
+//!
+//! Export of the order graph in the [DOT](https://graphviz.org/doc/info/lang.html) language of Graphviz.
+//!
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::action::Action;
+use crate::order::{Node, Order};
+
+impl Order {
+
+    /// Returns the graph of the order in the DOT language, e.g. to render it via `dot -Tsvg`.
+    ///
+    /// Nodes and edges are labeled with their ids, sequence ids and actions. Released nodes and edges (base) are drawn
+    /// solid, others (horizon) dashed. A node passed more than once is drawn once, labeled with all its sequence ids.
+    /// Edges referring to unknown nodes implicitly add these nodes to the graph.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        // Writing into a String does not fail.
+        let _ = self.write_dot(&mut dot);
+        dot
+    }
+
+    fn write_dot(&self, dot: &mut String) -> core::fmt::Result {
+        writeln!(dot, "digraph \"{}\" {{", escape(&self.order_id))?;
+        writeln!(dot, "  label=\"orderId: {}, orderUpdateId: {}\";", escape(&self.order_id), self.order_update_id)?;
+
+        let mut written = Vec::<&str>::new();
+        for node in &self.nodes {
+            if written.contains(&node.node_id.as_str()) {
+                continue;
+            }
+            written.push(&node.node_id);
+            let occurrences = self.nodes.iter()
+                .filter(|other| other.node_id == node.node_id)
+                .collect::<Vec<&Node>>();
+            let sequence_ids = occurrences.iter()
+                .map(|node| node.sequence_id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let actions = occurrences.iter()
+                .flat_map(|node| node.actions.iter())
+                .collect::<Vec<_>>();
+            let released = occurrences.iter().any(|node| node.released);
+            writeln!(dot, "  \"{}\" [label=\"{}\\nsequenceId: {}{}\", style={}];",
+                escape(&node.node_id),
+                escape(&node.node_id),
+                sequence_ids,
+                label_actions(&actions),
+                style(released))?;
+        }
+
+        for edge in &self.edges {
+            let actions = edge.actions.iter().collect::<Vec<_>>();
+            writeln!(dot, "  \"{}\" -> \"{}\" [label=\"{}\\nsequenceId: {}{}\", style={}];",
+                escape(&edge.start_node_id),
+                escape(&edge.end_node_id),
+                escape(&edge.edge_id),
+                edge.sequence_id,
+                label_actions(&actions),
+                style(edge.released))?;
+        }
+
+        writeln!(dot, "}}")
+    }
+}
+
+fn style(released: bool) -> &'static str {
+    if released { "solid" } else { "dashed" }
+}
+
+fn label_actions(actions: &[&Action]) -> String {
+    actions.iter()
+        .map(|action| format!("\\n{} ({})", escape(&action.action_type), escape(&action.action_id)))
+        .collect()
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::action::{Action, BlockingType};
+    use crate::order::{Edge, Node, Order};
+    use crate::timestamp::from_unix_millis;
+
+    fn action(action_type: &str, action_id: &str) -> Action {
+        Action {
+            action_type: action_type.into(),
+            action_id: action_id.into(),
+            action_description: None,
+            blocking_type: BlockingType::Hard,
+            action_parameters: vec![],
+        }
+    }
+
+    fn node(node_id: &str, sequence_id: u64, released: bool, actions: Vec<Action>) -> Node {
+        Node {
+            node_id: node_id.into(),
+            sequence_id,
+            node_description: None,
+            released,
+            node_position: None,
+            actions,
+        }
+    }
+
+    fn edge(edge_id: &str, sequence_id: u64, released: bool, start_node_id: &str, end_node_id: &str) -> Edge {
+        Edge {
+            edge_id: edge_id.into(),
+            sequence_id,
+            edge_description: None,
+            released,
+            start_node_id: start_node_id.into(),
+            end_node_id: end_node_id.into(),
+            max_speed: None,
+            max_height: None,
+            min_height: None,
+            orientation: None,
+            orientation_type: None,
+            direction: None,
+            rotation_allowed: None,
+            max_rotation_speed: None,
+            length: None,
+            trajectory: None,
+            actions: vec![],
+        }
+    }
+
+    #[rstest]
+    fn test_Order_to_dot() {
+        let order = Order {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            order_id: "order \"1\"".into(),
+            order_update_id: 3,
+            zone_set_id: None,
+            nodes: vec![
+                node("n1", 0, true, vec![]),
+                node("n2", 2, true, vec![action("pick", "a1")]),
+                node("n1", 4, false, vec![]),
+            ],
+            edges: vec![
+                edge("e1", 1, true, "n1", "n2"),
+                edge("e2", 3, false, "n2", "n1"),
+            ],
+        };
+
+        assert_that!(order.to_dot(), eq(concat!(
+            "digraph \"order \\\"1\\\"\" {\n",
+            "  label=\"orderId: order \\\"1\\\", orderUpdateId: 3\";\n",
+            "  \"n1\" [label=\"n1\\nsequenceId: 0, 4\", style=solid];\n",
+            "  \"n2\" [label=\"n2\\nsequenceId: 2\\npick (a1)\", style=solid];\n",
+            "  \"n1\" -> \"n2\" [label=\"e1\\nsequenceId: 1\", style=solid];\n",
+            "  \"n2\" -> \"n1\" [label=\"e2\\nsequenceId: 3\", style=dashed];\n",
+            "}\n",
+        )));
+    }
+}
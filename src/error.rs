@@ -0,0 +1,255 @@
+//!
+//! Error types defined by the specification and constructors of errors referencing their cause.
+//!
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::common::HeaderId;
+use crate::order::Order;
+use crate::state::{Error, ErrorLevel, ErrorReference};
+
+/// Type of an error as used in the `errorType` of an [`Error`].
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum StandardErrorType {
+    /// A received message is not valid, e.g. it does not match the JSON schema.
+    ValidationError,
+    /// An order cannot be executed, e.g. the AGV is still executing another order.
+    OrderError,
+    /// An order update is outdated or does not continue the current base.
+    OrderUpdateError,
+    /// The nodes and edges of an order cannot be traversed, e.g. the first node is not within reach.
+    NoRouteError,
+    /// The zone set of an order is not known or differs from the zone set used by the AGV.
+    ZoneSetError,
+    /// An error type not defined by the specification, e.g. a manufacturer specific one.
+    Other(String),
+}
+
+impl StandardErrorType {
+
+    /// Returns the error type as used in messages, e.g. `orderUpdateError`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            StandardErrorType::ValidationError => "validationError",
+            StandardErrorType::OrderError => "orderError",
+            StandardErrorType::OrderUpdateError => "orderUpdateError",
+            StandardErrorType::NoRouteError => "noRouteError",
+            StandardErrorType::ZoneSetError => "zoneSetError",
+            StandardErrorType::Other(error_type) => error_type,
+        }
+    }
+}
+
+impl From<&str> for StandardErrorType {
+
+    fn from(error_type: &str) -> Self {
+        match error_type {
+            "validationError" => StandardErrorType::ValidationError,
+            "orderError" => StandardErrorType::OrderError,
+            "orderUpdateError" => StandardErrorType::OrderUpdateError,
+            "noRouteError" => StandardErrorType::NoRouteError,
+            "zoneSetError" => StandardErrorType::ZoneSetError,
+            other => StandardErrorType::Other(other.into()),
+        }
+    }
+}
+
+impl From<StandardErrorType> for String {
+
+    fn from(error_type: StandardErrorType) -> Self {
+        match error_type {
+            StandardErrorType::Other(error_type) => error_type,
+            error_type => error_type.as_str().into(),
+        }
+    }
+}
+
+impl core::fmt::Display for StandardErrorType {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl Error {
+
+    /// Returns a builder of an error of type `error_type`, which is a warning without references and description unless
+    /// specified otherwise.
+    pub fn builder(error_type: impl Into<String>) -> ErrorBuilder {
+        ErrorBuilder {
+            error: Self {
+                error_type: error_type.into(),
+                error_references: Vec::new(),
+                error_description: None,
+                error_level: ErrorLevel::Warning,
+            },
+        }
+    }
+
+    /// Returns the type of the error, which is [`StandardErrorType::Other`] for types not defined by the specification.
+    pub fn standard_error_type(&self) -> StandardErrorType {
+        StandardErrorType::from(self.error_type.as_str())
+    }
+
+    /// Creates a warning of type `validationError` referencing the `headerId` of the invalid message.
+    pub fn validation_error(header_id: HeaderId, description: impl Into<String>) -> Self {
+        Self::builder(StandardErrorType::ValidationError)
+            .reference("headerId", header_id)
+            .description(description)
+            .build()
+    }
+
+    /// Creates a warning of type `orderError` referencing the `headerId`, `orderId` and `orderUpdateId` of `order`.
+    pub fn order_error(order: &Order, description: impl Into<String>) -> Self {
+        Self::for_order(StandardErrorType::OrderError, order, description)
+    }
+
+    /// Creates a warning of type `orderUpdateError` referencing the `headerId`, `orderId` and `orderUpdateId` of
+    /// `order`.
+    pub fn order_update_error(order: &Order, description: impl Into<String>) -> Self {
+        Self::for_order(StandardErrorType::OrderUpdateError, order, description)
+    }
+
+    /// Creates a warning of type `noRouteError` referencing the `headerId`, `orderId` and `orderUpdateId` of `order`.
+    pub fn no_route_error(order: &Order, description: impl Into<String>) -> Self {
+        Self::for_order(StandardErrorType::NoRouteError, order, description)
+    }
+
+    /// Creates a warning of type `zoneSetError` referencing the `headerId`, `orderId`, `orderUpdateId` and, if
+    /// present, the `zoneSetId` of `order`.
+    pub fn zone_set_error(order: &Order, description: impl Into<String>) -> Self {
+        let mut error = Self::for_order(StandardErrorType::ZoneSetError, order, description);
+        error.error_references.extend(order.zone_set_id.as_ref().map(|zone_set_id| ErrorReference {
+            reference_key: "zoneSetId".into(),
+            reference_value: zone_set_id.to_string(),
+        }));
+        error
+    }
+
+    /// Creates a warning of `error_type` referencing the `headerId`, `orderId` and `orderUpdateId` of `order`.
+    pub fn for_order(error_type: StandardErrorType, order: &Order, description: impl Into<String>) -> Self {
+        Self::builder(error_type)
+            .reference("headerId", order.header_id)
+            .reference("orderId", &order.order_id)
+            .reference("orderUpdateId", order.order_update_id)
+            .description(description)
+            .build()
+    }
+}
+
+/// Builder of an [`Error`], see [`Error::builder`].
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct ErrorBuilder {
+    error: Error,
+}
+
+impl ErrorBuilder {
+
+    /// Sets the level of the error.
+    pub fn level(mut self, error_level: ErrorLevel) -> Self {
+        self.error.error_level = error_level;
+        self
+    }
+
+    /// Appends a reference to the source of the error, e.g. `("orderId", "o1")` or `("orderUpdateId", 2)`.
+    pub fn reference(mut self, reference_key: impl Into<String>, reference_value: impl ToString) -> Self {
+        self.error.error_references.push(ErrorReference {
+            reference_key: reference_key.into(),
+            reference_value: reference_value.to_string(),
+        });
+        self
+    }
+
+    /// Sets the verbose description of the error.
+    pub fn description(mut self, error_description: impl Into<String>) -> Self {
+        self.error.error_description = Some(error_description.into());
+        self
+    }
+
+    /// Returns the error.
+    pub fn build(self) -> Error {
+        self.error
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::order::Order;
+    use crate::state::{Error, ErrorLevel, ErrorReference};
+    use crate::timestamp::from_unix_millis;
+    use super::StandardErrorType;
+
+    #[rstest]
+    #[case(StandardErrorType::ValidationError, "validationError")]
+    #[case(StandardErrorType::OrderError, "orderError")]
+    #[case(StandardErrorType::OrderUpdateError, "orderUpdateError")]
+    #[case(StandardErrorType::NoRouteError, "noRouteError")]
+    #[case(StandardErrorType::ZoneSetError, "zoneSetError")]
+    #[case(StandardErrorType::Other("batteryLowError".into()), "batteryLowError")]
+    fn test_StandardErrorType_wire_string(#[case] error_type: StandardErrorType, #[case] expected: &str) {
+        assert_that!(error_type.to_string(), eq(expected));
+        assert_that!(StandardErrorType::from(expected), eq(&error_type));
+    }
+
+    #[rstest]
+    fn test_Error_zone_set_error_references_order() {
+        let order = Order {
+            header_id: 7,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            order_id: "o1".into(),
+            order_update_id: 3,
+            zone_set_id: Some("z1".into()),
+            nodes: vec![],
+            edges: vec![],
+        };
+
+        let error = Error::zone_set_error(&order, "unknown zone set");
+
+        assert_that!(error.standard_error_type(), eq(&StandardErrorType::ZoneSetError));
+        assert_that!(error.error_references.iter().map(|reference| (reference.reference_key.as_str(), reference.reference_value.as_str())).collect::<vec::Vec<_>>(), eq(&vec![
+            ("headerId", "7"),
+            ("orderId", "o1"),
+            ("orderUpdateId", "3"),
+            ("zoneSetId", "z1"),
+        ]));
+        assert_that!(error.error_description, some(eq("unknown zone set")));
+    }
+
+    #[rstest]
+    fn test_Error_validation_error_references_header() {
+        let error = Error::validation_error(42, "invalid JSON");
+
+        assert_that!(error.error_type, eq("validationError"));
+        assert_that!(error.error_references, elements_are![matches_pattern!(ErrorReference { reference_key: eq("headerId"), reference_value: eq("42") })]);
+    }
+
+    #[rstest]
+    fn test_Error_builder() {
+        let error = Error::builder(StandardErrorType::OrderUpdateError)
+            .level(ErrorLevel::Fatal)
+            .reference("orderId", "o1")
+            .reference("orderUpdateId", 2)
+            .description("outdated order update")
+            .build();
+
+        assert_that!(error, matches_pattern!(Error {
+            error_type: eq("orderUpdateError"),
+            error_references: elements_are![
+                matches_pattern!(ErrorReference { reference_key: eq("orderId"), reference_value: eq("o1") }),
+                matches_pattern!(ErrorReference { reference_key: eq("orderUpdateId"), reference_value: eq("2") }),
+            ],
+            error_description: some(eq("outdated order update")),
+            error_level: matches_pattern!(ErrorLevel::Fatal),
+        }));
+    }
+}
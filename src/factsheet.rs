@@ -1,17 +1,26 @@
 use alloc::string::String;
 use alloc::vec::Vec;
 use crate::common::{BoundingBoxReference, HeaderId, LoadDimensions, Timestamp};
+use crate::finite::Float;
+use crate::units::{Acceleration, Length, Mass, Speed};
 
 /// The factsheet provides basic information about a specific AGV type series. This information allows comparison of different AGV types and can be applied for the planning, dimensioning and simulation of an AGV system. The factsheet also includes information about AGV communication interfaces which are required for the integration of an AGV type series into a VD[M]A-5050-compliant master control.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct Factsheet {
     /// header_id of the message. The header_id is defined per topic and incremented by 1 with each sent (but not necessarily received) message.
+    #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
     pub header_id: HeaderId,
     /// Timestamp (ISO8601, UTC); YYYY-MM-DDTHH:mm:ss.ssZ; e.g. 2017-04-15T11:40:03.12Z
+    #[cfg_attr(feature = "defmt", defmt(Display2Format))]
+    #[cfg_attr(feature = "serde", serde(with = "crate::timestamp"))]
+    #[cfg_attr(feature = "ts-rs", ts(as = "String"))]
     pub timestamp: Timestamp,
     /// Version of the protocol [Major].[Minor].[Patch], e.g. 1.3.2
     pub version: String,
@@ -32,11 +41,15 @@ pub struct Factsheet {
     /// Abstract specification of load capabilities
     pub load_specification: Option<LoadSpecification>,
     /// Detailed specification of localization
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
     pub localization_parameters: Option<u64>
 }
 
 /// These parameters generally specify the class and the capabilities of the AGV.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -51,7 +64,8 @@ pub struct TypeSpecification {
     /// Simplified description of AGV class.
     pub agv_class: AgvClass,
     /// maximum loadable mass
-    pub max_load_mass: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub max_load_mass: Mass,
     /// simplified description of localization type
     pub localization_types: Vec<LocalizationType>,
     /// List of path planning types supported by the AGV, sorted by priority
@@ -59,7 +73,10 @@ pub struct TypeSpecification {
 }
 
 /// Simplified description of AGV kinematics-type.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -72,7 +89,10 @@ pub enum AgvKinematic {
 }
 
 /// Simplified description of AGV class.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -84,7 +104,10 @@ pub enum AgvClass {
     Carrier
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -98,7 +121,10 @@ pub enum LocalizationType {
     Grid
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -110,32 +136,46 @@ pub enum NavigationType {
 }
 
 /// These parameters specify the basic physical properties of the AGV.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct PhysicalParameters {
     /// minimal controlled continuous speed of the AGV
-    pub speed_min: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub speed_min: Speed,
     /// maximum speed of the AGV
-    pub speed_max: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub speed_max: Speed,
     /// maximum acceleration with maximum load
-    pub acceleration_max: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub acceleration_max: Acceleration,
     /// maximum deceleration with maximum load
-    pub deceleration_max: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub deceleration_max: Acceleration,
     /// minimum height of AGV
-    pub height_min: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub height_min: Option<Length>,
     /// maximum height of AGV
-    pub height_max: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub height_max: Length,
     /// width of AGV
-    pub width: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub width: Length,
     /// length of AGV
-    pub length: f32
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub length: Length
 }
 
 /// This JSON-object describes the protocol limitations of the AGV. If a parameter is not defined or set to zero then there is no explicit limit for this parameter.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -150,30 +190,42 @@ pub struct ProtocolLimits {
 }
 
 /// Maximum lengths of strings
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct MaxStringLens {
     /// maximum MQTT Message length
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
     pub msg_len: Option<u64>,
     /// maximum length of serial-number part in MQTT-topics. Affected Parameters: order.serial_number, instantActions.serial_number, state.SerialNumber, visualization.serial_number, connection.serial_number
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
     pub topic_serial_len: Option<u64>,
     /// maximum length of all other parts in MQTT-topics. Affected parameters: order.timestamp, order.version, order.manufacturer, instantActions.timestamp, instantActions.version, instantActions.manufacturer, state.timestamp, state.version, state.manufacturer, visualization.timestamp, visualization.version, visualization.manufacturer, connection.timestamp, connection.version, connection.manufacturer
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
     pub topic_elem_len: Option<u64>,
     /// maximum length of ID-Strings. Affected parameters: order.orderId, order.zoneSetId, node.nodeId, nodePosition.mapId, action.actionId, edge.edgeId, edge.startNodeId, edge.endNodeId
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
     pub id_len: Option<u64>,
     /// If true ID-strings need to contain numerical values only
     pub id_numerical_only: Option<bool>,
     /// maximum length of ENUM- and Key-Strings. Affected parameters: action.actionType, action.blockingType, edge.direction, actionParameter.key, state.operatingMode, load.loadPosition, load.loadType, actionState.actionStatus, error.errorType, error.errorLevel, errorReference.referenceKey, info.infoType, info.infoLevel, safetyState.eStop, connection.connectionState
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
     pub enum_len: Option<u64>,
     /// maximum length of loadId Strings
+    #[cfg_attr(feature = "ts-rs", ts(as = "Option<f64>"))]
     pub load_id_len: Option<u64>
 }
 
 /// Maximum lengths of arrays.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -230,24 +282,34 @@ pub struct MaxArrayLens {
 }
 
 /// Timing information.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct Timing {
     /// minimum interval sending order messages to the AGV
-    pub min_order_interval: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub min_order_interval: Float,
     /// minimum interval for sending state-messages
-    pub min_state_interval: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub min_state_interval: Float,
     /// default interval for sending state-messages if not defined, the default value from the main document is used
-    pub default_state_interval: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub default_state_interval: Option<Float>,
     /// default interval for sending messages on visualization topic
-    pub visualization_interval: Option<f32>
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub visualization_interval: Option<Float>
 }
 
 /// Supported features of VDA5050 protocol
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -259,7 +321,10 @@ pub struct ProtocolFeatures {
     pub agv_actions: Vec<AgvAction>
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -274,7 +339,10 @@ pub struct OptionalParameter {
 }
 
 /// Type of support for the optional parameter.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -286,7 +354,10 @@ pub enum Support {
     Required
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -304,7 +375,10 @@ pub struct AgvAction {
     pub result_description: Option<String>
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -315,7 +389,10 @@ pub enum ActionScope {
     Edge
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -332,7 +409,10 @@ pub struct ActionParameter {
 }
 
 /// Data type of Value.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -348,7 +428,10 @@ pub enum ValueDataType {
 }
 
 /// Detailed definition of AGV geometry.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -361,7 +444,10 @@ pub struct AgvGeometry {
     pub envelopes3d: Vec<Envelopes3d>
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -376,17 +462,23 @@ pub struct WheelDefinition {
     pub is_active_steered: bool,
     pub position: Position,
     /// nominal diameter of wheel
-    pub diameter: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub diameter: Float,
     /// nominal width of wheel
-    pub width: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub width: Float,
     /// nominal displacement of the wheel’s center to the rotation point (necessary for caster wheels). If the parameter is not defined, it is assumed to be 0
-    pub center_displacement: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub center_displacement: Option<Float>,
     /// free text: can be used by the manufacturer to define constraints
     pub constraints: Option<String>
 }
 
 /// Type of an AGV's wheel.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -398,21 +490,30 @@ pub enum WheelType {
     Mecanum
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct Position {
     /// [m] x-position in AGV-coordinate system
-    pub x: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub x: Float,
     /// y-position in AGV-coordinate system
-    pub y: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub y: Float,
     /// orientation of wheel in AGV-coordinate system Necessary for fixed wheels
-    pub theta: Option<f32>
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub theta: Option<Float>
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -426,19 +527,27 @@ pub struct Envelopes2d {
     pub description: Option<String>
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct PolygonPoint {
     /// x-position of polygon-point
-    pub x: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub x: Float,
     /// y-position of polygon-point
-    pub y: f32
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub y: Float
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -453,19 +562,23 @@ pub struct Envelopes3d {
     /// protocol and url-definition for downloading the 3D-envelope curve data e.g. ftp://xxx.yyy.com/ac4dgvhoif5tghji
     pub url: Option<String>,
     /// free text: description of envelope curve set
-    pub description: Option<f32>
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub description: Option<Float>
 }
 
 /// 3D-envelope curve data, format specified in ‚format‘
-#[cfg_attr(feature = "fmt", derive(Debug))]
-#[cfg_attr(feature = "serde",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(rename_all = "camelCase")
-)]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Data;
 
 /// Abstract specification of load capabilities.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -477,7 +590,10 @@ pub struct LoadSpecification {
     pub load_sets: Vec<LoadSet>
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -493,29 +609,42 @@ pub struct LoadSet {
     pub bounding_box_reference: Option<BoundingBoxReference>,
     pub load_dimensions: Option<LoadDimensions>,
     /// maximum weight of loadtype
-    pub max_weigth: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub max_weigth: Option<Mass>,
     /// minimum allowed height for handling of this load-type and –weight. References to bounding_box_reference
-    pub min_loadhandling_height: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub min_loadhandling_height: Option<Length>,
     /// maximum allowed height for handling of this load-type and –weight. references to bounding_box_reference
-    pub max_loadhandling_height: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub max_loadhandling_height: Option<Length>,
     /// minimum allowed depth for this load-type and –weight. references to bounding_box_reference
-    pub min_loadhandling_depth: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub min_loadhandling_depth: Option<Length>,
     /// maximum allowed depth for this load-type and –weight. references to bounding_box_reference
-    pub max_loadhandling_depth: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub max_loadhandling_depth: Option<Length>,
     /// minimum allowed tilt for this load-type and –weight
-    pub min_loadhandling_tilt: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub min_loadhandling_tilt: Option<Float>,
     /// maximum allowed tilt for this load-type and –weight
-    pub max_loadhandling_tilt: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub max_loadhandling_tilt: Option<Float>,
     /// maximum allowed speed for this load-type and –weight
-    pub agv_speed_limit: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub agv_speed_limit: Option<Speed>,
     /// maximum allowed acceleration for this load-type and –weight
-    pub agv_acceleration_limit: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub agv_acceleration_limit: Option<Acceleration>,
     /// maximum allowed deceleration for this load-type and –weight
-    pub agv_deceleration_limit: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub agv_deceleration_limit: Option<Acceleration>,
     /// approx. time for picking up the load
-    pub pick_time: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub pick_time: Option<Float>,
     /// approx. time for dropping the load
-    pub drop_time: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub drop_time: Option<Float>,
     /// free text description of the load handling set
-    pub description: Option<f32>
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub description: Option<Float>
 }
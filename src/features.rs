@@ -0,0 +1,81 @@
+//!
+//! Constants reflecting the features this crate has been compiled with.
+//!
+
+/// `true` if the `builders` feature is enabled.
+pub const BUILDERS: bool = cfg!(feature = "builders");
+
+/// `true` if the `checked-types` feature is enabled.
+pub const CHECKED_TYPES: bool = cfg!(feature = "checked-types");
+
+/// `true` if the `chrono` feature is enabled.
+pub const CHRONO: bool = cfg!(feature = "chrono");
+
+/// `true` if the `defmt` feature is enabled.
+pub const DEFMT: bool = cfg!(feature = "defmt");
+
+/// `true` if the `ffi` feature is enabled.
+pub const FFI: bool = cfg!(feature = "ffi");
+
+/// `true` if the `finite-floats` feature is enabled.
+pub const FINITE_FLOATS: bool = cfg!(feature = "finite-floats");
+
+/// `true` if the `fixtures` feature is enabled.
+pub const FIXTURES: bool = cfg!(feature = "fixtures");
+
+/// `true` if the `fmt` feature is enabled.
+pub const FMT: bool = cfg!(feature = "fmt");
+
+/// `true` if the `geojson` feature is enabled.
+pub const GEOJSON: bool = cfg!(feature = "geojson");
+
+/// `true` if the `json` feature is enabled.
+pub const JSON: bool = cfg!(feature = "json");
+
+/// `true` if the `json-value` feature is enabled.
+pub const JSON_VALUE: bool = cfg!(feature = "json-value");
+
+/// `true` if the `jsonschema` feature is enabled.
+pub const JSONSCHEMA: bool = cfg!(feature = "jsonschema");
+
+/// `true` if the `proptest` feature is enabled.
+pub const PROPTEST: bool = cfg!(feature = "proptest");
+
+/// `true` if the `python` feature is enabled.
+pub const PYTHON: bool = cfg!(feature = "python");
+
+/// `true` if the `ros2` feature is enabled.
+pub const ROS2: bool = cfg!(feature = "ros2");
+
+/// `true` if the `serde` feature is enabled.
+pub const SERDE: bool = cfg!(feature = "serde");
+
+/// `true` if the `strict-floats` feature is enabled.
+pub const STRICT_FLOATS: bool = cfg!(feature = "strict-floats");
+
+/// `true` if the `time` feature is enabled.
+pub const TIME: bool = cfg!(feature = "time");
+
+/// `true` if the `timestamp-millis` feature is enabled.
+pub const TIMESTAMP_MILLIS: bool = cfg!(feature = "timestamp-millis");
+
+/// `true` if the `timestamp-string` feature is enabled.
+pub const TIMESTAMP_STRING: bool = cfg!(feature = "timestamp-string");
+
+/// `true` if the `ts-rs` feature is enabled.
+pub const TS_RS: bool = cfg!(feature = "ts-rs");
+
+/// `true` if the `typed-ids` feature is enabled.
+pub const TYPED_IDS: bool = cfg!(feature = "typed-ids");
+
+/// `true` if the `units` feature is enabled.
+pub const UNITS: bool = cfg!(feature = "units");
+
+/// `true` if the `uuid` feature is enabled.
+pub const UUID: bool = cfg!(feature = "uuid");
+
+/// `true` if the `v2_0` feature is enabled.
+pub const V2_0: bool = cfg!(feature = "v2_0");
+
+/// `true` if the `wasm` feature is enabled.
+pub const WASM: bool = cfg!(feature = "wasm");
@@ -0,0 +1,274 @@
+//!
+//! C interface for parsing and serializing messages, suitable for generating a header via cbindgen.
+//!
+//! Messages are passed as opaque handles, which are created by the `*_from_json` functions and have to be released
+//! via the corresponding `*_free` function. Strings returned by this interface have to be released via
+//! [`vda5050_string_free`].
+//!
+//! As the crate type cannot depend on features, the library to link against is built via `cargo rustc`, e.g.
+//! `cargo rustc --release --features ffi --crate-type staticlib` or `--crate-type cdylib` for a shared library.
+//!
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use core::ffi::{c_char, CStr};
+use core::ptr;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::checked::Raw;
+use crate::order::Order;
+use crate::state::State;
+
+/// Opaque handle of an [`Order`].
+#[derive(Clone)]
+pub struct Vda5050Order(Order);
+
+/// Opaque handle of a [`State`].
+#[derive(Clone)]
+pub struct Vda5050State(State);
+
+/// Summary of an order, which is zeroed for a null handle.
+#[derive(Clone, Default)]
+#[repr(C)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct Vda5050OrderView {
+    /// header_id of the message.
+    pub header_id: u64,
+    /// orderUpdate identification.
+    pub order_update_id: u64,
+    /// Number of nodes of the order.
+    pub nodes_len: usize,
+    /// Number of edges of the order.
+    pub edges_len: usize,
+}
+
+/// Summary of a state, which is zeroed for a null handle.
+#[derive(Clone, Default)]
+#[repr(C)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct Vda5050StateView {
+    /// header_id of the message.
+    pub header_id: u64,
+    /// Order Update Identification of the current order.
+    pub order_update_id: u64,
+    /// sequence_id of the last reached node.
+    pub last_node_sequence_id: u64,
+    /// True: indicates that the AGV is driving and/or rotating.
+    pub driving: bool,
+    /// State of charge of the battery in percent.
+    pub battery_charge: f32,
+    /// Number of node states.
+    pub node_states_len: usize,
+    /// Number of edge states.
+    pub edge_states_len: usize,
+    /// Number of active errors.
+    pub errors_len: usize,
+}
+
+unsafe fn from_json<T: DeserializeOwned>(json: *const c_char) -> Option<T> {
+    if json.is_null() {
+        return None;
+    }
+    let json = CStr::from_ptr(json).to_str().ok()?;
+    serde_json::from_str(json).ok()
+}
+
+fn to_json<T: Serialize>(value: &T) -> *mut c_char {
+    serde_json::to_string(value)
+        .ok()
+        .and_then(|json| CString::new(json).ok())
+        .map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Parses an order from a NUL-terminated JSON string.
+///
+/// Returns a null pointer if `json` is null, not valid UTF-8 or not a valid order.
+///
+/// # Safety
+///
+/// `json` has to be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn vda5050_order_from_json(json: *const c_char) -> *mut Vda5050Order {
+    from_json(json).map_or(ptr::null_mut(), |order| Box::into_raw(Box::new(Vda5050Order(order))))
+}
+
+/// Serializes an order into a NUL-terminated JSON string.
+///
+/// Returns a null pointer if `order` is null or the order cannot be serialized.
+///
+/// # Safety
+///
+/// `order` has to be null or a handle returned by [`vda5050_order_from_json`] which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn vda5050_order_to_json(order: *const Vda5050Order) -> *mut c_char {
+    order.as_ref().map_or(ptr::null_mut(), |order| to_json(&order.0))
+}
+
+/// Returns a summary of an order.
+///
+/// Returns a zeroed summary if `order` is null.
+///
+/// # Safety
+///
+/// `order` has to be null or a handle returned by [`vda5050_order_from_json`] which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn vda5050_order_view(order: *const Vda5050Order) -> Vda5050OrderView {
+    order.as_ref().map_or_else(Vda5050OrderView::default, |order| Vda5050OrderView {
+        header_id: order.0.header_id,
+        order_update_id: order.0.order_update_id,
+        nodes_len: order.0.nodes.len(),
+        edges_len: order.0.edges.len(),
+    })
+}
+
+/// Releases an order.
+///
+/// # Safety
+///
+/// `order` has to be null or a handle returned by [`vda5050_order_from_json`] which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn vda5050_order_free(order: *mut Vda5050Order) {
+    if !order.is_null() {
+        drop(Box::from_raw(order));
+    }
+}
+
+/// Parses a state from a NUL-terminated JSON string.
+///
+/// Returns a null pointer if `json` is null, not valid UTF-8 or not a valid state.
+///
+/// # Safety
+///
+/// `json` has to be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn vda5050_state_from_json(json: *const c_char) -> *mut Vda5050State {
+    from_json(json).map_or(ptr::null_mut(), |state| Box::into_raw(Box::new(Vda5050State(state))))
+}
+
+/// Serializes a state into a NUL-terminated JSON string.
+///
+/// Returns a null pointer if `state` is null or the state cannot be serialized.
+///
+/// # Safety
+///
+/// `state` has to be null or a handle returned by [`vda5050_state_from_json`] which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn vda5050_state_to_json(state: *const Vda5050State) -> *mut c_char {
+    state.as_ref().map_or(ptr::null_mut(), |state| to_json(&state.0))
+}
+
+/// Returns a summary of a state.
+///
+/// Returns a zeroed summary if `state` is null.
+///
+/// # Safety
+///
+/// `state` has to be null or a handle returned by [`vda5050_state_from_json`] which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn vda5050_state_view(state: *const Vda5050State) -> Vda5050StateView {
+    state.as_ref().map_or_else(Vda5050StateView::default, |state| Vda5050StateView {
+        header_id: state.0.header_id,
+        order_update_id: state.0.order_update_id,
+        last_node_sequence_id: state.0.last_node_sequence_id,
+        driving: state.0.driving,
+        battery_charge: state.0.battery_state.battery_charge.raw(),
+        node_states_len: state.0.node_states.len(),
+        edge_states_len: state.0.edge_states.len(),
+        errors_len: state.0.errors.len(),
+    })
+}
+
+/// Releases a state.
+///
+/// # Safety
+///
+/// `state` has to be null or a handle returned by [`vda5050_state_from_json`] which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn vda5050_state_free(state: *mut Vda5050State) {
+    if !state.is_null() {
+        drop(Box::from_raw(state));
+    }
+}
+
+/// Releases a string returned by this interface.
+///
+/// # Safety
+///
+/// `string` has to be null or a string returned by this interface which has not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn vda5050_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::ffi::CString;
+    use core::ffi::CStr;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    const ORDER: &str = r#"{"headerId":1,"timestamp":"2017-04-15T11:40:03.120Z","version":"2.0.0","manufacturer":"Fubar Co.","serialNumber":"1234","orderId":"o1","orderUpdateId":3,"zoneSetId":null,"nodes":[{"nodeId":"n1","sequenceId":0,"nodeDescription":null,"released":true,"nodePosition":null,"actions":[]}],"edges":[]}"#;
+
+    #[rstest]
+    fn test_ffi_Order_json_round_trip() {
+        let json = CString::new(ORDER).unwrap();
+
+        unsafe {
+            let order = vda5050_order_from_json(json.as_ptr());
+            assert_that!(order.is_null(), eq(false));
+
+            let view = vda5050_order_view(order);
+            assert_that!(view, matches_pattern!(Vda5050OrderView {
+                header_id: eq(&1),
+                order_update_id: eq(&3),
+                nodes_len: eq(&1),
+                edges_len: eq(&0),
+            }));
+
+            let serialized = vda5050_order_to_json(order);
+            assert_that!(CStr::from_ptr(serialized).to_str(), ok(eq(ORDER)));
+
+            vda5050_string_free(serialized);
+            vda5050_order_free(order);
+        }
+    }
+
+    #[rstest]
+    #[case::invalid_json(Some("{"))]
+    #[case::not_a_state(Some(ORDER))]
+    #[case::null(None)]
+    fn test_ffi_State_from_invalid_json_returns_null(#[case] json: Option<&str>) {
+        let json = json.map(|json| CString::new(json).unwrap());
+        let pointer = json.as_ref().map_or(core::ptr::null(), |json| json.as_ptr());
+
+        unsafe {
+            let state = vda5050_state_from_json(pointer);
+            assert_that!(state.is_null(), eq(true));
+            assert_that!(vda5050_state_to_json(state).is_null(), eq(true));
+            assert_that!(vda5050_state_view(state), matches_pattern!(Vda5050StateView {
+                header_id: eq(&0),
+                driving: eq(&false),
+                node_states_len: eq(&0),
+            }));
+            vda5050_state_free(state);
+        }
+    }
+
+    #[rstest]
+    fn test_ffi_Order_view_of_null_is_zeroed() {
+        unsafe {
+            assert_that!(vda5050_order_view(core::ptr::null()), matches_pattern!(Vda5050OrderView {
+                header_id: eq(&0),
+                order_update_id: eq(&0),
+                nodes_len: eq(&0),
+                edges_len: eq(&0),
+            }));
+        }
+    }
+}
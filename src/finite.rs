@@ -0,0 +1,283 @@
+//!
+//! The [`Finite`] wrapper of floating point numbers, which are neither NaN nor infinite.
+//!
+//! If the feature `finite-floats` is enabled, all floating point fields of the messages use [`Finite`], so that
+//! messages can implement [`Eq`], [`Ord`] and [`Hash`](core::hash::Hash), e.g. to be used as keys of deduplication
+//! caches. The aliases [`Float`] and [`Double`] resolve to the type used by the fields.
+//!
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::checked::{OutOfRange, Raw};
+
+/// Type of single precision fields, which is [`Finite<f32>`] if the feature `finite-floats` is enabled and `f32`
+/// otherwise.
+#[cfg(feature = "finite-floats")]
+pub type Float = Finite<f32>;
+
+/// Type of single precision fields, which is [`Finite<f32>`] if the feature `finite-floats` is enabled and `f32`
+/// otherwise.
+#[cfg(not(feature = "finite-floats"))]
+pub type Float = f32;
+
+/// Type of double precision fields, which is [`Finite<f64>`] if the feature `finite-floats` is enabled and `f64`
+/// otherwise.
+#[cfg(feature = "finite-floats")]
+pub type Double = Finite<f64>;
+
+/// Type of double precision fields, which is [`Finite<f64>`] if the feature `finite-floats` is enabled and `f64`
+/// otherwise.
+#[cfg(not(feature = "finite-floats"))]
+pub type Double = f64;
+
+/// Error returned when a floating point number is NaN or infinite.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct NotFinite;
+
+impl core::fmt::Display for NotFinite {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("NaN or infinite floating point number")
+    }
+}
+
+/// Floating point number which is neither NaN nor infinite.
+///
+/// Negative zero is stored as positive zero, thus equal values have equal hashes and the total order of
+/// [`Ord`] coincides with the numerical order.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct Finite<T>(T);
+
+macro_rules! finite {
+    ($type:ty, $serialize:ident) => {
+
+        impl Finite<$type> {
+
+            /// Returns `value` or an error if it is NaN or infinite.
+            pub fn new(value: $type) -> Result<Self, NotFinite> {
+                if value.is_finite() {
+                    Ok(Self(if value == 0.0 { 0.0 } else { value }))
+                }
+                else {
+                    Err(NotFinite)
+                }
+            }
+
+            /// Returns the value.
+            pub fn get(self) -> $type {
+                self.0
+            }
+        }
+
+        impl TryFrom<$type> for Finite<$type> {
+            type Error = NotFinite;
+
+            fn try_from(value: $type) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl From<Finite<$type>> for $type {
+            fn from(value: Finite<$type>) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq for Finite<$type> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for Finite<$type> {}
+
+        impl PartialEq<$type> for Finite<$type> {
+            fn eq(&self, other: &$type) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialOrd for Finite<$type> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Finite<$type> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for Finite<$type> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl core::fmt::Display for Finite<$type> {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, formatter)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for Finite<$type> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.$serialize(self.0)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl <'de> serde::Deserialize<'de> for Finite<$type> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Self::new(<$type>::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+            }
+        }
+
+        #[cfg(feature = "ts-rs")]
+        impl ts_rs::TS for Finite<$type> {
+            type WithoutGenerics = Self;
+
+            fn name() -> alloc::string::String {
+                <$type>::name()
+            }
+
+            fn inline() -> alloc::string::String {
+                <$type>::inline()
+            }
+
+            fn inline_flattened() -> alloc::string::String {
+                <$type>::inline_flattened()
+            }
+
+            fn decl() -> alloc::string::String {
+                <$type>::decl()
+            }
+
+            fn decl_concrete() -> alloc::string::String {
+                <$type>::decl_concrete()
+            }
+        }
+
+        #[cfg(feature = "strict-floats")]
+        impl crate::float::Finite for Finite<$type> {
+            fn is_finite(&self) -> bool {
+                true
+            }
+        }
+    };
+}
+
+finite!(f32, serialize_f32);
+finite!(f64, serialize_f64);
+
+impl Raw for Finite<f32> {
+
+    fn try_from_raw(value: f32) -> Result<Self, OutOfRange> {
+        Self::new(value).map_err(|_| OutOfRange { value, min: f32::MIN, max: f32::MAX })
+    }
+
+    fn raw(self) -> f32 {
+        self.0
+    }
+}
+
+/// Conversion of a double precision field from its raw `f64`, regardless of whether the feature `finite-floats` is
+/// enabled.
+pub(crate) trait RawDouble: Copy {
+
+    /// Returns the field holding `value` or an error if `value` is NaN or infinite.
+    fn try_from_raw(value: f64) -> Result<Self, NotFinite>;
+}
+
+/// Returns the field holding `value`, which has to be finite.
+#[cfg(test)]
+pub(crate) fn double<T: RawDouble>(value: f64) -> T {
+    T::try_from_raw(value).expect("finite value")
+}
+
+impl RawDouble for f64 {
+
+    fn try_from_raw(value: f64) -> Result<Self, NotFinite> {
+        Ok(value)
+    }
+}
+
+impl RawDouble for Finite<f64> {
+
+    fn try_from_raw(value: f64) -> Result<Self, NotFinite> {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::hash::{Hash, Hasher};
+    use googletest::prelude::*;
+    use rstest::rstest;
+    use std::collections::hash_map::DefaultHasher;
+
+    use super::Finite;
+
+    fn hash(value: Finite<f32>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[rstest]
+    #[case(1.5, true)]
+    #[case(f32::NAN, false)]
+    #[case(f32::INFINITY, false)]
+    #[case(f32::NEG_INFINITY, false)]
+    fn test_Finite_new(#[case] value: f32, #[case] valid: bool) {
+        assert_that!(Finite::<f32>::new(value).is_ok(), eq(valid));
+    }
+
+    #[rstest]
+    fn test_Finite_treats_negative_zero_as_zero() {
+        let (zero, negative_zero) = (Finite::<f32>::new(0.0).unwrap(), Finite::<f32>::new(-0.0).unwrap());
+
+        assert_that!(zero, eq(negative_zero));
+        assert_that!(hash(zero), eq(hash(negative_zero)));
+        assert_that!(zero.cmp(&negative_zero), eq(core::cmp::Ordering::Equal));
+    }
+
+    #[rstest]
+    fn test_Finite_ord() {
+        let mut values = [2.5, -1.0, 0.0].map(|value| Finite::<f64>::new(value).unwrap());
+
+        values.sort();
+
+        assert_that!(values.map(Finite::<f64>::get), eq([-1.0, 0.0, 2.5]));
+    }
+
+    #[cfg(feature = "finite-floats")]
+    #[rstest]
+    fn test_NodePosition_as_key() {
+        let position = |x: f32| crate::common::NodePosition {
+            x: Finite::<f32>::new(x).unwrap(),
+            y: Finite::<f32>::new(2.0).unwrap(),
+            theta: None,
+            allowed_deviation_xy: None,
+            allowed_deviation_theta: None,
+            map_id: "map".into(),
+            map_description: None,
+        };
+
+        let positions = [position(1.0), position(-0.0), position(1.0), position(0.0)].into_iter().collect::<std::collections::HashSet<_>>();
+
+        assert_that!(positions.len(), eq(2));
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn test_serde_Finite() {
+        assert_that!(serde_json::from_str::<Finite<f32>>("1.5"), ok(eq(&Finite::<f32>::new(1.5).unwrap())));
+        assert_that!(serde_json::to_string(&Finite::<f32>::new(1.5).unwrap()).unwrap(), eq("1.5"));
+    }
+}
@@ -0,0 +1,153 @@
+//!
+//! Loading of messages from YAML and TOML, e.g. factsheets or test orders kept as configuration files.
+//!
+use serde::de::DeserializeOwned;
+
+/// Deserializes a message from a YAML string.
+pub fn from_yaml_str<T: DeserializeOwned>(yaml: &str) -> Result<T, serde_yaml::Error> {
+    serde_yaml::from_str(yaml)
+}
+
+/// Deserializes a message from a TOML string.
+///
+/// Timestamps have to be given as strings, e.g. `timestamp = "2017-04-15T11:40:03.12Z"`, as TOML's native
+/// date-times are not supported.
+pub fn from_toml_str<T: DeserializeOwned>(toml: &str) -> Result<T, toml::de::Error> {
+    toml::from_str(toml)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::action::{Action, ActionParameter, ActionParameterValue, BlockingType};
+    use crate::factsheet::MaxArrayLens;
+    use crate::finite::double;
+    use crate::instant_actions::InstantActions;
+    use super::{from_toml_str, from_yaml_str};
+
+    const MAX_ARRAY_LENS_YAML: &str = r#"
+order.nodes: 100
+order.edges: 99
+node.actions: 5
+edge.actions: 4
+actions.actionsParameters: 8
+instantActions: 10
+trajectory.knotVector: 32
+trajectory.controlPoints: 28
+state.nodeStates: 100
+state.edgeStates: 99
+state.loads: 1
+state.actionStates: 50
+state.errors: 20
+state.information: 20
+error.errorReferences: 4
+information.infoReferences: 4
+"#;
+
+    const MAX_ARRAY_LENS_TOML: &str = r#"
+"order.nodes" = 100
+"order.edges" = 99
+"node.actions" = 5
+"edge.actions" = 4
+"actions.actionsParameters" = 8
+instantActions = 10
+"trajectory.knotVector" = 32
+"trajectory.controlPoints" = 28
+"state.nodeStates" = 100
+"state.edgeStates" = 99
+"state.loads" = 1
+"state.actionStates" = 50
+"state.errors" = 20
+"state.information" = 20
+"error.errorReferences" = 4
+"information.infoReferences" = 4
+"#;
+
+    const INSTANT_ACTIONS_YAML: &str = r#"
+headerId: 1
+timestamp: 2017-04-15T11:40:03.12Z
+version: 2.0.0
+manufacturer: Fubar Co.
+serialNumber: "1234"
+instantActions:
+  - actionType: pick
+    actionId: a1
+    blockingType: HARD
+    actionParameters:
+      - { key: "null", value: ~ }
+      - { key: bool, value: true }
+      - { key: integer, value: 42 }
+      - { key: float, value: 1.5 }
+      - { key: string, value: left }
+"#;
+
+    const INSTANT_ACTIONS_TOML: &str = r#"
+headerId = 1
+timestamp = "2017-04-15T11:40:03.12Z"
+version = "2.0.0"
+manufacturer = "Fubar Co."
+serialNumber = "1234"
+
+[[instantActions]]
+actionType = "pick"
+actionId = "a1"
+blockingType = "HARD"
+actionParameters = [
+    { key = "bool", value = true },
+    { key = "integer", value = 42 },
+    { key = "float", value = 1.5 },
+    { key = "string", value = "left" },
+]
+"#;
+
+    #[rstest]
+    #[case::yaml(from_yaml_str::<MaxArrayLens>(MAX_ARRAY_LENS_YAML).ok())]
+    #[case::toml(from_toml_str::<MaxArrayLens>(MAX_ARRAY_LENS_TOML).ok())]
+    fn test_MaxArrayLens_from_fixture(#[case] lens: Option<MaxArrayLens>) {
+        assert_that!(lens, some(matches_pattern!(MaxArrayLens {
+            order_nodes: eq(&100),
+            actions_actions_parameters: eq(&8),
+            instant_actions: eq(&10),
+            information_info_references: eq(&4),
+        })));
+    }
+
+    #[rstest]
+    fn test_InstantActions_from_yaml() {
+        let instant_actions = from_yaml_str::<InstantActions>(INSTANT_ACTIONS_YAML);
+
+        assert_that!(instant_actions, ok(matches_pattern!(InstantActions {
+            serial_number: eq("1234"),
+            instant_actions: elements_are![matches_pattern!(Action {
+                blocking_type: matches_pattern!(BlockingType::Hard),
+                action_parameters: elements_are![
+                    matches_pattern!(ActionParameter { key: eq("null"), value: eq(&ActionParameterValue::Null) }),
+                    matches_pattern!(ActionParameter { key: eq("bool"), value: eq(&ActionParameterValue::Boolean(true)) }),
+                    matches_pattern!(ActionParameter { key: eq("integer"), value: eq(&ActionParameterValue::Integer(42)) }),
+                    matches_pattern!(ActionParameter { key: eq("float"), value: eq(&ActionParameterValue::Float(double(1.5))) }),
+                    matches_pattern!(ActionParameter { key: eq("string"), value: eq(&ActionParameterValue::String("left".into())) }),
+                ],
+            })],
+        })));
+    }
+
+    #[rstest]
+    fn test_InstantActions_from_toml() {
+        let instant_actions = from_toml_str::<InstantActions>(INSTANT_ACTIONS_TOML);
+
+        assert_that!(instant_actions, ok(matches_pattern!(InstantActions {
+            serial_number: eq("1234"),
+            instant_actions: elements_are![matches_pattern!(Action {
+                action_parameters: elements_are![
+                    matches_pattern!(ActionParameter { key: eq("bool"), value: eq(&ActionParameterValue::Boolean(true)) }),
+                    matches_pattern!(ActionParameter { key: eq("integer"), value: eq(&ActionParameterValue::Integer(42)) }),
+                    matches_pattern!(ActionParameter { key: eq("float"), value: eq(&ActionParameterValue::Float(double(1.5))) }),
+                    matches_pattern!(ActionParameter { key: eq("string"), value: eq(&ActionParameterValue::String("left".into())) }),
+                ],
+            })],
+        })));
+    }
+}
@@ -0,0 +1,131 @@
+//!
+//! Serialization of floating point numbers which refuses NaN and infinite values (feature `strict-floats`).
+//!
+use alloc::vec::Vec;
+use serde::de::Error as _;
+use serde::ser::Error as _;
+
+/// Floating point numbers and containers of them which can be checked for NaN and infinite values.
+pub(crate) trait Finite {
+
+    /// Returns `true` if neither NaN nor an infinite value is contained.
+    fn is_finite(&self) -> bool;
+}
+
+impl Finite for f32 {
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+}
+
+impl Finite for f64 {
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+impl <T: Finite> Finite for Option<T> {
+    fn is_finite(&self) -> bool {
+        self.as_ref().map_or(true, Finite::is_finite)
+    }
+}
+
+impl <T: Finite> Finite for Vec<T> {
+    fn is_finite(&self) -> bool {
+        self.iter().all(Finite::is_finite)
+    }
+}
+
+pub(crate) fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Finite + serde::Serialize,
+    S: serde::Serializer,
+{
+    if value.is_finite() {
+        value.serialize(serializer)
+    }
+    else {
+        Err(S::Error::custom("NaN or infinite floating point number"))
+    }
+}
+
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Finite + serde::Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    let value = T::deserialize(deserializer)?;
+    if value.is_finite() {
+        Ok(value)
+    }
+    else {
+        Err(D::Error::custom("NaN or infinite floating point number"))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use googletest::prelude::*;
+    use rstest::rstest;
+    use crate::checked::checked;
+    use crate::common::Velocity;
+
+    #[rstest]
+    fn test_serde_Velocity_with_finite_values() {
+
+        let velocity = Velocity {
+            vx: Some(checked(1.5)),
+            vy: None,
+            omega: Some(checked(-0.25)),
+        };
+
+        let json = r#"{"vx":1.5,"vy":null,"omega":-0.25}"#;
+
+        let to = serde_json::to_string(&velocity);
+        let from = serde_json::from_str::<Velocity>(r#"{"vx":1.5,"omega":-0.25}"#);
+
+        assert_that!(to, ok(eq(json)));
+
+        assert_that!(from, ok(matches_pattern!(
+            Velocity {
+                vx: some(eq(&1.5)),
+                vy: none(),
+                omega: some(eq(&-0.25))
+            }
+        )));
+    }
+
+    #[cfg(not(feature = "finite-floats"))]
+    #[rstest]
+    #[case(f32::NAN)]
+    #[case(f32::INFINITY)]
+    #[case(f32::NEG_INFINITY)]
+    fn test_serialize_Velocity_with_non_finite_value(#[case] value: f32) {
+
+        let velocity = Velocity {
+            vx: Some(value),
+            vy: None,
+            omega: None,
+        };
+
+        assert_that!(serde_json::to_string(&velocity), err(anything()));
+    }
+
+    #[rstest]
+    fn test_deserialize_Velocity_with_out_of_range_value() {
+        assert_that!(serde_json::from_str::<Velocity>(r#"{"vx":1e300,"vy":null,"omega":null}"#), err(anything()));
+    }
+
+    #[cfg(not(feature = "finite-floats"))]
+    #[rstest]
+    fn test_serialize_ActionParameter_with_non_finite_value() {
+
+        let parameter = crate::action::ActionParameter {
+            key: "my-float".into(),
+            value: crate::action::ActionParameterValue::Float(f64::NAN),
+        };
+
+        assert_that!(serde_json::to_string(&parameter), err(anything()));
+    }
+}
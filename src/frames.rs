@@ -0,0 +1,110 @@
+//!
+//! Transforms of points and poses between the map frame and the vehicle frame of an AGV at its position.
+//!
+//! The vehicle frame has its origin at the reference point of the AGV, where the x-axis points forwards and the
+//! y-axis to the left, e.g. for the bounding boxes of loads, the envelopes and the positions of wheels. The map frame is
+//! the project specific coordinate system the [`AgvPosition`] is given in.
+//!
+use crate::angle::normalize;
+use crate::checked::Raw;
+use crate::common::AgvPosition;
+use crate::pose::Pose;
+
+/// Returns the point `(x, y)` in the vehicle frame of an AGV at `position` in the map frame.
+pub fn point_to_map(position: &AgvPosition, point: (f32, f32)) -> (f32, f32) {
+    transform((position.x.raw(), position.y.raw(), position.theta.raw()), point)
+}
+
+/// Returns the point `(x, y)` in the map frame in the vehicle frame of an AGV at `position`.
+pub fn point_to_vehicle(position: &AgvPosition, (px, py): (f32, f32)) -> (f32, f32) {
+    let (sin, cos) = (libm::sinf(position.theta.raw()), libm::cosf(position.theta.raw()));
+    let (dx, dy) = (px - position.x.raw(), py - position.y.raw());
+    (dx * cos + dy * sin, dy * cos - dx * sin)
+}
+
+/// Returns `pose` in the vehicle frame of an AGV at `position` as `(x, y, theta)` in the map frame, where `theta` is
+/// normalized into `[-π, π]` and `None` if `pose` has no orientation.
+pub fn pose_to_map(position: &AgvPosition, pose: &impl Pose) -> (f32, f32, Option<f32>) {
+    let (x, y) = point_to_map(position, (pose.x(), pose.y()));
+    (x, y, pose.theta().map(|theta| normalize(theta + position.theta.raw())))
+}
+
+/// Returns `pose` in the map frame as `(x, y, theta)` in the vehicle frame of an AGV at `position`, where `theta` is
+/// normalized into `[-π, π]` and `None` if `pose` has no orientation.
+pub fn pose_to_vehicle(position: &AgvPosition, pose: &impl Pose) -> (f32, f32, Option<f32>) {
+    let (x, y) = point_to_vehicle(position, (pose.x(), pose.y()));
+    (x, y, pose.theta().map(|theta| normalize(theta - position.theta.raw())))
+}
+
+/// Returns `point` given relative to `pose` in the coordinates `pose` is given in.
+pub(crate) fn transform((x, y, theta): (f32, f32, f32), (px, py): (f32, f32)) -> (f32, f32) {
+    let (sin, cos) = (libm::sinf(theta), libm::cosf(theta));
+    (x + px * cos - py * sin, y + px * sin + py * cos)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::f32::consts::{FRAC_PI_2, PI};
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::common::{AgvPosition, NodePosition};
+    use crate::factsheet::Position;
+    use super::{point_to_map, point_to_vehicle, pose_to_map, pose_to_vehicle};
+
+    fn position(x: f32, y: f32, theta: f32) -> AgvPosition {
+        AgvPosition {
+            x: checked(x),
+            y: checked(y),
+            theta: checked(theta),
+            map_id: "map".into(),
+            map_description: None,
+            position_initialized: true,
+            localization_score: None,
+            deviation_range: None,
+        }
+    }
+
+    #[rstest]
+    #[case::origin(position(0.0, 0.0, 0.0), (1.0, 2.0), (1.0, 2.0))]
+    #[case::translated(position(10.0, 5.0, 0.0), (1.0, 2.0), (11.0, 7.0))]
+    #[case::rotated(position(10.0, 5.0, FRAC_PI_2), (1.0, 2.0), (8.0, 6.0))]
+    #[case::reversed(position(0.0, 0.0, PI), (1.0, 0.0), (-1.0, 0.0))]
+    fn test_point_to_map_and_back(#[case] position: AgvPosition, #[case] vehicle: (f32, f32), #[case] map: (f32, f32)) {
+        let (x, y) = point_to_map(&position, vehicle);
+        assert_that!((x, y), (near(map.0, 1e-5), near(map.1, 1e-5)));
+
+        let (x, y) = point_to_vehicle(&position, map);
+        assert_that!((x, y), (near(vehicle.0, 1e-5), near(vehicle.1, 1e-5)));
+    }
+
+    #[rstest]
+    fn test_pose_to_map_of_wheel() {
+        let wheel = Position { x: checked(0.5), y: checked(0.0), theta: Some(checked(FRAC_PI_2)) };
+
+        let (x, y, theta) = pose_to_map(&position(1.0, 1.0, PI), &wheel);
+
+        assert_that!((x, y), (near(0.5, 1e-5), near(1.0, 1e-5)));
+        assert_that!(theta, some(near(-FRAC_PI_2, 1e-5)));
+    }
+
+    #[rstest]
+    fn test_pose_to_vehicle_of_node() {
+        let node = NodePosition {
+            x: checked(1.0),
+            y: checked(3.0),
+            theta: None,
+            allowed_deviation_xy: None,
+            allowed_deviation_theta: None,
+            map_id: "map".into(),
+            map_description: None,
+        };
+
+        let (x, y, theta) = pose_to_vehicle(&position(1.0, 1.0, FRAC_PI_2), &node);
+
+        assert_that!((x, y), (near(2.0, 1e-5), near(0.0, 1e-5)));
+        assert_that!(theta, none());
+    }
+}
@@ -0,0 +1,223 @@
+//!
+//! Export of orders and trajectories as [GeoJSON](https://www.rfc-editor.org/rfc/rfc7946), e.g. to inspect layouts
+//! and routes in GIS or visualization tools.
+//!
+//! Coordinates are the x and y coordinates of the map in meters, they are not converted into longitude and latitude.
+//!
+use alloc::vec::Vec;
+
+use serde_json::{json, Value};
+
+use crate::checked::Raw;
+use crate::common::{NodePosition, Trajectory};
+use crate::order::{Edge, Node, Order};
+
+/// Number of points a trajectory is sampled at by default.
+pub const DEFAULT_SAMPLES: usize = 32;
+
+/// Converts an order into a `FeatureCollection`.
+///
+/// Each node with a position becomes a `Point` feature. Each edge becomes a `LineString` feature, which follows the
+/// trajectory sampled at `samples` points if present or connects the positions of its start and end node otherwise.
+/// Edges without trajectory and node positions are omitted.
+pub fn order_to_feature_collection(order: &Order, samples: usize) -> Value {
+    let nodes = order.nodes.iter()
+        .filter_map(node_to_feature);
+    let edges = order.edges.iter()
+        .filter_map(|edge| edge_to_feature(order, edge, samples));
+    json!({
+        "type": "FeatureCollection",
+        "features": nodes.chain(edges).collect::<Vec<_>>(),
+    })
+}
+
+/// Converts a trajectory sampled at `samples` evenly spaced parameters into a `LineString` feature.
+///
+/// Returns `None` if the trajectory is malformed or fewer than two samples are requested.
+pub fn trajectory_to_feature(trajectory: &Trajectory, samples: usize) -> Option<Value> {
+    Some(json!({
+        "type": "Feature",
+        "geometry": line_string(sample(trajectory, samples)?),
+        "properties": {},
+    }))
+}
+
+fn node_to_feature(node: &Node) -> Option<Value> {
+    let position = node.node_position.as_ref()?;
+    Some(json!({
+        "type": "Feature",
+        "geometry": {
+            "type": "Point",
+            "coordinates": [position.x, position.y],
+        },
+        "properties": {
+            "nodeId": node.node_id,
+            "sequenceId": node.sequence_id,
+            "released": node.released,
+            "mapId": position.map_id,
+        },
+    }))
+}
+
+fn edge_to_feature(order: &Order, edge: &Edge, samples: usize) -> Option<Value> {
+    let points = match &edge.trajectory {
+        Some(trajectory) => sample(trajectory, samples)?,
+        None => {
+            let start = node_position(order, &edge.start_node_id)?;
+            let end = node_position(order, &edge.end_node_id)?;
+            alloc::vec![[start.x.raw(), start.y.raw()], [end.x.raw(), end.y.raw()]]
+        }
+    };
+    Some(json!({
+        "type": "Feature",
+        "geometry": line_string(points),
+        "properties": {
+            "edgeId": edge.edge_id,
+            "sequenceId": edge.sequence_id,
+            "released": edge.released,
+            "startNodeId": edge.start_node_id,
+            "endNodeId": edge.end_node_id,
+        },
+    }))
+}
+
+fn node_position<'a>(order: &'a Order, node_id: &str) -> Option<&'a NodePosition> {
+    order.nodes.iter()
+        .find(|node| node.node_id == node_id)
+        .and_then(|node| node.node_position.as_ref())
+}
+
+fn sample(trajectory: &Trajectory, samples: usize) -> Option<Vec<[f32; 2]>> {
+    if samples < 2 {
+        return None;
+    }
+    let (start, end) = trajectory.domain()?;
+    (0..samples)
+        .map(|index| {
+            let u = if index + 1 == samples { end } else { start + (end - start) * index as f32 / (samples - 1) as f32 };
+            trajectory.point_at(u).map(|(x, y)| [x, y])
+        })
+        .collect()
+}
+
+fn line_string(points: Vec<[f32; 2]>) -> Value {
+    json!({
+        "type": "LineString",
+        "coordinates": points,
+    })
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::common::NodePosition;
+    use crate::order::{Edge, Node, Order};
+    use crate::timestamp::from_unix_millis;
+    use crate::trajectory::tests::quarter_circle;
+    use super::{order_to_feature_collection, trajectory_to_feature};
+
+    fn node(node_id: &str, sequence_id: u64, position: Option<(f32, f32)>) -> Node {
+        Node {
+            node_id: node_id.into(),
+            sequence_id,
+            node_description: None,
+            released: true,
+            node_position: position.map(|(x, y)| NodePosition {
+                x: checked(x),
+                y: checked(y),
+                theta: None,
+                allowed_deviation_xy: None,
+                allowed_deviation_theta: None,
+                map_id: "map".into(),
+                map_description: None,
+            }),
+            actions: vec![],
+        }
+    }
+
+    fn edge(edge_id: &str, sequence_id: u64, start_node_id: &str, end_node_id: &str) -> Edge {
+        Edge {
+            edge_id: edge_id.into(),
+            sequence_id,
+            edge_description: None,
+            released: false,
+            start_node_id: start_node_id.into(),
+            end_node_id: end_node_id.into(),
+            max_speed: None,
+            max_height: None,
+            min_height: None,
+            orientation: None,
+            orientation_type: None,
+            direction: None,
+            rotation_allowed: None,
+            max_rotation_speed: None,
+            length: None,
+            trajectory: None,
+            actions: vec![],
+        }
+    }
+
+    fn order(nodes: vec::Vec<Node>, edges: vec::Vec<Edge>) -> Order {
+        Order {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            order_id: "o1".into(),
+            order_update_id: 0,
+            zone_set_id: None,
+            nodes,
+            edges,
+        }
+    }
+
+    #[rstest]
+    fn test_order_to_feature_collection_with_straight_edge() {
+        let order = order(
+            vec![node("n1", 0, Some((0.0, 0.0))), node("n2", 2, Some((3.0, 4.0)))],
+            vec![edge("e1", 1, "n1", "n2")],
+        );
+
+        let collection = order_to_feature_collection(&order, 8);
+
+        assert_that!(collection["type"], eq(&serde_json::json!("FeatureCollection")));
+        assert_that!(collection["features"][0]["geometry"], eq(&serde_json::json!({ "type": "Point", "coordinates": [0.0, 0.0] })));
+        assert_that!(collection["features"][1]["properties"]["nodeId"], eq(&serde_json::json!("n2")));
+        assert_that!(collection["features"][2]["geometry"], eq(&serde_json::json!({ "type": "LineString", "coordinates": [[0.0, 0.0], [3.0, 4.0]] })));
+        assert_that!(collection["features"][2]["properties"]["released"], eq(&serde_json::json!(false)));
+    }
+
+    #[rstest]
+    fn test_order_to_feature_collection_samples_trajectory() {
+        let mut curved = edge("e1", 1, "n1", "n2");
+        curved.trajectory = Some(quarter_circle());
+        let order = order(vec![node("n1", 0, None), node("n2", 2, None)], vec![curved]);
+
+        let collection = order_to_feature_collection(&order, 5);
+
+        assert_that!(collection["features"].as_array().unwrap(), len(eq(1)));
+        assert_that!(collection["features"][0]["geometry"]["coordinates"].as_array().unwrap(), len(eq(5)));
+        assert_that!(collection["features"][0]["geometry"]["coordinates"][4], eq(&serde_json::json!([0.0, 1.0])));
+    }
+
+    #[rstest]
+    fn test_order_to_feature_collection_omits_edges_without_geometry() {
+        let order = order(vec![node("n1", 0, None), node("n2", 2, None)], vec![edge("e1", 1, "n1", "n2")]);
+
+        let collection = order_to_feature_collection(&order, 8);
+
+        assert_that!(collection["features"].as_array().unwrap(), empty());
+    }
+
+    #[rstest]
+    fn test_trajectory_to_feature() {
+        assert_that!(trajectory_to_feature(&quarter_circle(), 2), some(anything()));
+        assert_that!(trajectory_to_feature(&quarter_circle(), 1), none());
+    }
+}
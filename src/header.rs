@@ -0,0 +1,351 @@
+//!
+//! Monitoring of the header ids of messages, which are incremented by one with each message sent on a topic.
+//!
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+use crate::common::{HeaderId, Timestamp};
+
+/// Header shared by all messages, identifying the AGV and the message on its topic.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct Header {
+    /// Header id of the message, incremented by one with each message sent on the topic.
+    pub header_id: HeaderId,
+    /// Time at which the message was sent.
+    pub timestamp: Timestamp,
+    /// Version of the protocol, e.g. `2.0.0`.
+    pub version: String,
+    /// Manufacturer of the AGV.
+    pub manufacturer: String,
+    /// Serial number of the AGV.
+    pub serial_number: String,
+}
+
+impl Header {
+
+    /// Returns the header of the message `header_id` of the AGV `serial_number` of `manufacturer` for protocol
+    /// version `2.0.0`.
+    pub fn new(manufacturer: impl Into<String>, serial_number: impl Into<String>, header_id: HeaderId, timestamp: Timestamp) -> Self {
+        Header {
+            header_id,
+            timestamp,
+            version: "2.0.0".into(),
+            manufacturer: manufacturer.into(),
+            serial_number: serial_number.into(),
+        }
+    }
+}
+
+/// Identity of an AGV, which is part of the header of all its messages.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct AgvIdentity {
+    /// Manufacturer of the AGV.
+    pub manufacturer: String,
+    /// Serial number of the AGV.
+    pub serial_number: String,
+}
+
+impl AgvIdentity {
+
+    /// Returns the identity of the AGV `serial_number` of `manufacturer`.
+    pub fn new(manufacturer: impl Into<String>, serial_number: impl Into<String>) -> Self {
+        AgvIdentity {
+            manufacturer: manufacturer.into(),
+            serial_number: serial_number.into(),
+        }
+    }
+
+    /// Returns the header of the message `header_id` of the AGV, see [`Header::new`].
+    pub fn header(&self, header_id: HeaderId, timestamp: Timestamp) -> Header {
+        Header::new(self.manufacturer.clone(), self.serial_number.clone(), header_id, timestamp)
+    }
+}
+
+/// Topic of a message, e.g. to distinguish the header ids of the states and the visualizations of an AGV.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum Topic {
+    /// `order`
+    Order,
+    /// `instantActions`
+    InstantActions,
+    /// `state`
+    State,
+    /// `visualization`
+    Visualization,
+    /// `connection`
+    Connection,
+    /// `factsheet`
+    Factsheet,
+}
+
+impl Topic {
+
+    /// Returns the name of the topic as used in MQTT topics, e.g. `instantActions`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Topic::Order => "order",
+            Topic::InstantActions => "instantActions",
+            Topic::State => "state",
+            Topic::Visualization => "visualization",
+            Topic::Connection => "connection",
+            Topic::Factsheet => "factsheet",
+        }
+    }
+}
+
+impl core::fmt::Display for Topic {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// Relation of an observed header id to the previous header id of the same AGV and topic, see
+/// [`HeaderMonitor::observe`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum HeaderEvent {
+    /// The first message of the AGV on the topic.
+    First,
+    /// The header id follows the previous one.
+    Consecutive,
+    /// The header id skips `missed` header ids, e.g. due to messages lost on the way.
+    Gap {
+        /// Number of skipped header ids.
+        missed: u64,
+    },
+    /// The header id equals the previous one, e.g. due to a message delivered twice.
+    Duplicate,
+    /// The header id is lower than the previous one, e.g. due to a reboot of the AGV.
+    Reset,
+}
+
+/// Statistics of the header ids of an AGV on a topic, see [`HeaderMonitor::statistics`].
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct HeaderStatistics {
+    /// Last observed header id.
+    pub last_header_id: HeaderId,
+    /// Number of observed messages, including duplicates.
+    pub received: u64,
+    /// Number of header ids skipped by gaps.
+    pub missed: u64,
+    /// Number of messages repeating the previous header id.
+    pub duplicates: u64,
+    /// Number of header ids lower than the previous one.
+    pub resets: u64,
+}
+
+/// Source of the current time (UTC) stamped onto messages.
+///
+/// Implemented for closures, e.g. `|| chrono::Utc::now()` on targets with a system clock, or a closure reading a
+/// real-time clock on `no_std` targets.
+pub trait Clock {
+
+    /// Returns the current time.
+    fn now(&mut self) -> Timestamp;
+}
+
+impl<F: FnMut() -> Timestamp> Clock for F {
+    fn now(&mut self) -> Timestamp {
+        self()
+    }
+}
+
+/// Generates the headers of the messages of an AGV, maintaining an independent header id per topic.
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct HeaderGenerator<C> {
+    identity: AgvIdentity,
+    version: String,
+    clock: C,
+    header_ids: BTreeMap<Topic, HeaderId>,
+}
+
+impl<C: Clock> HeaderGenerator<C> {
+
+    /// Creates a generator of the headers of the AGV `identity` for protocol version `2.0.0`, stamped with the time of
+    /// `clock` and starting with header id zero on every topic.
+    pub fn new(identity: AgvIdentity, clock: C) -> Self {
+        HeaderGenerator {
+            identity,
+            version: "2.0.0".into(),
+            clock,
+            header_ids: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the protocol version of the generated headers, e.g. `2.0.0`.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// Returns the identity of the AGV.
+    pub fn identity(&self) -> &AgvIdentity {
+        &self.identity
+    }
+
+    /// Returns the header id of the next message on `topic`.
+    pub fn peek(&self, topic: Topic) -> HeaderId {
+        self.header_ids.get(&topic).copied().unwrap_or(0)
+    }
+
+    /// Returns the header of the next message on `topic` stamped with the current time and increments the header id
+    /// of the topic, wrapping around at its maximum.
+    pub fn next(&mut self, topic: Topic) -> Header {
+        let header_id = self.header_ids.entry(topic).or_insert(0);
+        let header = Header {
+            header_id: *header_id,
+            timestamp: self.clock.now(),
+            version: self.version.clone(),
+            manufacturer: self.identity.manufacturer.clone(),
+            serial_number: self.identity.serial_number.clone(),
+        };
+        *header_id = header_id.wrapping_add(1);
+        header
+    }
+}
+
+/// Tracks the header ids of messages per AGV and topic and detects gaps, duplicates and resets.
+#[derive(Clone, Default)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct HeaderMonitor {
+    statistics: BTreeMap<(String, String, Topic), HeaderStatistics>,
+}
+
+impl HeaderMonitor {
+
+    /// Creates a monitor which has not observed any message yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the header id of a message of the AGV `serial_number` of `manufacturer` on `topic` and returns its
+    /// relation to the previous header id.
+    pub fn observe(&mut self, manufacturer: &str, serial_number: &str, topic: Topic, header_id: HeaderId) -> HeaderEvent {
+        let key = (String::from(manufacturer), String::from(serial_number), topic);
+        let Some(statistics) = self.statistics.get_mut(&key) else {
+            self.statistics.insert(key, HeaderStatistics { last_header_id: header_id, received: 1, ..HeaderStatistics::default() });
+            return HeaderEvent::First;
+        };
+        let event = match header_id.checked_sub(statistics.last_header_id) {
+            Some(0) => {
+                statistics.duplicates += 1;
+                HeaderEvent::Duplicate
+            }
+            Some(1) => HeaderEvent::Consecutive,
+            Some(difference) => {
+                statistics.missed += difference - 1;
+                HeaderEvent::Gap { missed: difference - 1 }
+            }
+            None => {
+                statistics.resets += 1;
+                HeaderEvent::Reset
+            }
+        };
+        statistics.last_header_id = header_id;
+        statistics.received += 1;
+        event
+    }
+
+    /// Returns the statistics of the AGV `serial_number` of `manufacturer` on `topic`, if any message was observed.
+    pub fn statistics(&self, manufacturer: &str, serial_number: &str, topic: Topic) -> Option<HeaderStatistics> {
+        self.statistics.get(&(String::from(manufacturer), String::from(serial_number), topic)).copied()
+    }
+
+    /// Forgets the header ids of all topics of the AGV `serial_number` of `manufacturer`, e.g. when it is removed
+    /// from the fleet.
+    pub fn forget(&mut self, manufacturer: &str, serial_number: &str) {
+        self.statistics.retain(|(known_manufacturer, known_serial_number, _), _| known_manufacturer != manufacturer || known_serial_number != serial_number);
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec::Vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::timestamp::from_unix_millis;
+    use super::{AgvIdentity, Header, HeaderEvent, HeaderGenerator, HeaderMonitor, HeaderStatistics, Topic};
+
+    #[rstest]
+    fn test_HeaderGenerator_next() {
+        let mut millis = 0;
+        let mut generator = HeaderGenerator::new(AgvIdentity::new("Fubar Co.", "1234"), || {
+            millis += 100;
+            from_unix_millis(millis).unwrap()
+        });
+
+        let headers = [Topic::State, Topic::State, Topic::Visualization, Topic::State].into_iter()
+            .map(|topic| generator.next(topic))
+            .map(|header| (header.header_id, header.timestamp))
+            .collect::<Vec<_>>();
+
+        assert_that!(headers, eq(&[
+            (0, from_unix_millis(100).unwrap()),
+            (1, from_unix_millis(200).unwrap()),
+            (0, from_unix_millis(300).unwrap()),
+            (2, from_unix_millis(400).unwrap()),
+        ]));
+        assert_that!(generator.peek(Topic::State), eq(3));
+        assert_that!(generator.peek(Topic::Connection), eq(0));
+        assert_that!(generator.next(Topic::Connection), matches_pattern!(Header {
+            version: eq("2.0.0"),
+            manufacturer: eq("Fubar Co."),
+            serial_number: eq("1234")
+        }));
+    }
+
+    #[rstest]
+    fn test_HeaderMonitor_observe() {
+        let mut monitor = HeaderMonitor::new();
+
+        let events = [3, 4, 4, 7, 1, 2].into_iter()
+            .map(|header_id| monitor.observe("Fubar Co.", "1234", Topic::State, header_id))
+            .collect::<Vec<_>>();
+
+        assert_that!(events, eq(&[
+            HeaderEvent::First,
+            HeaderEvent::Consecutive,
+            HeaderEvent::Duplicate,
+            HeaderEvent::Gap { missed: 2 },
+            HeaderEvent::Reset,
+            HeaderEvent::Consecutive,
+        ]));
+        assert_that!(monitor.statistics("Fubar Co.", "1234", Topic::State), some(eq(HeaderStatistics {
+            last_header_id: 2,
+            received: 6,
+            missed: 2,
+            duplicates: 1,
+            resets: 1,
+        })));
+    }
+
+    #[rstest]
+    fn test_HeaderMonitor_separates_agvs_and_topics() {
+        let mut monitor = HeaderMonitor::new();
+
+        monitor.observe("Fubar Co.", "1234", Topic::State, 1);
+
+        assert_that!(monitor.observe("Fubar Co.", "1234", Topic::Visualization, 5), eq(HeaderEvent::First));
+        assert_that!(monitor.observe("Fubar Co.", "5678", Topic::State, 5), eq(HeaderEvent::First));
+        assert_that!(monitor.observe("Fubar Co.", "1234", Topic::State, 2), eq(HeaderEvent::Consecutive));
+    }
+
+    #[rstest]
+    fn test_HeaderMonitor_forget() {
+        let mut monitor = HeaderMonitor::new();
+        monitor.observe("Fubar Co.", "1234", Topic::State, 1);
+        monitor.observe("Fubar Co.", "5678", Topic::State, 1);
+
+        monitor.forget("Fubar Co.", "1234");
+
+        assert_that!(monitor.statistics("Fubar Co.", "1234", Topic::State), none());
+        assert_that!(monitor.statistics("Fubar Co.", "5678", Topic::State), some(anything()));
+    }
+}
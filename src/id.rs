@@ -0,0 +1,299 @@
+//!
+//! Identifiers of orders, nodes, edges, actions, maps and zone sets.
+//!
+//! If the feature `typed-ids` is enabled, the identifiers are distinct newtypes of [`String`], so that e.g. an edge id
+//! cannot be passed where a node id is expected. Otherwise they are aliases of [`String`]. In both cases identifiers
+//! can be created via `From<&str>` and borrowed as `&str` via `as_str`.
+//!
+//! Unique identifiers can be generated by an [`IdFactory`], e.g. random ones by [`RandomIds`] if the feature `uuid` is
+//! enabled.
+//!
+use alloc::string::String;
+
+macro_rules! identifier {
+    ($(#[$meta:meta])* $name:ident) => {
+
+        $(#[$meta])*
+        #[cfg(not(feature = "typed-ids"))]
+        pub type $name = alloc::string::String;
+
+        $(#[$meta])*
+        #[cfg(feature = "typed-ids")]
+        #[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        pub struct $name(String);
+
+        #[cfg(feature = "typed-ids")]
+        impl $name {
+
+            /// Returns the identifier `id`.
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
+
+            /// Returns the identifier as string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Returns the identifier as string.
+            pub fn into_string(self) -> String {
+                self.0
+            }
+
+            /// Returns a random identifier, i.e. a version 4 UUID, see [`RandomIds`].
+            #[cfg(feature = "uuid")]
+            pub fn random() -> Self {
+                Self(RandomIds.next_id())
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl core::ops::Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl core::borrow::Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self(id.into())
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl core::str::FromStr for $name {
+            type Err = core::convert::Infallible;
+
+            fn from_str(id: &str) -> Result<Self, Self::Err> {
+                Ok(Self(id.into()))
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool {
+                self.0 == *other
+            }
+        }
+
+        #[cfg(feature = "typed-ids")]
+        impl core::fmt::Display for $name {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str(&self.0)
+            }
+        }
+
+        #[cfg(all(feature = "typed-ids", feature = "defmt"))]
+        impl defmt::Format for $name {
+            fn format(&self, formatter: defmt::Formatter) {
+                defmt::write!(formatter, "{=str}", self.0.as_str());
+            }
+        }
+
+        #[cfg(all(feature = "typed-ids", feature = "ts-rs"))]
+        impl ts_rs::TS for $name {
+            type WithoutGenerics = Self;
+
+            fn name() -> String {
+                String::name()
+            }
+
+            fn inline() -> String {
+                String::inline()
+            }
+
+            fn inline_flattened() -> String {
+                String::inline_flattened()
+            }
+
+            fn decl() -> String {
+                String::decl()
+            }
+
+            fn decl_concrete() -> String {
+                String::decl_concrete()
+            }
+        }
+    };
+}
+
+identifier!(
+    /// Identifier of an order (`orderId`).
+    OrderId
+);
+
+identifier!(
+    /// Identifier of a node (`nodeId`), e.g. also of the start and end node of an edge.
+    NodeId
+);
+
+identifier!(
+    /// Identifier of an edge (`edgeId`).
+    EdgeId
+);
+
+identifier!(
+    /// Identifier of an action (`actionId`).
+    ActionId
+);
+
+identifier!(
+    /// Identifier of a map (`mapId`).
+    MapId
+);
+
+identifier!(
+    /// Identifier of a zone set (`zoneSetId`).
+    ZoneSetId
+);
+
+/// Source of unique identifiers, e.g. of actions and orders.
+///
+/// Implemented for closures returning strings, for the
+/// [`ActionIdGenerator`](crate::standard_action::ActionIdGenerator) and, if the feature `uuid` is enabled, for
+/// [`RandomIds`].
+pub trait IdFactory {
+
+    /// Returns the next identifier.
+    fn next_id(&mut self) -> String;
+
+    /// Returns the next identifier as action id.
+    fn action_id(&mut self) -> ActionId {
+        identifier(self.next_id())
+    }
+
+    /// Returns the next identifier as order id.
+    fn order_id(&mut self) -> OrderId {
+        identifier(self.next_id())
+    }
+}
+
+impl<F: FnMut() -> String> IdFactory for F {
+    fn next_id(&mut self) -> String {
+        self()
+    }
+}
+
+/// Factory of random identifiers, which are version 4 UUIDs in their hyphenated form, e.g.
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+#[cfg(feature = "uuid")]
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct RandomIds;
+
+#[cfg(feature = "uuid")]
+impl IdFactory for RandomIds {
+    fn next_id(&mut self) -> String {
+        use alloc::string::ToString;
+        uuid::Uuid::new_v4().to_string()
+    }
+}
+
+fn identifier<T: From<String>>(id: String) -> T {
+    T::from(id)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    #[cfg(feature = "typed-ids")]
+    use alloc::string::{String, ToString};
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use super::IdFactory;
+    #[cfg(feature = "typed-ids")]
+    use super::NodeId;
+    #[cfg(all(feature = "typed-ids", feature = "serde"))]
+    use super::EdgeId;
+    #[cfg(feature = "uuid")]
+    use super::{ActionId, OrderId};
+
+    #[rstest]
+    fn test_IdFactory_closure() {
+        let mut next = 0;
+        let mut factory = || {
+            next += 1;
+            alloc::format!("id-{next}")
+        };
+
+        assert_that!(factory.action_id(), eq("id-1"));
+        assert_that!(factory.order_id(), eq("id-2"));
+    }
+
+    #[cfg(feature = "uuid")]
+    #[rstest]
+    fn test_random_ids_are_uuids() {
+        let (first, second) = (ActionId::random(), ActionId::random());
+
+        assert_that!(first.len(), eq(36));
+        assert_that!(first.as_str().parse::<uuid::Uuid>(), ok(anything()));
+        assert_that!(first, not(eq(&second)));
+        assert_that!(OrderId::random().to_string().chars().nth(14), some(eq('4')));
+    }
+
+    #[cfg(feature = "typed-ids")]
+    #[rstest]
+    fn test_NodeId_conversions() {
+        let node_id = "n1".parse::<NodeId>().unwrap();
+
+        assert_that!(node_id, eq(&NodeId::from("n1")));
+        assert_that!(node_id.to_string(), eq("n1"));
+        assert_that!(node_id.as_str(), eq("n1"));
+        assert_that!(String::from(node_id), eq("n1"));
+    }
+
+    #[cfg(all(feature = "typed-ids", feature = "serde"))]
+    #[rstest]
+    fn test_serde_EdgeId_is_transparent() {
+        assert_that!(serde_json::to_string(&EdgeId::from("e1")), ok(eq(r#""e1""#)));
+        assert_that!(serde_json::from_str::<EdgeId>(r#""e1""#), ok(eq(&EdgeId::from("e1"))));
+    }
+}
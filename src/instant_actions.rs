@@ -2,17 +2,27 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use crate::action::Action;
 use crate::common::{HeaderId, Timestamp};
+use crate::header::Header;
+#[cfg(feature = "json")]
+use crate::factsheet::ProtocolLimits;
 
 /// Instant actions that the AGV is to execute as soon as they arrive.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct InstantActions {
     /// header_id of the message. The header_id is defined per topic and incremented by 1 with each sent (but not necessarily received) message.
+    #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
     pub header_id: HeaderId,
     /// Timestamp (ISO8601, UTC); YYYY-MM-DDTHH:mm:ss.ssZ; e.g. 2017-04-15T11:40:03.12Z
+    #[cfg_attr(feature = "defmt", defmt(Display2Format))]
+    #[cfg_attr(feature = "serde", serde(with = "crate::timestamp"))]
+    #[cfg_attr(feature = "ts-rs", ts(as = "String"))]
     pub timestamp: Timestamp,
     /// Version of the protocol [Major].[Minor].[Patch], e.g. 1.3.2
     pub version: String,
@@ -23,3 +33,39 @@ pub struct InstantActions {
     /// Array of actions that need to be performed immediately and are not part of the regular order.
     pub instant_actions: Vec<Action>
 }
+
+impl InstantActions {
+
+    /// Returns the instant actions `instant_actions` sent with `header`.
+    pub fn new(header: Header, instant_actions: Vec<Action>) -> Self {
+        InstantActions {
+            header_id: header.header_id,
+            timestamp: header.timestamp,
+            version: header.version,
+            manufacturer: header.manufacturer,
+            serial_number: header.serial_number,
+            instant_actions,
+        }
+    }
+
+    /// Returns the instant actions consisting of `action` only, e.g. a one-off `cancelOrder`.
+    pub fn single(header: Header, action: Action) -> Self {
+        InstantActions::new(header, alloc::vec![action])
+    }
+}
+
+#[cfg(feature = "json")]
+impl InstantActions {
+
+    /// Returns the length in bytes of the compact JSON representation of the instant actions, as published via MQTT.
+    ///
+    /// Returns [`usize::MAX`] if the instant actions cannot be serialized.
+    pub fn serialized_len_estimate(&self) -> usize {
+        crate::json::serialized_len(self)
+    }
+
+    /// Returns `true` if the instant actions does not exceed the maximum message length (`msgLen`) of the given protocol limits.
+    pub fn fits_within(&self, limits: &ProtocolLimits) -> bool {
+        crate::json::fits_within(self.serialized_len_estimate(), limits)
+    }
+}
@@ -0,0 +1,358 @@
+//!
+//! JSON specific functionality.
+//!
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::factsheet::ProtocolLimits;
+
+/// Serialization into canonical JSON.
+///
+/// The canonical JSON of a value has its object keys sorted, contains no insignificant whitespace and formats numbers
+/// in their shortest representation, with integral floating point numbers written without fraction (e.g. `1` instead
+/// of `1.0`). Thus, equal messages produce byte-wise identical JSON, which makes it suitable for hashing and
+/// deduplication.
+pub trait ToCanonicalJson {
+
+    /// Serializes `self` into canonical JSON.
+    fn to_canonical_json(&self) -> Result<String, serde_json::Error>;
+}
+
+impl <T: Serialize + ?Sized> ToCanonicalJson for T {
+
+    fn to_canonical_json(&self) -> Result<String, serde_json::Error> {
+        // Parsing the serialized JSON keeps `f32` numbers in their shortest representation, which `to_value` widens to
+        // `f64`, e.g. `0.10000000149011612` instead of `0.1`.
+        let value = serde_json::from_str::<Value>(&serde_json::to_string(self)?)?;
+        let mut output = String::new();
+        write_canonical(&mut output, &value)?;
+        Ok(output)
+    }
+}
+
+/// Returns the length in bytes of the compact JSON representation of a value or [`usize::MAX`] if it cannot be
+/// serialized.
+pub(crate) fn serialized_len<T: Serialize + ?Sized>(value: &T) -> usize {
+    serde_json::to_vec(value).map_or(usize::MAX, |json| json.len())
+}
+
+/// Returns `true` if a message of the given length does not exceed the maximum message length of the protocol limits.
+pub(crate) fn fits_within(len: usize, limits: &ProtocolLimits) -> bool {
+    limits.max_string_lens.msg_len.map_or(true, |max| (len as u64) <= max)
+}
+
+/// Largest integer up to which every integer is exactly representable as `f64`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+fn write_canonical(output: &mut String, value: &Value) -> Result<(), serde_json::Error> {
+    match value {
+        Value::Null => output.push_str("null"),
+        Value::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+        Value::Number(number) => {
+            match number.as_f64() {
+                Some(float) if number.is_f64() && (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&float) && float == (float as i64) as f64 => {
+                    output.push_str(&serde_json::to_string(&(float as i64))?)
+                }
+                // Depending on its version, serde_json writes positive exponents with or without sign.
+                _ => output.push_str(&serde_json::to_string(number)?.replace("e+", "e")),
+            }
+        }
+        Value::String(value) => output.push_str(&serde_json::to_string(value)?),
+        Value::Array(values) => {
+            output.push('[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_canonical(output, value)?;
+            }
+            output.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_unstable_by_key(|(key, _)| *key);
+            output.push('{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                output.push_str(&serde_json::to_string(key)?);
+                output.push(':');
+                write_canonical(output, value)?;
+            }
+            output.push('}');
+        }
+    }
+    Ok(())
+}
+
+/// Implements the conversions from and into [`Value`] as well as [`patch_with`](crate::v2_0::order::Order::patch_with)
+/// for a message.
+#[cfg(feature = "json-value")]
+macro_rules! json_value {
+    ($($message:ty),* $(,)?) => {
+        $(
+            impl TryFrom<Value> for $message {
+                type Error = serde_json::Error;
+
+                fn try_from(value: Value) -> Result<Self, Self::Error> {
+                    serde_json::from_value(value)
+                }
+            }
+
+            impl TryFrom<$message> for Value {
+                type Error = serde_json::Error;
+
+                fn try_from(message: $message) -> Result<Self, Self::Error> {
+                    serde_json::to_value(message)
+                }
+            }
+
+            impl $message {
+
+                /// Returns the JSON representation of the message with the given JSON merge patch
+                /// ([RFC 7396](https://www.rfc-editor.org/rfc/rfc7396)) applied, e.g. to add vendor specific fields.
+                ///
+                /// Fields set to `null` in the patch are removed from the result.
+                pub fn patch_with(&self, patch: Value) -> Result<Value, serde_json::Error> {
+                    let mut value = serde_json::to_value(self)?;
+                    merge_patch(&mut value, patch);
+                    Ok(value)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "json-value")]
+json_value!(
+    crate::connection::Connection,
+    crate::factsheet::Factsheet,
+    crate::instant_actions::InstantActions,
+    crate::order::Order,
+    crate::state::State,
+    crate::visualization::Visualization,
+);
+
+#[cfg(feature = "json-value")]
+fn merge_patch(target: &mut Value, patch: Value) {
+    match patch {
+        Value::Object(patch) => {
+            if !target.is_object() {
+                *target = Value::Object(serde_json::Map::new());
+            }
+            if let Value::Object(target) = target {
+                for (key, value) in patch {
+                    if value.is_null() {
+                        target.remove(&key);
+                    }
+                    else {
+                        merge_patch(target.entry(key).or_insert(Value::Null), value);
+                    }
+                }
+            }
+        }
+        patch => *target = patch,
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use alloc::vec;
+
+    use crate::action::{Action, BlockingType};
+    #[cfg(all(feature = "json-value", feature = "strict-floats", not(feature = "finite-floats")))]
+    use crate::action::{ActionParameter, ActionParameterValue};
+    use crate::checked::checked;
+    use crate::common::Velocity;
+    use crate::connection::{Connection, ConnectionState};
+    use crate::factsheet::{MaxArrayLens, MaxStringLens, ProtocolLimits, Timing};
+    use crate::instant_actions::InstantActions;
+    use crate::timestamp::from_unix_millis;
+    use super::ToCanonicalJson;
+
+    #[rstest]
+    fn test_to_canonical_json_sorts_keys_without_whitespace() {
+        let connection = Connection {
+            header_id: 42,
+            timestamp: from_unix_millis(1_492_256_403_120).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            connection_state: ConnectionState::Online,
+        };
+
+        assert_that!(connection.to_canonical_json(), ok(eq(
+            r#"{"connectionState":"ONLINE","headerId":42,"manufacturer":"Fubar Co.","serialNumber":"1234","timestamp":"2017-04-15T11:40:03.120Z","version":"2.0.0"}"#
+        )));
+    }
+
+    #[rstest]
+    fn test_to_canonical_json_formats_floats() {
+        let velocity = Velocity { vx: Some(checked(1.0)), vy: Some(checked(-0.5)), omega: None };
+
+        assert_that!(velocity.to_canonical_json(), ok(eq(r#"{"omega":null,"vx":1,"vy":-0.5}"#)));
+    }
+
+    #[rstest]
+    fn test_to_canonical_json_formats_f32_in_shortest_representation() {
+        let velocity = Velocity { vx: Some(checked(0.1)), vy: Some(checked(-1.3)), omega: Some(checked(3.4028235e38)) };
+
+        assert_that!(velocity.to_canonical_json(), ok(eq(r#"{"omega":3.4028235e38,"vx":0.1,"vy":-1.3}"#)));
+    }
+
+    #[rstest]
+    #[case(serde_json::json!(2e-7), "2e-7")]
+    #[case(serde_json::json!(1e300), "1e300")]
+    #[case(serde_json::json!(-3.0), "-3")]
+    #[case(serde_json::json!(u64::MAX), "18446744073709551615")]
+    fn test_to_canonical_json_formats_numbers(#[case] value: serde_json::Value, #[case] expected: &str) {
+        assert_that!(value.to_canonical_json(), ok(eq(expected)));
+    }
+
+    #[rstest]
+    fn test_to_canonical_json_is_identical_for_equal_values() {
+        let a: serde_json::Value = serde_json::from_str(r#"{ "vy": 2.0, "vx": 1 }"#).unwrap();
+        let b: serde_json::Value = serde_json::from_str(r#"{"vx":1.0,"vy":2}"#).unwrap();
+
+        assert_that!(a.to_canonical_json().unwrap(), eq(&b.to_canonical_json().unwrap()));
+    }
+
+    fn protocol_limits(msg_len: Option<u64>) -> ProtocolLimits {
+        ProtocolLimits {
+            max_string_lens: MaxStringLens {
+                msg_len,
+                topic_serial_len: None,
+                topic_elem_len: None,
+                id_len: None,
+                id_numerical_only: None,
+                enum_len: None,
+                load_id_len: None,
+            },
+            max_array_lens: MaxArrayLens {
+                order_nodes: 0,
+                order_edges: 0,
+                node_actions: 0,
+                edge_actions: 0,
+                actions_actions_parameters: 0,
+                instant_actions: 0,
+                trajectory_knot_vector: 0,
+                trajectory_control_points: 0,
+                state_node_states: 0,
+                state_edge_states: 0,
+                state_loads: 0,
+                state_action_states: 0,
+                state_errors: 0,
+                state_information: 0,
+                error_error_references: 0,
+                information_info_references: 0,
+            },
+            timing: Timing {
+                min_order_interval: checked(1.0),
+                min_state_interval: checked(1.0),
+                default_state_interval: None,
+                visualization_interval: None,
+            },
+        }
+    }
+
+    fn instant_actions() -> InstantActions {
+        InstantActions {
+            header_id: 1,
+            timestamp: from_unix_millis(1_492_256_403_120).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            instant_actions: vec![Action {
+                action_type: "pause".into(),
+                action_id: "a1".into(),
+                action_description: None,
+                blocking_type: BlockingType::Hard,
+                action_parameters: vec![],
+            }],
+        }
+    }
+
+    #[rstest]
+    fn test_serialized_len_estimate_matches_compact_json() {
+        let instant_actions = instant_actions();
+
+        assert_that!(instant_actions.serialized_len_estimate(), eq(serde_json::to_string(&instant_actions).unwrap().len()));
+    }
+
+    #[rstest]
+    #[case(None, true)]
+    #[case(Some(1000), true)]
+    #[case(Some(100), false)]
+    fn test_fits_within(#[case] msg_len: Option<u64>, #[case] expected: bool) {
+        assert_that!(instant_actions().fits_within(&protocol_limits(msg_len)), eq(expected));
+    }
+
+    #[cfg(feature = "json-value")]
+    fn connection() -> Connection {
+        Connection {
+            header_id: 42,
+            timestamp: from_unix_millis(1_492_256_403_120).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            connection_state: ConnectionState::Online,
+        }
+    }
+
+    #[cfg(feature = "json-value")]
+    #[rstest]
+    fn test_Connection_try_into_and_from_Value() {
+        let value = serde_json::Value::try_from(connection()).unwrap();
+
+        assert_that!(value["connectionState"], eq(&serde_json::json!("ONLINE")));
+        assert_that!(Connection::try_from(value), ok(matches_pattern!(Connection {
+            header_id: eq(&42),
+            serial_number: eq("1234"),
+        })));
+    }
+
+    #[cfg(feature = "json-value")]
+    #[rstest]
+    fn test_Connection_try_from_invalid_Value() {
+        assert_that!(Connection::try_from(serde_json::json!({ "headerId": "42" })), err(anything()));
+    }
+
+    #[cfg(all(feature = "json-value", feature = "strict-floats", not(feature = "finite-floats")))]
+    #[rstest]
+    fn test_InstantActions_try_into_Value_rejects_non_finite_floats() {
+        let mut instant_actions = instant_actions();
+        instant_actions.instant_actions[0].action_parameters.push(ActionParameter {
+            key: "duration".into(),
+            value: ActionParameterValue::Float(f64::NAN),
+        });
+
+        assert_that!(serde_json::Value::try_from(instant_actions), err(anything()));
+    }
+
+    #[cfg(feature = "json-value")]
+    #[rstest]
+    fn test_Connection_patch_with() {
+        let patched = connection().patch_with(serde_json::json!({
+            "version": null,
+            "manufacturer": "ACME",
+            "vendor": { "firmware": "1.2.3" },
+        }));
+
+        assert_that!(patched, ok(eq(&serde_json::json!({
+            "headerId": 42,
+            "timestamp": "2017-04-15T11:40:03.120Z",
+            "manufacturer": "ACME",
+            "serialNumber": "1234",
+            "connectionState": "ONLINE",
+            "vendor": { "firmware": "1.2.3" },
+        }))));
+    }
+}
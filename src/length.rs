@@ -0,0 +1,67 @@
+//!
+//! Lengths of edges derived from their trajectories or the positions of their nodes.
+//!
+use crate::checked::Raw;
+use crate::order::{Edge, Order};
+use crate::pose::distance;
+
+/// Maximum deviation in meters of the polyline measured by [`Edge::effective_length`] from the trajectory.
+const LENGTH_TOLERANCE: f32 = 1e-3;
+
+impl Edge {
+
+    /// Returns the length of the edge in meters, which is `length` if defined, otherwise the length of the trajectory,
+    /// otherwise the straight-line distance between the positions of the start and end node in `order`.
+    ///
+    /// The length of a trajectory is approximated within 1 mm, see [`Trajectory::length`](crate::common::Trajectory::length),
+    /// where a malformed trajectory is ignored. Returns `None` if the length cannot be derived, e.g. because a node has
+    /// no position or the nodes are on different maps.
+    pub fn effective_length(&self, order: &Order) -> Option<f32> {
+        self.length.map(Raw::raw)
+            .or_else(|| self.trajectory.as_ref()?.length(LENGTH_TOLERANCE))
+            .or_else(|| {
+                let position = |node_id: &str| order.node_by_id(node_id)?.node_position.as_ref();
+                let (start, end) = (position(&self.start_node_id)?, position(&self.end_node_id)?);
+                (start.map_id == end.map_id).then(|| distance(start, end))
+            })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::f32::consts::FRAC_PI_2;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::builder::EdgeBuilder;
+    use crate::checked::checked;
+    use crate::order::{Edge, Node, Order};
+    use crate::timestamp::from_unix_millis;
+    use crate::trajectory::tests::quarter_circle;
+
+    fn order(edge: EdgeBuilder, end_map_id: &str) -> Order {
+        Order::builder("o1", from_unix_millis(0).unwrap())
+            .node(Node::builder("n1").position(checked(0.0), checked(0.0), "map"))
+            .edge_to(edge, Node::builder("n2").position(checked(3.0), checked(4.0), end_map_id))
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case::length(Edge::builder("e1").length(checked(7.0)).trajectory(quarter_circle()), 7.0)]
+    #[case::trajectory(Edge::builder("e1").trajectory(quarter_circle()), FRAC_PI_2)]
+    #[case::node_distance(Edge::builder("e1"), 5.0)]
+    fn test_Edge_effective_length(#[case] edge: EdgeBuilder, #[case] expected: f32) {
+        let order = order(edge, "map");
+
+        assert_that!(order.edges[0].effective_length(&order), some(near(expected, 1e-3)));
+    }
+
+    #[rstest]
+    fn test_Edge_effective_length_across_maps_is_unknown() {
+        let order = order(Edge::builder("e1"), "other");
+
+        assert_that!(order.edges[0].effective_length(&order), none());
+    }
+}
@@ -8,13 +8,41 @@
 //!
 //! | Feature   | Default  | Description                                                                                                            |
 //! | --------- |:--------:| ---------------------------------------------------------------------------------------------------------------------- |
+//! | builders  | &#x2717; | When enabled, structs with many fields and no curated builder, e.g. `PhysicalParameters` and `LoadSet`, provide a generated `builder()`. |
+//! | checked-types | &#x2717; | When enabled, percentages, angles, distances and speeds use newtypes rejecting values outside of their range.   |
+//! | chrono    | &#x2714; | When enabled, timestamps are represented by `chrono::DateTime<Utc>`.                                                  |
+//! | defmt     | &#x2717; | When enabled, certain types will provide an implementation for the `defmt::Format` trait.                             |
+//! | ffi       | &#x2717; | When enabled, a C interface for parsing and serializing orders and states is available. Implies `json` and `std`.   |
+//! | finite-floats | &#x2717; | When enabled, floating point fields use `Finite`, which rejects NaN and infinity, and position types implement `Eq`, `Ord` and `Hash`. |
+//! | fixtures  | &#x2717; | When enabled, messages can be loaded from YAML and TOML fixtures. Implies `serde` and `std`.                        |
 //! | fmt       | &#x2714; | When enabled, certain types will provide an implementation for [`core::fmt::Debug`] and [`core::fmt::Display`] traits. |
+//! | geojson   | &#x2717; | When enabled, orders and trajectories can be exported as GeoJSON. Implies `json`.                                  |
+//! | json      | &#x2717; | When enabled, JSON specific functionality like canonical serialization is available. Implies `serde`.              |
+//! | json-value | &#x2717; | When enabled, messages can be converted from and into `serde_json::Value` and patched via `patch_with`. Implies `json`. |
+//! | jsonschema | &#x2717; | When enabled, raw messages can be validated against the JSON schemas of their topic via [jsonschema](https://docs.rs/jsonschema). Implies `json` and `std`. |
+//! | proptest  | &#x2717; | When enabled, [proptest](https://docs.rs/proptest) strategies generating spec-valid messages are available. Implies `fmt` and `std`. |
+//! | python    | &#x2717; | When enabled, `Order`, `State` and `InstantActions` are available as Python classes via [pyo3](https://docs.rs/pyo3). Implies `json` and `std`. |
+//! | ros2      | &#x2717; | When enabled, messages can be converted from and into plain structs mirroring the `vda5050_msgs` ROS 2 messages.   |
 //! | serde     | &#x2717; | When enabled, certain types will provide an implementation for [`serde::Serialize`] and [`serde::Deserialize`] traits. |
-//! | v2_0      | &#x2717; | When enabled, VDA5050 version 2 types are available.                                                                   |
+//! | strict-floats | &#x2717; | When enabled, (de-)serialization fails for NaN and infinite floating point numbers. Implies `serde`.           |
+//! | time      | &#x2717; | When enabled, timestamps are represented by `time::OffsetDateTime`.                                                   |
+//! | timestamp-millis | &#x2717; | When enabled, timestamps are represented by milliseconds since the unix epoch.                                 |
+//! | timestamp-string | &#x2717; | When enabled, timestamps are represented by the raw ISO8601 `String`.                                          |
+//! | ts-rs     | &#x2717; | When enabled, certain types will provide an implementation for the `ts_rs::TS` trait. Implies `serde` and `std`.       |
+//! | typed-ids | &#x2717; | When enabled, order, node, edge, action, map and zone set ids are distinct newtypes of `String` instead of aliases. |
+//! | units     | &#x2717; | When enabled, lengths, speeds, accelerations and masses of the factsheet use unit newtypes like `Meters` and `Kilograms`. |
+//! | uuid      | &#x2717; | When enabled, random ids based on version 4 UUIDs can be generated. Implies `typed-ids`.                               |
+//! | v2_0      | &#x2714; | When enabled, VDA5050 version 2 types are available.                                                                   |
+//! | wasm      | &#x2717; | When enabled, states and visualization messages can be decoded and encoded in JavaScript via [wasm-bindgen](https://docs.rs/wasm-bindgen). Implies `json` and `std`. |
 //!
 //! <sup>&#x2714; enabled, &#x2717; disabled</sup>
 //!
-#![cfg_attr(not(test), no_std)]
+//! At least one version feature (e.g. `v2_0`) and one timestamp feature (e.g. `chrono`) have to be enabled, otherwise
+//! the crate refuses to compile. If more than one timestamp feature is enabled, they take precedence in the order
+//! `chrono`, `time`, `timestamp-millis`, `timestamp-string`. Which features are enabled can be inspected at compile
+//! time via the constants in the [`features`] module.
+//!
+#![cfg_attr(not(any(test, feature = "ffi", feature = "fixtures", feature = "jsonschema", feature = "proptest", feature = "python", feature = "ts-rs", feature = "wasm")), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 #[cfg(test)]
@@ -22,38 +50,194 @@ extern crate std;
 
 extern crate alloc;
 
+#[cfg(not(any(feature = "v2_0", doc)))]
+compile_error!("vda5050-types: no VDA5050 version selected. Enable at least one version feature, e.g. `features = [\"v2_0\"]`.");
+
+#[cfg(not(any(feature = "chrono", feature = "time", feature = "timestamp-millis", feature = "timestamp-string")))]
+compile_error!("vda5050-types: no timestamp backend selected. Enable one of the features `chrono`, `time`, `timestamp-millis` or `timestamp-string`.");
+
+pub mod features;
+
+mod acceptance;
 mod action;
+mod angle;
+mod battery;
+mod bounds;
+#[cfg(feature = "serde")]
+mod bounded;
+mod builder;
+#[cfg(feature = "builders")]
+mod builders;
+mod checked;
 mod common;
+mod completeness;
 mod connection;
+mod delta;
+mod deviation;
+mod dot;
+mod error;
 mod factsheet;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod finite;
+#[cfg(feature = "fixtures")]
+mod fixtures;
+#[cfg(feature = "strict-floats")]
+mod float;
+mod frames;
+#[cfg(feature = "geojson")]
+mod geojson;
+mod header;
+mod id;
 mod instant_actions;
+#[cfg(feature = "json")]
+mod json;
+mod length;
+mod load;
+mod optional_parameter;
 mod order;
+mod pose;
+mod position;
+mod progression;
+#[cfg(feature = "python")]
+mod python;
+mod report;
+mod route;
+#[cfg(feature = "ros2")]
+mod ros2;
+#[cfg(feature = "jsonschema")]
+mod schema;
+mod standard_action;
 mod state;
+#[cfg(feature = "proptest")]
+mod strategy;
+mod timestamp;
+mod timing;
+mod trajectory;
+mod transition;
+#[cfg(feature = "json")]
+mod truncation;
+mod units;
+mod update;
+mod validation;
+mod velocity;
 mod visualization;
+#[cfg(feature = "wasm")]
+mod wasm;
 
 #[cfg(any(feature = "v2_0", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "v2_0")))]
 pub mod v2_0 {
 
+    pub mod angle {
+        pub use crate::angle::Radians as Radians;
+        pub use crate::angle::diff as diff;
+        pub use crate::angle::normalize as normalize;
+        pub use crate::angle::to_degrees as to_degrees;
+        pub use crate::angle::to_radians as to_radians;
+    }
+
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub mod bounded {
+        pub use crate::bounded::Limits as Limits;
+    }
+
     pub mod common {
         pub use crate::action::Action as Action;
         pub use crate::action::ActionParameter as ActionParameter;
         pub use crate::action::BlockingType as BlockingType;
+        pub use crate::bounds::BoundingBox as BoundingBox;
+        pub use crate::standard_action::ActionIdGenerator as ActionIdGenerator;
+        pub use crate::standard_action::ExpectedStateChange as ExpectedStateChange;
+        pub use crate::standard_action::FactsheetRequest as FactsheetRequest;
+        pub use crate::standard_action::InitPositionParameters as InitPositionParameters;
+        pub use crate::standard_action::LoadHandlingParameters as LoadHandlingParameters;
+        #[cfg(feature = "builders")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "builders")))]
+        pub use crate::builders::LoadHandlingParametersBuilder as LoadHandlingParametersBuilder;
+        pub use crate::standard_action::LogReport as LogReport;
+        pub use crate::standard_action::StandardAction as StandardAction;
+        pub use crate::standard_action::StandardActionError as StandardActionError;
+        pub use crate::standard_action::StateRequest as StateRequest;
+
+        pub use crate::checked::Angle as Angle;
+        pub use crate::checked::Magnitude as Magnitude;
+        pub use crate::checked::NonNegative as NonNegative;
+        pub use crate::checked::NormalizedAngle as NormalizedAngle;
+        pub use crate::checked::OutOfRange as OutOfRange;
+        pub use crate::checked::Percent as Percent;
+        pub use crate::checked::Percentage as Percentage;
+
+        pub use crate::units::Acceleration as Acceleration;
+        pub use crate::units::Kilograms as Kilograms;
+        pub use crate::units::Length as Length;
+        pub use crate::units::Mass as Mass;
+        pub use crate::units::Meters as Meters;
+        pub use crate::units::MetersPerSecond as MetersPerSecond;
+        pub use crate::units::MetersPerSecondSquared as MetersPerSecondSquared;
+        pub use crate::units::Speed as Speed;
 
         pub use crate::common::AgvPosition as AgvPosition;
+        #[cfg(feature = "builders")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "builders")))]
+        pub use crate::builders::AgvPositionBuilder as AgvPositionBuilder;
         pub use crate::common::BoundingBoxReference as BoundingBoxReference;
         pub use crate::common::ControlPoint as ControlPoint;
         pub use crate::common::HeaderId as HeaderId;
+        #[cfg(feature = "builders")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "builders")))]
+        pub use crate::builders::MissingField as MissingField;
+        pub use crate::header::AgvIdentity as AgvIdentity;
+        pub use crate::header::Header as Header;
+        pub use crate::header::Clock as Clock;
+        pub use crate::header::HeaderEvent as HeaderEvent;
+        pub use crate::header::HeaderGenerator as HeaderGenerator;
+        pub use crate::header::HeaderMonitor as HeaderMonitor;
+        pub use crate::header::HeaderStatistics as HeaderStatistics;
+        pub use crate::id::ActionId as ActionId;
+        pub use crate::id::EdgeId as EdgeId;
+        pub use crate::id::IdFactory as IdFactory;
+        pub use crate::id::MapId as MapId;
+        pub use crate::id::NodeId as NodeId;
+        pub use crate::id::OrderId as OrderId;
+        #[cfg(feature = "uuid")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+        pub use crate::id::RandomIds as RandomIds;
+        pub use crate::id::ZoneSetId as ZoneSetId;
         pub use crate::common::LoadDimensions as LoadDimensions;
         pub use crate::common::NodePosition as NodePosition;
+        #[cfg(feature = "builders")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "builders")))]
+        pub use crate::builders::NodePositionBuilder as NodePositionBuilder;
+        pub use crate::pose::Pose as Pose;
+        pub use crate::pose::distance as distance;
+        pub use crate::pose::heading_difference as heading_difference;
         pub use crate::common::Timestamp as Timestamp;
+        pub use crate::timestamp::EpochMillis as EpochMillis;
+        pub use crate::timestamp::InvalidTimestamp as InvalidTimestamp;
+        pub use crate::timestamp::TimestampPrecision as TimestampPrecision;
+        pub use crate::timestamp::from_unix_millis as timestamp_from_unix_millis;
+        pub use crate::timestamp::parse as parse_timestamp;
+        pub use crate::timestamp::to_unix_millis as timestamp_to_unix_millis;
+        #[cfg(feature = "serde")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+        pub use crate::timestamp::precision as timestamp_precision;
         pub use crate::common::Trajectory as Trajectory;
+        pub use crate::header::Topic as Topic;
+        pub use crate::deviation::VehicleTolerance as VehicleTolerance;
         pub use crate::common::Velocity as Velocity;
+
+        pub use crate::finite::Double as Double;
+        pub use crate::finite::Finite as Finite;
+        pub use crate::finite::Float as Float;
+        pub use crate::finite::NotFinite as NotFinite;
     }
 
     pub mod connection {
         pub use crate::connection::Connection as Connection;
         pub use crate::connection::ConnectionState as ConnectionState;
+        pub use crate::transition::ConnectionTracker as ConnectionTracker;
     }
 
     pub mod factsheet {
@@ -63,60 +247,246 @@ pub mod v2_0 {
         pub use crate::factsheet::AgvClass as AgvClass;
         pub use crate::factsheet::AgvGeometry as AgvGeometry;
         pub use crate::factsheet::AgvKinematic as AgvKinematic;
+        pub use crate::completeness::Completeness as Completeness;
         pub use crate::factsheet::Data as Data;
         pub use crate::factsheet::Envelopes2d as Envelopes2d;
         pub use crate::factsheet::Envelopes3d as Envelopes3d;
         pub use crate::factsheet::Factsheet as Factsheet;
+        pub use crate::builder::FactsheetBuilder as FactsheetBuilder;
         pub use crate::factsheet::LoadSet as LoadSet;
+        #[cfg(feature = "builders")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "builders")))]
+        pub use crate::builders::LoadSetBuilder as LoadSetBuilder;
         pub use crate::factsheet::LoadSpecification as LoadSpecification;
         pub use crate::factsheet::LocalizationType as LocalizationType;
         pub use crate::factsheet::MaxArrayLens as MaxArrayLens;
+        #[cfg(feature = "builders")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "builders")))]
+        pub use crate::builders::MaxArrayLensBuilder as MaxArrayLensBuilder;
         pub use crate::factsheet::MaxStringLens as MaxStringLens;
+        #[cfg(feature = "builders")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "builders")))]
+        pub use crate::builders::MaxStringLensBuilder as MaxStringLensBuilder;
         pub use crate::factsheet::NavigationType as NavigationType;
         pub use crate::factsheet::OptionalParameter as OptionalParameter;
+        pub use crate::optional_parameter::OptionalParamPath as OptionalParamPath;
         pub use crate::factsheet::PhysicalParameters as PhysicalParameters;
+        #[cfg(feature = "builders")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "builders")))]
+        pub use crate::builders::PhysicalParametersBuilder as PhysicalParametersBuilder;
         pub use crate::factsheet::PolygonPoint as PolygonPoint;
         pub use crate::factsheet::Position as Position;
         pub use crate::factsheet::ProtocolFeatures as ProtocolFeatures;
         pub use crate::factsheet::ProtocolLimits as ProtocolLimits;
         pub use crate::factsheet::Support as Support;
         pub use crate::factsheet::Timing as Timing;
+        pub use crate::builder::TimingBuilder as TimingBuilder;
         pub use crate::factsheet::TypeSpecification as TypeSpecification;
+        #[cfg(feature = "builders")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "builders")))]
+        pub use crate::builders::TypeSpecificationBuilder as TypeSpecificationBuilder;
         pub use crate::factsheet::ValueDataType as ValueDataType;
         pub use crate::factsheet::WheelDefinition as WheelDefinition;
         pub use crate::factsheet::WheelType as WheelType;
     }
 
+    pub mod frames {
+        pub use crate::frames::point_to_map as point_to_map;
+        pub use crate::frames::point_to_vehicle as point_to_vehicle;
+        pub use crate::frames::pose_to_map as pose_to_map;
+        pub use crate::frames::pose_to_vehicle as pose_to_vehicle;
+    }
+
+    #[cfg(feature = "geojson")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "geojson")))]
+    pub mod geojson {
+        pub use crate::geojson::DEFAULT_SAMPLES as DEFAULT_SAMPLES;
+        pub use crate::geojson::order_to_feature_collection as order_to_feature_collection;
+        pub use crate::geojson::trajectory_to_feature as trajectory_to_feature;
+    }
+
     pub mod instant_actions {
+        pub use crate::transition::ActionIdTracker as ActionIdTracker;
+        pub use crate::transition::ActionIssuer as ActionIssuer;
         pub use crate::instant_actions::InstantActions as InstantActions;
     }
 
+    #[cfg(feature = "ffi")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+    pub mod ffi {
+        pub use crate::ffi::Vda5050Order as Vda5050Order;
+        pub use crate::ffi::Vda5050OrderView as Vda5050OrderView;
+        pub use crate::ffi::Vda5050State as Vda5050State;
+        pub use crate::ffi::Vda5050StateView as Vda5050StateView;
+        pub use crate::ffi::vda5050_order_free as vda5050_order_free;
+        pub use crate::ffi::vda5050_order_from_json as vda5050_order_from_json;
+        pub use crate::ffi::vda5050_order_to_json as vda5050_order_to_json;
+        pub use crate::ffi::vda5050_order_view as vda5050_order_view;
+        pub use crate::ffi::vda5050_state_free as vda5050_state_free;
+        pub use crate::ffi::vda5050_state_from_json as vda5050_state_from_json;
+        pub use crate::ffi::vda5050_state_to_json as vda5050_state_to_json;
+        pub use crate::ffi::vda5050_state_view as vda5050_state_view;
+        pub use crate::ffi::vda5050_string_free as vda5050_string_free;
+    }
+
+    #[cfg(feature = "fixtures")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fixtures")))]
+    pub mod fixtures {
+        pub use crate::fixtures::from_toml_str as from_toml_str;
+        pub use crate::fixtures::from_yaml_str as from_yaml_str;
+    }
+
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    pub mod json {
+        pub use crate::json::ToCanonicalJson as ToCanonicalJson;
+    }
+
     pub mod order {
+
+        pub mod acceptance {
+            pub use crate::acceptance::Decision as Decision;
+            pub use crate::acceptance::check as check;
+        }
+
         pub use crate::order::Edge as Edge;
+        pub use crate::builder::EdgeBuilder as EdgeBuilder;
         pub use crate::order::Node as Node;
+        pub use crate::builder::NodeBuilder as NodeBuilder;
         pub use crate::order::Order as Order;
+        pub use crate::builder::OrderBuilder as OrderBuilder;
+        pub use crate::route::OrderIndex as OrderIndex;
+        pub use crate::progression::OrderStates as OrderStates;
+        pub use crate::builder::OrderPath as OrderPath;
         pub use crate::order::OrientationType as OrientationType;
+        pub use crate::builder::PathAtEdge as PathAtEdge;
+        pub use crate::builder::PathAtNode as PathAtNode;
+        pub use crate::builder::PathStart as PathStart;
+        pub use crate::route::Route as Route;
+        pub use crate::route::RouteElement as RouteElement;
+        pub use crate::route::RouteError as RouteError;
+        pub use crate::update::UpdateError as UpdateError;
+    }
+
+    #[cfg(feature = "python")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "python")))]
+    pub mod python {
+        pub use crate::python::PyInstantActions as PyInstantActions;
+        pub use crate::python::PyOrder as PyOrder;
+        pub use crate::python::PyState as PyState;
+        pub use crate::python::register as register;
+    }
+
+    #[cfg(feature = "ros2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ros2")))]
+    pub mod ros2 {
+        pub use crate::ros2::*;
+    }
+
+    #[cfg(feature = "jsonschema")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+    pub mod schema {
+        pub use crate::schema::SchemaValidator as SchemaValidator;
+        pub use crate::schema::connection as connection;
+        pub use crate::schema::factsheet as factsheet;
+        pub use crate::schema::instant_actions as instant_actions;
+        pub use crate::schema::order as order;
+        pub use crate::schema::state as state;
+        pub use crate::schema::visualization as visualization;
     }
 
     pub mod state {
         pub use crate::state::ActionState as ActionState;
+        pub use crate::state::ActionStatus as ActionStatus;
+        pub use crate::transition::ActionStatusTracker as ActionStatusTracker;
         pub use crate::state::BatteryState as BatteryState;
+        pub use crate::battery::ChargingPolicy as ChargingPolicy;
+        pub use crate::battery::ConsumptionModel as ConsumptionModel;
         pub use crate::state::EdgeState as EdgeState;
         pub use crate::state::Error as Error;
         pub use crate::state::ErrorReference as ErrorReference;
+        pub use crate::error::ErrorBuilder as ErrorBuilder;
         pub use crate::state::ErrorLevel as ErrorLevel;
         pub use crate::state::EStop as EStop;
         pub use crate::state::Information as Information;
+        #[cfg(feature = "json")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+        pub use crate::state::LazyState as LazyState;
         pub use crate::state::InfoReference as InfoReference;
         pub use crate::state::InfoLevel as InfoLevel;
         pub use crate::state::Load as Load;
         pub use crate::state::NodeState as NodeState;
         pub use crate::state::OperatingMode as OperatingMode;
         pub use crate::state::SafetyState as SafetyState;
+        pub use crate::error::StandardErrorType as StandardErrorType;
         pub use crate::state::State as State;
+        pub use crate::builder::StateBuilder as StateBuilder;
+        pub use crate::delta::StateDelta as StateDelta;
+    }
+
+    #[cfg(feature = "proptest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+    pub mod strategy {
+        pub use crate::strategy::*;
+    }
+
+    pub mod validation {
+        pub use crate::report::Finding as Finding;
+        pub use crate::validation::IdPolicy as IdPolicy;
+        pub use crate::validation::MAX_TIMESTAMP_AHEAD_MILLIS as MAX_TIMESTAMP_AHEAD_MILLIS;
+        #[cfg(feature = "json")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+        pub use crate::truncation::Overflow as Overflow;
+        pub use crate::validation::RULE_ACTION_SCOPE as RULE_ACTION_SCOPE;
+        pub use crate::validation::RULE_DISCONNECTED as RULE_DISCONNECTED;
+        pub use crate::validation::RULE_DUPLICATE_ID as RULE_DUPLICATE_ID;
+        pub use crate::validation::RULE_EMPTY_ID as RULE_EMPTY_ID;
+        pub use crate::validation::RULE_FUTURE_TIMESTAMP as RULE_FUTURE_TIMESTAMP;
+        pub use crate::validation::RULE_INCONSISTENT as RULE_INCONSISTENT;
+        pub use crate::validation::RULE_ILLEGAL_TRANSITION as RULE_ILLEGAL_TRANSITION;
+        pub use crate::validation::RULE_INVALID_CHARACTER as RULE_INVALID_CHARACTER;
+        pub use crate::validation::RULE_INVALID_POLYGON as RULE_INVALID_POLYGON;
+        pub use crate::validation::RULE_INVALID_VERSION as RULE_INVALID_VERSION;
+        pub use crate::validation::RULE_MALFORMED_TRAJECTORY as RULE_MALFORMED_TRAJECTORY;
+        pub use crate::validation::RULE_MISSING_FIELD as RULE_MISSING_FIELD;
+        pub use crate::validation::RULE_MISSING_PARAMETER as RULE_MISSING_PARAMETER;
+        pub use crate::validation::RULE_NO_BASE_NODE as RULE_NO_BASE_NODE;
+        pub use crate::validation::RULE_NO_DRIVEN_WHEEL as RULE_NO_DRIVEN_WHEEL;
+        pub use crate::validation::RULE_NOT_NUMERICAL as RULE_NOT_NUMERICAL;
+        pub use crate::validation::RULE_OUT_OF_RANGE as RULE_OUT_OF_RANGE;
+        pub use crate::validation::RULE_PARAMETER_TYPE as RULE_PARAMETER_TYPE;
+        pub use crate::validation::RULE_PHYSICAL_LIMIT as RULE_PHYSICAL_LIMIT;
+        pub use crate::validation::RULE_RELEASED_AFTER_HORIZON as RULE_RELEASED_AFTER_HORIZON;
+        pub use crate::validation::RULE_SCHEMA as RULE_SCHEMA;
+        pub use crate::validation::RULE_SEQUENCE_GAP as RULE_SEQUENCE_GAP;
+        pub use crate::validation::RULE_SEQUENCE_ORDER as RULE_SEQUENCE_ORDER;
+        pub use crate::validation::RULE_SEQUENCE_PARITY as RULE_SEQUENCE_PARITY;
+        pub use crate::validation::RULE_TOO_LONG as RULE_TOO_LONG;
+        pub use crate::validation::RULE_TOO_MANY_ELEMENTS as RULE_TOO_MANY_ELEMENTS;
+        pub use crate::validation::RULE_TOPIC_UNSAFE as RULE_TOPIC_UNSAFE;
+        pub use crate::validation::RULE_UNKNOWN_NODE as RULE_UNKNOWN_NODE;
+        pub use crate::validation::RULE_UNSUPPORTED_ACTION as RULE_UNSUPPORTED_ACTION;
+        pub use crate::report::Severity as Severity;
+        pub use crate::report::SeverityPolicy as SeverityPolicy;
+        pub use crate::validation::Validate as Validate;
+        pub use crate::report::ValidationReport as ValidationReport;
+        pub use crate::validation::Violation as Violation;
+        pub use crate::validation::validate_header as validate_header;
     }
 
     pub mod visualization {
         pub use crate::visualization::Visualization;
+        #[cfg(feature = "builders")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "builders")))]
+        pub use crate::builders::VisualizationBuilder as VisualizationBuilder;
+    }
+
+    #[cfg(feature = "wasm")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wasm")))]
+    pub mod wasm {
+        pub use crate::wasm::decode_state as decode_state;
+        pub use crate::wasm::decode_visualization as decode_visualization;
+        pub use crate::wasm::encode_state as encode_state;
+        pub use crate::wasm::encode_visualization as encode_visualization;
     }
 }
@@ -0,0 +1,130 @@
+//!
+//! Footprints of loads derived from their bounding boxes, and overlaps between loads.
+//!
+use crate::checked::Raw;
+use crate::common::AgvPosition;
+use crate::frames::{point_to_map, transform};
+use crate::state::Load;
+
+impl Load {
+
+    /// Returns the corners of the footprint of the load in vehicle coordinates in counterclockwise order, or `None` if
+    /// the load does not define its bounding box reference or dimensions.
+    ///
+    /// The footprint is the rectangle of the length and width of the load centered at the bounding box reference and
+    /// rotated by its `theta`, where the length extends along the x-axis if `theta` is zero or absent.
+    pub fn footprint(&self) -> Option<[(f32, f32); 4]> {
+        let reference = self.bounding_box_reference.as_ref()?;
+        let dimensions = self.load_dimensions.as_ref()?;
+        let (half_length, half_width) = (dimensions.length.raw() / 2.0, dimensions.width.raw() / 2.0);
+        let theta = reference.theta.map_or(0.0, Raw::raw);
+        let corner = |x: f32, y: f32| transform((reference.x.raw(), reference.y.raw(), theta), (x, y));
+        Some([
+            corner(-half_length, -half_width),
+            corner(half_length, -half_width),
+            corner(half_length, half_width),
+            corner(-half_length, half_width),
+        ])
+    }
+
+    /// Returns the corners of the footprint of the load in map coordinates if it is carried by an AGV at `position`,
+    /// see [`Load::footprint`].
+    pub fn footprint_on_map(&self, position: &AgvPosition) -> Option<[(f32, f32); 4]> {
+        self.footprint().map(|corners| corners.map(|corner| point_to_map(position, corner)))
+    }
+
+    /// Returns `true` if the footprints of the load and `other` carried by the same AGV overlap, i.e. share more than
+    /// their boundaries, or `false` if either footprint is unknown.
+    pub fn overlaps(&self, other: &Load) -> bool {
+        match (self.footprint(), other.footprint()) {
+            (Some(footprint), Some(other)) => !separated(&footprint, &other) && !separated(&other, &footprint),
+            _ => false,
+        }
+    }
+}
+
+/// Returns `true` if an edge of the convex `polygon` separates it from the convex `other`.
+fn separated(polygon: &[(f32, f32)], other: &[(f32, f32)]) -> bool {
+    let project = |points: &[(f32, f32)], (nx, ny): (f32, f32)| points.iter()
+        .map(|(x, y)| x * nx + y * ny)
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), projection| (min.min(projection), max.max(projection)));
+    (0..polygon.len()).any(|index| {
+        let ((ax, ay), (bx, by)) = (polygon[index], polygon[(index + 1) % polygon.len()]);
+        let normal = (ay - by, bx - ax);
+        let ((min, max), (other_min, other_max)) = (project(polygon, normal), project(other, normal));
+        max <= other_min || other_max <= min
+    })
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::f32::consts::FRAC_PI_2;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::common::{AgvPosition, BoundingBoxReference, LoadDimensions};
+    use crate::state::Load;
+
+    fn load(x: f32, y: f32, theta: Option<f32>, length: f32, width: f32) -> Load {
+        Load {
+            load_id: None,
+            load_type: None,
+            load_position: None,
+            bounding_box_reference: Some(BoundingBoxReference { x: checked(x), y: checked(y), z: checked(0.0), theta: theta.map(checked) }),
+            load_dimensions: Some(LoadDimensions { length: checked(length), width: checked(width), height: None }),
+            weight: None,
+        }
+    }
+
+    fn rounded(corners: [(f32, f32); 4]) -> [(f32, f32); 4] {
+        corners.map(|(x, y)| (libm::roundf(x * 1000.0) / 1000.0, libm::roundf(y * 1000.0) / 1000.0))
+    }
+
+    #[rstest]
+    #[case::axis_aligned(load(1.0, 0.0, None, 2.0, 1.0), [(0.0, -0.5), (2.0, -0.5), (2.0, 0.5), (0.0, 0.5)])]
+    #[case::rotated(load(1.0, 0.0, Some(FRAC_PI_2), 2.0, 1.0), [(1.5, -1.0), (1.5, 1.0), (0.5, 1.0), (0.5, -1.0)])]
+    fn test_Load_footprint(#[case] load: Load, #[case] expected: [(f32, f32); 4]) {
+        assert_that!(load.footprint().map(rounded), some(eq(expected)));
+    }
+
+    #[rstest]
+    fn test_Load_footprint_without_dimensions_is_unknown() {
+        let load = Load { load_dimensions: None, ..load(0.0, 0.0, None, 1.0, 1.0) };
+
+        assert_that!(load.footprint(), none());
+        assert_that!(load.overlaps(&load.clone()), eq(false));
+    }
+
+    #[rstest]
+    fn test_Load_footprint_on_map() {
+        let position = AgvPosition {
+            x: checked(10.0),
+            y: checked(5.0),
+            theta: checked(FRAC_PI_2),
+            map_id: "map".into(),
+            map_description: None,
+            position_initialized: true,
+            localization_score: None,
+            deviation_range: None,
+        };
+
+        let footprint = load(1.0, 0.0, None, 2.0, 1.0).footprint_on_map(&position);
+
+        assert_that!(footprint.map(rounded), some(eq([(10.5, 5.0), (10.5, 7.0), (9.5, 7.0), (9.5, 5.0)])));
+    }
+
+    #[rstest]
+    #[case::overlapping(load(0.5, 0.5, None, 1.0, 1.0), true)]
+    #[case::touching(load(1.0, 0.0, None, 1.0, 1.0), false)]
+    #[case::apart(load(2.0, 2.0, None, 1.0, 1.0), false)]
+    #[case::rotated_into(load(1.1, 0.0, Some(FRAC_PI_2 / 2.0), 1.0, 1.0), true)]
+    #[case::rotated_apart(load(1.3, 0.0, Some(FRAC_PI_2 / 2.0), 1.0, 1.0), false)]
+    fn test_Load_overlaps(#[case] other: Load, #[case] expected: bool) {
+        let load = load(0.0, 0.0, None, 1.0, 1.0);
+
+        assert_that!(load.overlaps(&other), eq(expected));
+        assert_that!(other.overlaps(&load), eq(expected));
+    }
+}
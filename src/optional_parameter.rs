@@ -0,0 +1,205 @@
+//!
+//! Typed paths of the optional parameters listed in the `protocolFeatures` of a factsheet.
+//!
+use alloc::string::String;
+
+use crate::action::Action;
+use crate::factsheet::OptionalParameter;
+use crate::order::{Edge, Node, Order};
+
+macro_rules! optional_param_paths {
+    ($($(#[$doc:meta])* $variant:ident => $path:literal, |$order:ident| $is_set:expr;)*) => {
+
+        /// Path of an optional field of an order, as used in the `parameter` of an [`OptionalParameter`], e.g.
+        /// `order.nodes.nodePosition.allowedDeviationTheta`.
+        #[derive(Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+        pub enum OptionalParamPath {
+            $(
+                $(#[$doc])*
+                $variant,
+            )*
+            /// A path not known to be an optional field of an order, e.g. of another message or a misspelled one.
+            Unknown(String),
+        }
+
+        impl OptionalParamPath {
+
+            /// Returns the path as used in factsheets, e.g. `order.edges.maxSpeed`.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(OptionalParamPath::$variant => $path,)*
+                    OptionalParamPath::Unknown(path) => path,
+                }
+            }
+
+            /// Returns `true` if the field is set by at least one node, edge or action of `order`. Returns `false` for
+            /// unknown paths.
+            pub fn is_set_in(&self, order: &Order) -> bool {
+                match self {
+                    $(OptionalParamPath::$variant => { let $order = order; $is_set })*
+                    OptionalParamPath::Unknown(_) => false,
+                }
+            }
+        }
+
+        impl From<&str> for OptionalParamPath {
+
+            fn from(path: &str) -> Self {
+                match path {
+                    $($path => OptionalParamPath::$variant,)*
+                    other => OptionalParamPath::Unknown(other.into()),
+                }
+            }
+        }
+    };
+}
+
+optional_param_paths! {
+    /// `order.zoneSetId`
+    ZoneSetId => "order.zoneSetId", |order| order.zone_set_id.is_some();
+    /// `order.nodes.nodeDescription`
+    NodeDescription => "order.nodes.nodeDescription", |order| any_node(order, |node| node.node_description.is_some());
+    /// `order.nodes.nodePosition`
+    NodePosition => "order.nodes.nodePosition", |order| any_node(order, |node| node.node_position.is_some());
+    /// `order.nodes.nodePosition.theta`
+    NodePositionTheta => "order.nodes.nodePosition.theta", |order| any_node(order, |node| node.node_position.as_ref().is_some_and(|position| position.theta.is_some()));
+    /// `order.nodes.nodePosition.allowedDeviationXY`
+    NodePositionAllowedDeviationXy => "order.nodes.nodePosition.allowedDeviationXY", |order| any_node(order, |node| node.node_position.as_ref().is_some_and(|position| position.allowed_deviation_xy.is_some()));
+    /// `order.nodes.nodePosition.allowedDeviationTheta`
+    NodePositionAllowedDeviationTheta => "order.nodes.nodePosition.allowedDeviationTheta", |order| any_node(order, |node| node.node_position.as_ref().is_some_and(|position| position.allowed_deviation_theta.is_some()));
+    /// `order.nodes.nodePosition.mapDescription`
+    NodePositionMapDescription => "order.nodes.nodePosition.mapDescription", |order| any_node(order, |node| node.node_position.as_ref().is_some_and(|position| position.map_description.is_some()));
+    /// `order.nodes.actions.actionDescription`
+    NodeActionDescription => "order.nodes.actions.actionDescription", |order| any_node_action(order, |action| action.action_description.is_some());
+    /// `order.nodes.actions.actionParameters`
+    NodeActionParameters => "order.nodes.actions.actionParameters", |order| any_node_action(order, |action| !action.action_parameters.is_empty());
+    /// `order.edges.edgeDescription`
+    EdgeDescription => "order.edges.edgeDescription", |order| any_edge(order, |edge| edge.edge_description.is_some());
+    /// `order.edges.maxSpeed`
+    EdgeMaxSpeed => "order.edges.maxSpeed", |order| any_edge(order, |edge| edge.max_speed.is_some());
+    /// `order.edges.maxHeight`
+    EdgeMaxHeight => "order.edges.maxHeight", |order| any_edge(order, |edge| edge.max_height.is_some());
+    /// `order.edges.minHeight`
+    EdgeMinHeight => "order.edges.minHeight", |order| any_edge(order, |edge| edge.min_height.is_some());
+    /// `order.edges.orientation`
+    EdgeOrientation => "order.edges.orientation", |order| any_edge(order, |edge| edge.orientation.is_some());
+    /// `order.edges.orientationType`
+    EdgeOrientationType => "order.edges.orientationType", |order| any_edge(order, |edge| edge.orientation_type.is_some());
+    /// `order.edges.direction`
+    EdgeDirection => "order.edges.direction", |order| any_edge(order, |edge| edge.direction.is_some());
+    /// `order.edges.rotationAllowed`
+    EdgeRotationAllowed => "order.edges.rotationAllowed", |order| any_edge(order, |edge| edge.rotation_allowed.is_some());
+    /// `order.edges.maxRotationSpeed`
+    EdgeMaxRotationSpeed => "order.edges.maxRotationSpeed", |order| any_edge(order, |edge| edge.max_rotation_speed.is_some());
+    /// `order.edges.length`
+    EdgeLength => "order.edges.length", |order| any_edge(order, |edge| edge.length.is_some());
+    /// `order.edges.trajectory`
+    EdgeTrajectory => "order.edges.trajectory", |order| any_edge(order, |edge| edge.trajectory.is_some());
+    /// `order.edges.trajectory.controlPoints.weight`
+    EdgeTrajectoryControlPointWeight => "order.edges.trajectory.controlPoints.weight", |order| any_edge(order, |edge| edge.trajectory.as_ref().is_some_and(|trajectory| trajectory.control_points.iter().any(|point| point.weight.is_some())));
+    /// `order.edges.trajectory.controlPoints.orientation`
+    EdgeTrajectoryControlPointOrientation => "order.edges.trajectory.controlPoints.orientation", |order| any_edge(order, |edge| edge.trajectory.as_ref().is_some_and(|trajectory| trajectory.control_points.iter().any(|point| point.orientation.is_some())));
+    /// `order.edges.actions.actionDescription`
+    EdgeActionDescription => "order.edges.actions.actionDescription", |order| any_edge_action(order, |action| action.action_description.is_some());
+    /// `order.edges.actions.actionParameters`
+    EdgeActionParameters => "order.edges.actions.actionParameters", |order| any_edge_action(order, |action| !action.action_parameters.is_empty());
+}
+
+fn any_node(order: &Order, predicate: impl Fn(&Node) -> bool) -> bool {
+    order.nodes.iter().any(predicate)
+}
+
+fn any_edge(order: &Order, predicate: impl Fn(&Edge) -> bool) -> bool {
+    order.edges.iter().any(predicate)
+}
+
+fn any_node_action(order: &Order, predicate: impl Fn(&Action) -> bool) -> bool {
+    order.nodes.iter().flat_map(|node| node.actions.iter()).any(predicate)
+}
+
+fn any_edge_action(order: &Order, predicate: impl Fn(&Action) -> bool) -> bool {
+    order.edges.iter().flat_map(|edge| edge.actions.iter()).any(predicate)
+}
+
+impl core::fmt::Display for OptionalParamPath {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl OptionalParameter {
+
+    /// Returns the parsed `parameter` of the optional parameter.
+    pub fn path(&self) -> OptionalParamPath {
+        OptionalParamPath::from(self.parameter.as_str())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::factsheet::{OptionalParameter, Support};
+    use crate::order::{Edge, Order};
+    use crate::timestamp::from_unix_millis;
+    use super::OptionalParamPath;
+
+    fn order(max_speed: Option<f32>) -> Order {
+        Order {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            order_id: "o1".into(),
+            order_update_id: 0,
+            zone_set_id: None,
+            nodes: vec![],
+            edges: vec![Edge {
+                edge_id: "e1".into(),
+                sequence_id: 1,
+                edge_description: None,
+                released: true,
+                start_node_id: "n1".into(),
+                end_node_id: "n2".into(),
+                max_speed: max_speed.map(checked),
+                max_height: None,
+                min_height: None,
+                orientation: None,
+                orientation_type: None,
+                direction: None,
+                rotation_allowed: None,
+                max_rotation_speed: None,
+                length: None,
+                trajectory: None,
+                actions: vec![],
+            }],
+        }
+    }
+
+    #[rstest]
+    #[case("order.nodes.nodePosition.allowedDeviationTheta", OptionalParamPath::NodePositionAllowedDeviationTheta)]
+    #[case("order.edges.maxSpeed", OptionalParamPath::EdgeMaxSpeed)]
+    #[case("state.agvPosition", OptionalParamPath::Unknown("state.agvPosition".into()))]
+    fn test_OptionalParameter_path(#[case] parameter: &str, #[case] expected: OptionalParamPath) {
+        let optional_parameter = OptionalParameter { parameter: parameter.into(), support: Support::Required, description: None };
+
+        assert_that!(optional_parameter.path(), eq(&expected));
+        assert_that!(expected.to_string(), eq(parameter));
+    }
+
+    #[rstest]
+    #[case(OptionalParamPath::EdgeMaxSpeed, Some(1.0), true)]
+    #[case(OptionalParamPath::EdgeMaxSpeed, None, false)]
+    #[case(OptionalParamPath::ZoneSetId, Some(1.0), false)]
+    #[case(OptionalParamPath::Unknown("order.edges.foo".into()), Some(1.0), false)]
+    fn test_OptionalParamPath_is_set_in(#[case] path: OptionalParamPath, #[case] max_speed: Option<f32>, #[case] expected: bool) {
+        assert_that!(path.is_set_in(&order(max_speed)), eq(expected));
+    }
+}
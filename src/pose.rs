@@ -0,0 +1,156 @@
+//!
+//! The [`Pose`] trait over the types carrying coordinates and an orientation, and geometry on poses.
+//!
+use crate::angle::diff;
+use crate::checked::Raw;
+use crate::common::{AgvPosition, ControlPoint, NodePosition};
+use crate::factsheet::Position;
+
+/// Position in a plane with an optional orientation, regardless of the coordinate system.
+pub trait Pose {
+
+    /// Returns the x-coordinate.
+    fn x(&self) -> f32;
+
+    /// Returns the y-coordinate.
+    fn y(&self) -> f32;
+
+    /// Returns the orientation, or `None` if the orientation is not defined.
+    fn theta(&self) -> Option<f32>;
+}
+
+impl Pose for AgvPosition {
+
+    fn x(&self) -> f32 {
+        self.x.raw()
+    }
+
+    fn y(&self) -> f32 {
+        self.y.raw()
+    }
+
+    fn theta(&self) -> Option<f32> {
+        Some(self.theta.raw())
+    }
+}
+
+impl Pose for NodePosition {
+
+    fn x(&self) -> f32 {
+        self.x.raw()
+    }
+
+    fn y(&self) -> f32 {
+        self.y.raw()
+    }
+
+    fn theta(&self) -> Option<f32> {
+        self.theta.map(Raw::raw)
+    }
+}
+
+impl Pose for Position {
+
+    fn x(&self) -> f32 {
+        self.x.raw()
+    }
+
+    fn y(&self) -> f32 {
+        self.y.raw()
+    }
+
+    fn theta(&self) -> Option<f32> {
+        self.theta.map(Raw::raw)
+    }
+}
+
+impl Pose for ControlPoint {
+
+    fn x(&self) -> f32 {
+        self.x.raw()
+    }
+
+    fn y(&self) -> f32 {
+        self.y.raw()
+    }
+
+    /// Returns the orientation of the AGV at the control point.
+    fn theta(&self) -> Option<f32> {
+        self.orientation.map(Raw::raw)
+    }
+}
+
+/// Returns the euclidean distance between the coordinates of `from` and `to`.
+pub fn distance(from: &impl Pose, to: &impl Pose) -> f32 {
+    libm::hypotf(to.x() - from.x(), to.y() - from.y())
+}
+
+/// Returns the angle within `[-π, π]` to rotate by from the orientation of `from` to the one of `to`, or `None` if
+/// either orientation is not defined.
+pub fn heading_difference(from: &impl Pose, to: &impl Pose) -> Option<f32> {
+    Some(diff(to.theta()?, from.theta()?))
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::f32::consts::{FRAC_PI_2, PI};
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::common::{AgvPosition, ControlPoint, NodePosition};
+    use crate::factsheet::Position;
+    use super::{distance, heading_difference, Pose};
+
+    fn agv_position(x: f32, y: f32, theta: f32) -> AgvPosition {
+        AgvPosition {
+            x: checked(x),
+            y: checked(y),
+            theta: checked(theta),
+            map_id: "map".into(),
+            map_description: None,
+            position_initialized: true,
+            localization_score: None,
+            deviation_range: None,
+        }
+    }
+
+    fn node_position(x: f32, y: f32, theta: Option<f32>) -> NodePosition {
+        NodePosition {
+            x: checked(x),
+            y: checked(y),
+            theta: theta.map(checked),
+            allowed_deviation_xy: None,
+            allowed_deviation_theta: None,
+            map_id: "map".into(),
+            map_description: None,
+        }
+    }
+
+    #[rstest]
+    fn test_Pose_of_position_types() {
+        let position = Position { x: checked(1.0), y: checked(2.0), theta: None };
+        let control_point = ControlPoint { x: checked(1.0), y: checked(2.0), orientation: Some(checked(0.5)), ..Default::default() };
+
+        assert_that!((position.x(), position.y(), position.theta()), eq((1.0, 2.0, None)));
+        assert_that!((control_point.x(), control_point.y(), control_point.theta()), eq((1.0, 2.0, Some(0.5))));
+        assert_that!(agv_position(1.0, 2.0, 0.5).theta(), some(eq(0.5)));
+    }
+
+    #[rstest]
+    fn test_distance_between_pose_types() {
+        assert_that!(distance(&agv_position(1.0, 1.0, 0.0), &node_position(4.0, 5.0, None)), eq(5.0));
+    }
+
+    #[rstest]
+    #[case::same(0.5, Some(0.5), Some(0.0))]
+    #[case::left(0.0, Some(FRAC_PI_2), Some(FRAC_PI_2))]
+    #[case::across_wrap(3.0, Some(-3.0), Some(2.0 * PI - 6.0))]
+    #[case::undefined(0.0, None, None)]
+    fn test_heading_difference(#[case] from: f32, #[case] to: Option<f32>, #[case] expected: Option<f32>) {
+        let difference = heading_difference(&agv_position(0.0, 0.0, from), &node_position(0.0, 0.0, to));
+
+        assert_that!(difference, eq(expected));
+    }
+}
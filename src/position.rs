@@ -0,0 +1,105 @@
+//!
+//! Conversions between the position of an AGV and the positions of nodes.
+//!
+use crate::angle::normalize;
+use crate::checked::Raw;
+use crate::common::{AgvPosition, NodePosition};
+use crate::deviation::VehicleTolerance;
+
+impl NodePosition {
+
+    /// Returns the position of a node at the coordinates, orientation and map of `position`, e.g. for a node created
+    /// where the AGV is located.
+    ///
+    /// The allowed deviations are taken from `deviation`, where deviations of zero or less are omitted, since they
+    /// mean that the normal tolerance of the AGV applies.
+    pub fn from_agv_position(position: &AgvPosition, deviation: &VehicleTolerance) -> Self {
+        NodePosition {
+            x: position.x,
+            y: position.y,
+            theta: Some(position.theta),
+            allowed_deviation_xy: Some(deviation.xy).filter(|xy| *xy > 0.0).and_then(|xy| Raw::try_from_raw(xy).ok()),
+            allowed_deviation_theta: Some(deviation.theta).filter(|theta| *theta > 0.0).and_then(|theta| Raw::try_from_raw(theta).ok()),
+            map_id: position.map_id.clone(),
+            map_description: position.map_description.clone(),
+        }
+    }
+}
+
+impl AgvPosition {
+
+    /// Returns the initialized position of an AGV located exactly at `node`, e.g. for simulating its arrival or for
+    /// the action `initPosition`.
+    ///
+    /// The AGV is oriented as defined by the node, or by `theta` if the node leaves the orientation open, which is
+    /// normalized into `[-π, π]`. Neither a localization score nor a deviation range is reported.
+    pub fn at_node(node: &NodePosition, theta: f32) -> Self {
+        AgvPosition {
+            x: node.x,
+            y: node.y,
+            theta: node.theta.unwrap_or_else(|| Raw::try_from_raw(normalize(theta)).unwrap_or_default()),
+            map_id: node.map_id.clone(),
+            map_description: node.map_description.clone(),
+            position_initialized: true,
+            localization_score: None,
+            deviation_range: None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::f32::consts::{FRAC_PI_2, PI};
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::{checked, Raw};
+    use crate::common::{AgvPosition, NodePosition};
+    use crate::deviation::VehicleTolerance;
+
+    #[rstest]
+    #[case::deviations(VehicleTolerance { xy: 0.5, theta: 0.1 }, Some(0.5), Some(0.1))]
+    #[case::no_deviations(VehicleTolerance::default(), None, None)]
+    fn test_NodePosition_from_agv_position(#[case] deviation: VehicleTolerance, #[case] allowed_deviation_xy: Option<f32>, #[case] allowed_deviation_theta: Option<f32>) {
+        let position = AgvPosition {
+            x: checked(1.0),
+            y: checked(2.0),
+            theta: checked(FRAC_PI_2),
+            map_id: "map".into(),
+            map_description: Some("floor 1".into()),
+            position_initialized: true,
+            localization_score: Some(checked(0.9)),
+            deviation_range: None,
+        };
+
+        let node = NodePosition::from_agv_position(&position, &deviation);
+
+        assert_that!((node.x.raw(), node.y.raw(), node.theta.map(Raw::raw)), eq((1.0, 2.0, Some(FRAC_PI_2))));
+        assert_that!(node.allowed_deviation_xy.map(Raw::raw), eq(allowed_deviation_xy));
+        assert_that!(node.allowed_deviation_theta.map(Raw::raw), eq(allowed_deviation_theta));
+        assert_that!(node, matches_pattern!(NodePosition { map_id: eq("map"), map_description: some(eq("floor 1")) }));
+    }
+
+    #[rstest]
+    #[case::node_orientation(Some(FRAC_PI_2), 0.0, FRAC_PI_2)]
+    #[case::open_orientation(None, 0.5, 0.5)]
+    #[case::normalized_orientation(None, 3.0 * PI, PI)]
+    fn test_AgvPosition_at_node(#[case] node_theta: Option<f32>, #[case] theta: f32, #[case] expected: f32) {
+        let node = NodePosition {
+            x: checked(1.0),
+            y: checked(2.0),
+            theta: node_theta.map(checked),
+            allowed_deviation_xy: Some(checked(0.5)),
+            allowed_deviation_theta: None,
+            map_id: "map".into(),
+            map_description: None,
+        };
+
+        let position = AgvPosition::at_node(&node, theta);
+
+        assert_that!((position.x.raw(), position.y.raw()), eq((1.0, 2.0)));
+        assert_that!(position.theta.raw(), near(expected, 1e-6));
+        assert_that!(position, matches_pattern!(AgvPosition { map_id: eq("map"), position_initialized: eq(&true), deviation_range: none() }));
+    }
+}
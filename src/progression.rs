@@ -0,0 +1,225 @@
+//!
+//! Progression of the state of an AGV executing an order.
+//!
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::action::Action;
+use crate::order::{Edge, Node, Order};
+use crate::state::{ActionState, ActionStatus, EdgeState, NodeState, State};
+
+impl Order {
+
+    /// Returns the node, edge and action states of an AGV which has just accepted the order.
+    ///
+    /// The states contain all nodes and edges of the order, including the first node, in the sequence they are
+    /// traversed, followed by the actions of the nodes and edges in the same sequence, all of which are `WAITING`.
+    pub fn initial_states(&self) -> OrderStates {
+        let mut nodes = self.nodes.iter().collect::<Vec<_>>();
+        let mut edges = self.edges.iter().collect::<Vec<_>>();
+        nodes.sort_by_key(|node| node.sequence_id);
+        edges.sort_by_key(|edge| edge.sequence_id);
+
+        let mut actions = nodes.iter().map(|node| (node.sequence_id, &node.actions))
+            .chain(edges.iter().map(|edge| (edge.sequence_id, &edge.actions)))
+            .collect::<Vec<_>>();
+        actions.sort_by_key(|(sequence_id, _)| *sequence_id);
+
+        OrderStates {
+            node_states: nodes.into_iter().map(node_state).collect(),
+            edge_states: edges.into_iter().map(edge_state).collect(),
+            action_states: actions.into_iter().flat_map(|(_, actions)| actions).map(action_state).collect(),
+        }
+    }
+}
+
+impl State {
+
+    /// Marks the node with the sequence id `sequence_id` as traversed and returns `true`, or returns `false` if the
+    /// node state does not exist.
+    ///
+    /// The node becomes the last node, the distance since the last node is reset, and the node states and edge states
+    /// up to the node are removed.
+    pub fn mark_node_traversed(&mut self, sequence_id: u64) -> bool {
+        let Some(node) = self.node_states.iter().find(|node| node.sequence_id == sequence_id) else {
+            return false;
+        };
+        self.last_node_id = node.node_id.clone();
+        self.last_node_sequence_id = sequence_id;
+        self.distance_since_last_node = None;
+        self.node_states.retain(|node| node.sequence_id > sequence_id);
+        self.edge_states.retain(|edge| edge.sequence_id > sequence_id);
+        true
+    }
+
+    /// Sets the status of the action `action_id` to `FINISHED` with the optional result and returns `true`, or returns
+    /// `false` if the action state does not exist.
+    pub fn finish_action(&mut self, action_id: &str, result_description: Option<String>) -> bool {
+        self.complete_action(action_id, ActionStatus::Finished, result_description)
+    }
+
+    /// Sets the status of the action `action_id` to `FAILED` with the optional result and returns `true`, or returns
+    /// `false` if the action state does not exist.
+    pub fn fail_action(&mut self, action_id: &str, result_description: Option<String>) -> bool {
+        self.complete_action(action_id, ActionStatus::Failed, result_description)
+    }
+
+    fn complete_action(&mut self, action_id: &str, action_status: ActionStatus, result_description: Option<String>) -> bool {
+        let Some(action) = self.action_states.iter_mut().find(|action| action.action_id == action_id) else {
+            return false;
+        };
+        action.action_status = action_status;
+        action.result_description = result_description;
+        true
+    }
+
+    /// Pauses or resumes the AGV.
+    ///
+    /// Pausing stops driving and pauses the running actions, resuming continues the paused actions.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = Some(paused);
+        if paused {
+            self.driving = false;
+        }
+        let (from, to) = if paused { (ActionStatus::Running, ActionStatus::Paused) } else { (ActionStatus::Paused, ActionStatus::Running) };
+        self.action_states.iter_mut()
+            .filter(|action| action.action_status == from)
+            .for_each(|action| action.action_status = to);
+    }
+}
+
+/// Node, edge and action states of an AGV executing an order, see [`Order::initial_states`].
+#[derive(Clone, Default)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct OrderStates {
+    /// States of the nodes the AGV still has to traverse.
+    pub node_states: Vec<NodeState>,
+    /// States of the edges the AGV still has to traverse.
+    pub edge_states: Vec<EdgeState>,
+    /// States of the actions of the nodes and edges.
+    pub action_states: Vec<ActionState>,
+}
+
+fn node_state(node: &Node) -> NodeState {
+    NodeState {
+        node_id: node.node_id.clone(),
+        sequence_id: node.sequence_id,
+        node_description: node.node_description.clone(),
+        node_position: node.node_position.clone(),
+        released: node.released,
+    }
+}
+
+fn edge_state(edge: &Edge) -> EdgeState {
+    EdgeState {
+        edge_id: edge.edge_id.clone(),
+        sequence_id: edge.sequence_id,
+        edge_description: edge.edge_description.clone(),
+        released: edge.released,
+        trajectory: edge.trajectory.clone(),
+    }
+}
+
+fn action_state(action: &Action) -> ActionState {
+    ActionState {
+        action_id: action.action_id.clone(),
+        action_type: Some(action.action_type.clone()),
+        action_description: action.action_description.clone(),
+        action_status: ActionStatus::Waiting,
+        result_description: None,
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::action::Action;
+    use crate::order::Order;
+    use crate::state::{ActionState, ActionStatus, State};
+    use crate::timestamp::from_unix_millis;
+
+    fn order() -> Order {
+        Order::builder("o1", from_unix_millis(0).unwrap())
+            .node("n1")
+            .edge_to("e1", "n2")
+            .edge_action(Action::start_pause("a2"))
+            .node_action(Action::start_pause("a3"))
+            .horizon_node("e2", "n3")
+            .build()
+            .unwrap()
+    }
+
+    fn state() -> State {
+        let states = order().initial_states();
+        State::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap())
+            .order("o1", 0)
+            .node_states(states.node_states)
+            .edge_states(states.edge_states)
+            .action_states(states.action_states)
+            .build()
+    }
+
+    #[rstest]
+    fn test_Order_initial_states() {
+        let order = order();
+        let mut order = Order { nodes: order.nodes.into_iter().rev().collect(), ..order };
+        order.nodes[2].actions.push(Action::stop_pause("a1"));
+
+        let states = order.initial_states();
+
+        let node_states = states.node_states.iter().map(|node| (node.node_id.as_str(), node.sequence_id, node.released)).collect::<Vec<_>>();
+        let edge_states = states.edge_states.iter().map(|edge| (edge.edge_id.as_str(), edge.sequence_id, edge.released)).collect::<Vec<_>>();
+        assert_that!(node_states, eq(&vec![("n1", 0, true), ("n2", 2, true), ("n3", 4, false)]));
+        assert_that!(edge_states, eq(&vec![("e1", 1, true), ("e2", 3, false)]));
+        assert_that!(states.action_states, elements_are![
+            matches_pattern!(ActionState { action_id: eq("a1"), action_type: some(eq("stopPause")), action_status: eq(&ActionStatus::Waiting) }),
+            matches_pattern!(ActionState { action_id: eq("a2"), action_type: some(eq("startPause")), action_status: eq(&ActionStatus::Waiting) }),
+            matches_pattern!(ActionState { action_id: eq("a3"), action_type: some(eq("startPause")), action_status: eq(&ActionStatus::Waiting) }),
+        ]);
+    }
+
+    #[rstest]
+    fn test_State_mark_node_traversed() {
+        let mut state = state();
+
+        assert_that!(state.mark_node_traversed(2), eq(true));
+        assert_that!(state.mark_node_traversed(1), eq(false));
+
+        assert_that!(state, matches_pattern!(State { last_node_id: eq("n2"), last_node_sequence_id: eq(&2) }));
+        assert_that!(state.node_states.iter().map(|node| node.sequence_id).collect::<Vec<_>>(), eq(&vec![4]));
+        assert_that!(state.edge_states.iter().map(|edge| edge.sequence_id).collect::<Vec<_>>(), eq(&vec![3]));
+    }
+
+    #[rstest]
+    fn test_State_finish_and_fail_action() {
+        let mut state = state();
+
+        assert_that!(state.finish_action("a2", Some("done".into())), eq(true));
+        assert_that!(state.fail_action("a3", None), eq(true));
+        assert_that!(state.finish_action("a9", None), eq(false));
+
+        assert_that!(state.action_states, elements_are![
+            matches_pattern!(ActionState { action_status: eq(&ActionStatus::Finished), result_description: some(eq("done")) }),
+            matches_pattern!(ActionState { action_status: eq(&ActionStatus::Failed), result_description: none() }),
+        ]);
+    }
+
+    #[rstest]
+    fn test_State_set_paused_pauses_and_resumes_running_actions() {
+        let mut state = State { driving: true, ..state() };
+        state.action_states[0].action_status = ActionStatus::Running;
+
+        state.set_paused(true);
+        assert_that!(state, matches_pattern!(State { paused: some(eq(&true)), driving: eq(&false) }));
+        assert_that!(state.action_states.iter().map(|action| action.action_status).collect::<Vec<_>>(), eq(&vec![ActionStatus::Paused, ActionStatus::Waiting]));
+
+        state.set_paused(false);
+        assert_that!(state.paused, some(eq(false)));
+        assert_that!(state.action_states.iter().map(|action| action.action_status).collect::<Vec<_>>(), eq(&vec![ActionStatus::Running, ActionStatus::Waiting]));
+    }
+}
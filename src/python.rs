@@ -0,0 +1,102 @@
+//!
+//! Python bindings of the core message types.
+//!
+use alloc::string::{String, ToString};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::instant_actions::InstantActions;
+use crate::order::Order;
+use crate::state::State;
+
+macro_rules! message_class {
+    ($class:ident, $name:literal, $message:ident) => {
+        #[doc = concat!("Python class of an [`", stringify!($message), "`](crate::v2_0) message.")]
+        #[pyclass(name = $name, module = "vda5050_types")]
+        pub struct $class(pub $message);
+
+        #[pymethods]
+        impl $class {
+
+            /// Parses the message from JSON.
+            #[staticmethod]
+            fn from_json(json: &str) -> PyResult<Self> {
+                serde_json::from_str(json)
+                    .map(Self)
+                    .map_err(|error| PyValueError::new_err(error.to_string()))
+            }
+
+            /// Serializes the message into JSON.
+            fn to_json(&self) -> PyResult<String> {
+                serde_json::to_string(&self.0)
+                    .map_err(|error| PyValueError::new_err(error.to_string()))
+            }
+        }
+
+        impl From<$message> for $class {
+            fn from(message: $message) -> Self {
+                Self(message)
+            }
+        }
+    };
+}
+
+message_class!(PyInstantActions, "InstantActions", InstantActions);
+message_class!(PyOrder, "Order", Order);
+message_class!(PyState, "State", State);
+
+/// Adds the message classes to the given Python module.
+///
+/// ```ignore
+/// #[pymodule]
+/// fn vda5050_types(module: &Bound<'_, PyModule>) -> PyResult<()> {
+///     vda5050_types::v2_0::python::register(module)
+/// }
+/// ```
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyInstantActions>()?;
+    module.add_class::<PyOrder>()?;
+    module.add_class::<PyState>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use googletest::prelude::*;
+    use pyo3::prelude::*;
+    use pyo3::types::{PyDict, PyModule};
+    use rstest::rstest;
+
+    use super::register;
+
+    const INSTANT_ACTIONS: &str = r#"{"headerId":1,"timestamp":"2017-04-15T11:40:03.120Z","version":"2.0.0","manufacturer":"Fubar Co.","serialNumber":"1234","instantActions":[]}"#;
+
+    fn run(code: &str) -> PyResult<String> {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new_bound(py, "vda5050_types")?;
+            register(&module)?;
+            let locals = PyDict::new_bound(py);
+            locals.set_item("vda5050_types", module)?;
+            locals.set_item("json", INSTANT_ACTIONS)?;
+            py.run_bound(code, None, Some(&locals))?;
+            locals.get_item("result")?.expect("result is set").extract()
+        })
+    }
+
+    #[rstest]
+    fn test_python_InstantActions_json_round_trip() {
+        let result = run("result = vda5050_types.InstantActions.from_json(json).to_json()");
+
+        assert_that!(result, ok(eq(INSTANT_ACTIONS)));
+    }
+
+    #[rstest]
+    fn test_python_Order_from_invalid_json_raises_value_error() {
+        let result = run("try:\n    vda5050_types.Order.from_json(json)\n    result = 'no error'\nexcept ValueError as error:\n    result = type(error).__name__");
+
+        assert_that!(result, ok(eq("ValueError")));
+    }
+}
@@ -0,0 +1,193 @@
+//!
+//! Reports of violations of several messages whose severities are configurable per rule.
+//!
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::validation::Violation;
+
+/// Severity of a violation, see [`SeverityPolicy`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum Severity {
+    /// The message can be processed, e.g. a timestamp slightly in the future.
+    Warning,
+    /// The message is to be rejected.
+    Error,
+}
+
+/// Severities of the violated rules, e.g. to tolerate violations of some rules in a deployment.
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct SeverityPolicy {
+    default: Option<Severity>,
+    rules: BTreeMap<String, Severity>,
+}
+
+impl SeverityPolicy {
+
+    /// Creates a policy rating the violations of all rules as [`Severity::Error`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rates the violations of all rules without explicit severity as `severity`.
+    pub fn default_severity(mut self, severity: Severity) -> Self {
+        self.default = Some(severity);
+        self
+    }
+
+    /// Rates the violations of `rule`, e.g. [`RULE_FUTURE_TIMESTAMP`](crate::validation::RULE_FUTURE_TIMESTAMP), as
+    /// `severity`.
+    pub fn rule(mut self, rule: &str, severity: Severity) -> Self {
+        self.rules.insert(rule.into(), severity);
+        self
+    }
+
+    /// Returns the severity of the violations of `rule`.
+    pub fn severity(&self, rule: &str) -> Severity {
+        self.rules.get(rule).copied().or(self.default).unwrap_or(Severity::Error)
+    }
+}
+
+/// A violation rated by a [`SeverityPolicy`] together with the message it was found in.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct Finding {
+    /// Description of the message, e.g. `order o1/2`.
+    pub source: String,
+    /// Severity of the violation.
+    pub severity: Severity,
+    /// The violation.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub violation: Violation,
+}
+
+/// Violations of one or more messages rated by a [`SeverityPolicy`].
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct ValidationReport {
+    /// Findings in the order they were added.
+    pub findings: Vec<Finding>,
+}
+
+impl ValidationReport {
+
+    /// Creates a report without findings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a report of the `violations` of the message `source` rated by `policy`.
+    pub fn from_violations(source: impl Into<String>, violations: Vec<Violation>, policy: &SeverityPolicy) -> Self {
+        let mut report = Self::new();
+        report.add(source, violations, policy);
+        report
+    }
+
+    /// Adds the `violations` of the message `source` rated by `policy`.
+    pub fn add(&mut self, source: impl Into<String>, violations: Vec<Violation>, policy: &SeverityPolicy) {
+        let source = source.into();
+        self.findings.extend(violations.into_iter().map(|violation| Finding {
+            source: source.clone(),
+            severity: policy.severity(violation.rule),
+            violation,
+        }));
+    }
+
+    /// Appends the findings of `other`, e.g. of another message.
+    pub fn merge(&mut self, other: ValidationReport) {
+        self.findings.extend(other.findings);
+    }
+
+    /// Returns the report without the findings below `severity`.
+    pub fn at_least(mut self, severity: Severity) -> Self {
+        self.retain(|finding| finding.severity >= severity);
+        self
+    }
+
+    /// Keeps the findings for which `keep` returns `true`.
+    pub fn retain(&mut self, keep: impl FnMut(&Finding) -> bool) {
+        self.findings.retain(keep);
+    }
+
+    /// Returns the findings of `severity`.
+    pub fn of_severity(&self, severity: Severity) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(move |finding| finding.severity == severity)
+    }
+
+    /// Returns `true` if any finding is an error.
+    pub fn has_errors(&self) -> bool {
+        self.findings.iter().any(|finding| finding.severity == Severity::Error)
+    }
+
+    /// Returns `true` if the report contains no finding.
+    pub fn is_empty(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::validation::{RULE_EMPTY_ID, RULE_FUTURE_TIMESTAMP, RULE_TOO_LONG, Violation};
+    use super::{Severity, SeverityPolicy, ValidationReport};
+
+    fn violation(path: &str, rule: &'static str) -> Violation {
+        Violation { path: path.into(), rule, message: "violated".into() }
+    }
+
+    #[rstest]
+    #[case::default(SeverityPolicy::new(), Severity::Error)]
+    #[case::default_severity(SeverityPolicy::new().default_severity(Severity::Warning), Severity::Warning)]
+    #[case::rule(SeverityPolicy::new().default_severity(Severity::Error).rule(RULE_FUTURE_TIMESTAMP, Severity::Warning), Severity::Warning)]
+    #[case::other_rule(SeverityPolicy::new().rule(RULE_TOO_LONG, Severity::Warning), Severity::Error)]
+    fn test_SeverityPolicy_severity(#[case] policy: SeverityPolicy, #[case] expected: Severity) {
+        assert_that!(policy.severity(RULE_FUTURE_TIMESTAMP), eq(expected));
+    }
+
+    #[rstest]
+    fn test_ValidationReport_merge_and_filter() {
+        let policy = SeverityPolicy::new().rule(RULE_FUTURE_TIMESTAMP, Severity::Warning);
+        let mut report = ValidationReport::from_violations("order o1", vec![violation("/timestamp", RULE_FUTURE_TIMESTAMP)], &policy);
+
+        report.merge(ValidationReport::from_violations("state 7", vec![violation("/orderId", RULE_EMPTY_ID)], &policy));
+
+        assert_that!(report.findings.iter().map(|finding| (finding.source.as_str(), finding.severity)).collect::<Vec<_>>(), eq(&vec![
+            ("order o1", Severity::Warning),
+            ("state 7", Severity::Error),
+        ]));
+        assert_that!(report.has_errors(), eq(true));
+        assert_that!(report.of_severity(Severity::Warning).count(), eq(1));
+
+        let errors = report.at_least(Severity::Error);
+
+        assert_that!(errors.findings.iter().map(|finding| finding.violation.path.as_str()).collect::<Vec<_>>(), eq(&vec!["/orderId"]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    fn test_serialize_ValidationReport() {
+        let report = ValidationReport::from_violations("state 7", vec![violation("/orderId", RULE_EMPTY_ID)], &SeverityPolicy::new());
+
+        assert_that!(serde_json::to_string(&report).unwrap(), eq(r#"{"findings":[{"source":"state 7","severity":"ERROR","path":"/orderId","rule":"empty-id","message":"violated"}]}"#));
+    }
+}
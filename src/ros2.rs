@@ -0,0 +1,1199 @@
+//!
+//! Plain structs mirroring the messages of the community [vda5050_msgs](https://github.com/ipa320/vda5050_msgs)
+//! ROS 2 package and conversions from and into the types of this crate.
+//!
+//! The structs only consist of ROS primitive types (`string`, `bool`, `uint64`, `int64`, `float64`) and arrays of
+//! other structs, thus they can be mapped one-to-one onto the messages generated by `r2r` or `rclrs`.
+//!
+//! As ROS messages have no optional fields, absent values are represented as follows:
+//! - optional strings as empty string,
+//! - optional numbers as `NaN`,
+//! - optional flags as `false`,
+//! - optional objects as a default object with the `valid` flag not set,
+//! - timestamps as ISO8601 string with millisecond precision,
+//! - enumerations as their VDA5050 string representation, e.g. `"HARD"`,
+//! - action parameter values as their textual representation, e.g. `"3.5"` or `"true"`, where floats always have a
+//!   fraction or exponent, e.g. `"42.0"`.
+//!
+//! Therefore, converting from ROS and back yields the same message up to the notation of numbers, e.g. `"4.20"` turns
+//! into `"4.2"`. Converting into ROS and back yields the same message except for values that ROS cannot distinguish:
+//! - absent flags turn into `false`,
+//! - empty optional strings turn into absent strings,
+//! - action parameter values of strings that are empty or the textual representation of a boolean or number, e.g.
+//!   `""`, `"true"` or `"7"`, turn into `Null`, `Boolean` or `Integer` values.
+//!
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::action;
+use crate::checked::Raw;
+use crate::common;
+use crate::connection;
+use crate::finite::{Double, RawDouble};
+use crate::instant_actions;
+use crate::order;
+use crate::state;
+use crate::timestamp::TimestampPrecision;
+
+/// Error returned when a ROS message cannot be converted.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum ConversionError {
+    /// The timestamp is not a valid ISO8601 timestamp.
+    InvalidTimestamp(String),
+    /// The value of an enumeration is unknown.
+    UnknownValue {
+        /// Name of the field.
+        field: &'static str,
+        /// The unknown value.
+        value: String,
+    },
+    /// The value of a field is outside of its range, which is only checked if the feature `checked-types` is enabled.
+    OutOfRange {
+        /// Name of the field.
+        field: &'static str,
+        /// The value outside of the range.
+        value: String,
+    },
+}
+
+impl core::fmt::Display for ConversionError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConversionError::InvalidTimestamp(value) => write!(formatter, "invalid ISO8601 timestamp `{value}`"),
+            ConversionError::UnknownValue { field, value } => write!(formatter, "unknown value `{value}` of field `{field}`"),
+            ConversionError::OutOfRange { field, value } => write!(formatter, "value `{value}` of field `{field}` is out of range"),
+        }
+    }
+}
+
+macro_rules! ros_message {
+    ($(#[$meta:meta])* $name:ident { $($(#[$field_meta:meta])* $field:ident: $type:ty),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Default, PartialEq)]
+        #[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+        pub struct $name {
+            $($(#[$field_meta])* pub $field: $type,)*
+        }
+    };
+}
+
+ros_message!(
+    /// `vda5050_msgs/msg/Order`
+    Order {
+        header_id: u64,
+        timestamp: String,
+        version: String,
+        manufacturer: String,
+        serial_number: String,
+        order_id: String,
+        order_update_id: u64,
+        zone_set_id: String,
+        nodes: Vec<Node>,
+        edges: Vec<Edge>,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/Node`
+    Node {
+        node_id: String,
+        sequence_id: u64,
+        node_description: String,
+        released: bool,
+        node_position: NodePosition,
+        actions: Vec<Action>,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/NodePosition`
+    NodePosition {
+        /// `false` if the position is absent.
+        valid: bool,
+        x: f64,
+        y: f64,
+        theta: f64,
+        allowed_deviation_xy: f64,
+        allowed_deviation_theta: f64,
+        map_id: String,
+        map_description: String,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/Edge`
+    Edge {
+        edge_id: String,
+        sequence_id: u64,
+        edge_description: String,
+        released: bool,
+        start_node_id: String,
+        end_node_id: String,
+        max_speed: f64,
+        max_height: f64,
+        min_height: f64,
+        orientation: f64,
+        orientation_type: String,
+        direction: String,
+        rotation_allowed: bool,
+        max_rotation_speed: f64,
+        trajectory: Trajectory,
+        length: f64,
+        actions: Vec<Action>,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/Trajectory`
+    Trajectory {
+        /// `false` if the trajectory is absent.
+        valid: bool,
+        degree: i64,
+        knot_vector: Vec<f64>,
+        control_points: Vec<ControlPoint>,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/ControlPoint`
+    ControlPoint {
+        x: f64,
+        y: f64,
+        weight: f64,
+        orientation: f64,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/Action`
+    Action {
+        action_type: String,
+        action_id: String,
+        action_description: String,
+        blocking_type: String,
+        action_parameters: Vec<ActionParameter>,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/ActionParameter`
+    ActionParameter {
+        key: String,
+        value: String,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/InstantActions`
+    InstantActions {
+        header_id: u64,
+        timestamp: String,
+        version: String,
+        manufacturer: String,
+        serial_number: String,
+        instant_actions: Vec<Action>,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/Connection`
+    Connection {
+        header_id: u64,
+        timestamp: String,
+        version: String,
+        manufacturer: String,
+        serial_number: String,
+        connection_state: String,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/State`
+    State {
+        header_id: u64,
+        timestamp: String,
+        version: String,
+        manufacturer: String,
+        serial_number: String,
+        order_id: String,
+        order_update_id: u64,
+        zone_set_id: String,
+        last_node_id: String,
+        last_node_sequence_id: u64,
+        driving: bool,
+        paused: bool,
+        new_base_request: bool,
+        distance_since_last_node: f64,
+        operating_mode: String,
+        node_states: Vec<NodeState>,
+        edge_states: Vec<EdgeState>,
+        agv_position: AgvPosition,
+        velocity: Velocity,
+        loads: Vec<Load>,
+        action_states: Vec<ActionState>,
+        battery_state: BatteryState,
+        errors: Vec<Error>,
+        information: Vec<Information>,
+        safety_state: SafetyState,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/NodeState`
+    NodeState {
+        node_id: String,
+        sequence_id: u64,
+        node_description: String,
+        node_position: NodePosition,
+        released: bool,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/EdgeState`
+    EdgeState {
+        edge_id: String,
+        sequence_id: u64,
+        edge_description: String,
+        released: bool,
+        trajectory: Trajectory,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/AGVPosition`
+    AgvPosition {
+        /// `false` if the position is absent.
+        valid: bool,
+        x: f64,
+        y: f64,
+        theta: f64,
+        map_id: String,
+        map_description: String,
+        position_initialized: bool,
+        localization_score: f64,
+        deviation_range: f64,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/Velocity`
+    Velocity {
+        /// `false` if the velocity is absent.
+        valid: bool,
+        vx: f64,
+        vy: f64,
+        omega: f64,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/Load`
+    Load {
+        load_id: String,
+        load_type: String,
+        load_position: String,
+        bounding_box_reference: BoundingBoxReference,
+        load_dimensions: LoadDimensions,
+        weight: f64,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/BoundingBoxReference`
+    BoundingBoxReference {
+        /// `false` if the reference is absent.
+        valid: bool,
+        x: f64,
+        y: f64,
+        z: f64,
+        theta: f64,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/LoadDimensions`
+    LoadDimensions {
+        /// `false` if the dimensions are absent.
+        valid: bool,
+        length: f64,
+        width: f64,
+        height: f64,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/ActionState`
+    ActionState {
+        action_id: String,
+        action_type: String,
+        action_description: String,
+        action_status: String,
+        result_description: String,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/BatteryState`
+    BatteryState {
+        battery_charge: f64,
+        battery_voltage: f64,
+        /// Negative if absent.
+        battery_health: i64,
+        charging: bool,
+        reach: f64,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/Error`
+    Error {
+        error_type: String,
+        error_references: Vec<Reference>,
+        error_description: String,
+        error_level: String,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/Info`
+    Information {
+        info_type: String,
+        info_references: Vec<Reference>,
+        info_description: String,
+        info_level: String,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/ErrorReference` and `vda5050_msgs/msg/InfoReference`
+    Reference {
+        reference_key: String,
+        reference_value: String,
+    }
+);
+
+ros_message!(
+    /// `vda5050_msgs/msg/SafetyState`
+    SafetyState {
+        e_stop: String,
+        field_violation: bool,
+    }
+);
+
+fn to_string<T: Into<String>>(value: Option<T>) -> String {
+    value.map(Into::into).unwrap_or_default()
+}
+
+fn from_string<T: From<String>>(value: String) -> Option<T> {
+    (!value.is_empty()).then(|| T::from(value))
+}
+
+fn to_id<T: Into<String>>(id: T) -> String {
+    id.into()
+}
+
+fn from_id<T: From<String>>(id: String) -> T {
+    T::from(id)
+}
+
+fn raw_f64<T: Raw>(value: T) -> f64 {
+    f64::from(value.raw())
+}
+
+fn to_f64<T: Raw>(value: Option<T>) -> f64 {
+    value.map_or(f64::NAN, raw_f64)
+}
+
+fn checked<T: Raw>(field: &'static str, value: f64) -> Result<T, ConversionError> {
+    T::try_from_raw(value as f32).map_err(|_| ConversionError::OutOfRange { field, value: value.to_string() })
+}
+
+fn from_f64<T: Raw>(field: &'static str, value: f64) -> Result<Option<T>, ConversionError> {
+    (!value.is_nan()).then(|| checked(field, value)).transpose()
+}
+
+fn to_timestamp(timestamp: &common::Timestamp) -> String {
+    TimestampPrecision::Milliseconds.format(timestamp)
+}
+
+fn from_timestamp(timestamp: String) -> Result<common::Timestamp, ConversionError> {
+    crate::timestamp::parse(&timestamp).map_err(|_| ConversionError::InvalidTimestamp(timestamp))
+}
+
+fn from_vec<T, U: TryFrom<T, Error = ConversionError>>(values: Vec<T>) -> Result<Vec<U>, ConversionError> {
+    values.into_iter().map(U::try_from).collect()
+}
+
+fn to_vec<T, U: From<T>>(values: Vec<T>) -> Vec<U> {
+    values.into_iter().map(U::from).collect()
+}
+
+/// Implements the conversion of an enumeration into and from its string representation.
+macro_rules! enumeration {
+    ($field:literal, $to:ident, $from:ident, $type:ty { $($variant:ident => $value:literal),* $(,)? }) => {
+        fn $to(value: $type) -> String {
+            match value {
+                $(<$type>::$variant => String::from($value),)*
+            }
+        }
+
+        fn $from(value: String) -> Result<$type, ConversionError> {
+            match value.as_str() {
+                $($value => Ok(<$type>::$variant),)*
+                _ => Err(ConversionError::UnknownValue { field: $field, value }),
+            }
+        }
+    };
+}
+
+enumeration!("blockingType", to_blocking_type, from_blocking_type, action::BlockingType {
+    None => "NONE",
+    Soft => "SOFT",
+    Hard => "HARD",
+});
+
+enumeration!("orientationType", to_orientation_type, from_orientation_type, order::OrientationType {
+    Global => "GLOBAL",
+    Tangential => "TANGENTIAL",
+});
+
+enumeration!("connectionState", to_connection_state, from_connection_state, connection::ConnectionState {
+    Online => "ONLINE",
+    Offline => "OFFLINE",
+    ConnectionBroken => "CONNECTIONBROKEN",
+});
+
+enumeration!("operatingMode", to_operating_mode, from_operating_mode, state::OperatingMode {
+    Automatic => "AUTOMATIC",
+    Semiautomatic => "SEMIAUTOMATIC",
+    Manual => "MANUAL",
+    Service => "SERVICE",
+    Teachin => "TEACHIN",
+});
+
+enumeration!("actionStatus", to_action_status, from_action_status, state::ActionStatus {
+    Waiting => "WAITING",
+    Initializing => "INITIALIZING",
+    Paused => "PAUSED",
+    Running => "RUNNING",
+    Finished => "FINISHED",
+    Failed => "FAILED",
+});
+
+enumeration!("errorLevel", to_error_level, from_error_level, state::ErrorLevel {
+    Warning => "WARNING",
+    Fatal => "FATAL",
+});
+
+enumeration!("infoLevel", to_info_level, from_info_level, state::InfoLevel {
+    Info => "INFO",
+    Debug => "DEBUG",
+});
+
+enumeration!("eStop", to_e_stop, from_e_stop, state::EStop {
+    Autoack => "AUTOACK",
+    Manual => "MANUAL",
+    Remote => "REMOTE",
+    None => "NONE",
+});
+
+fn to_action_parameter_value(value: action::ActionParameterValue) -> String {
+    match value {
+        action::ActionParameterValue::Null => String::new(),
+        action::ActionParameterValue::Boolean(value) => value.to_string(),
+        action::ActionParameterValue::Integer(value) => value.to_string(),
+        action::ActionParameterValue::Float(value) => {
+            let mut value = value.to_string();
+            if value.parse::<i64>().is_ok() {
+                value.push_str(".0");
+            }
+            value
+        }
+        action::ActionParameterValue::String(value) => value,
+    }
+}
+
+fn from_action_parameter_value(value: String) -> action::ActionParameterValue {
+    if value.is_empty() {
+        action::ActionParameterValue::Null
+    }
+    else if let Ok(boolean) = value.parse() {
+        action::ActionParameterValue::Boolean(boolean)
+    }
+    else if let Ok(integer) = value.parse() {
+        action::ActionParameterValue::Integer(integer)
+    }
+    else if let Some(float) = value.parse().ok().and_then(|float| Double::try_from_raw(float).ok()) {
+        action::ActionParameterValue::Float(float)
+    }
+    else {
+        action::ActionParameterValue::String(value)
+    }
+}
+
+impl From<order::Order> for Order {
+    fn from(order: order::Order) -> Self {
+        Self {
+            header_id: order.header_id,
+            timestamp: to_timestamp(&order.timestamp),
+            version: order.version,
+            manufacturer: order.manufacturer,
+            serial_number: order.serial_number,
+            order_id: to_id(order.order_id),
+            order_update_id: order.order_update_id,
+            zone_set_id: to_string(order.zone_set_id),
+            nodes: to_vec(order.nodes),
+            edges: to_vec(order.edges),
+        }
+    }
+}
+
+impl TryFrom<Order> for order::Order {
+    type Error = ConversionError;
+
+    fn try_from(order: Order) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header_id: order.header_id,
+            timestamp: from_timestamp(order.timestamp)?,
+            version: order.version,
+            manufacturer: order.manufacturer,
+            serial_number: order.serial_number,
+            order_id: from_id(order.order_id),
+            order_update_id: order.order_update_id,
+            zone_set_id: from_string(order.zone_set_id),
+            nodes: from_vec(order.nodes)?,
+            edges: from_vec(order.edges)?,
+        })
+    }
+}
+
+impl From<order::Node> for Node {
+    fn from(node: order::Node) -> Self {
+        Self {
+            node_id: to_id(node.node_id),
+            sequence_id: node.sequence_id,
+            node_description: to_string(node.node_description),
+            released: node.released,
+            node_position: node.node_position.map(NodePosition::from).unwrap_or_default(),
+            actions: to_vec(node.actions),
+        }
+    }
+}
+
+impl TryFrom<Node> for order::Node {
+    type Error = ConversionError;
+
+    fn try_from(node: Node) -> Result<Self, Self::Error> {
+        Ok(Self {
+            node_id: from_id(node.node_id),
+            sequence_id: node.sequence_id,
+            node_description: from_string(node.node_description),
+            released: node.released,
+            node_position: node.node_position.try_into()?,
+            actions: from_vec(node.actions)?,
+        })
+    }
+}
+
+impl From<common::NodePosition> for NodePosition {
+    fn from(position: common::NodePosition) -> Self {
+        Self {
+            valid: true,
+            x: raw_f64(position.x),
+            y: raw_f64(position.y),
+            theta: to_f64(position.theta),
+            allowed_deviation_xy: to_f64(position.allowed_deviation_xy),
+            allowed_deviation_theta: to_f64(position.allowed_deviation_theta),
+            map_id: to_id(position.map_id),
+            map_description: to_string(position.map_description),
+        }
+    }
+}
+
+impl TryFrom<NodePosition> for Option<common::NodePosition> {
+    type Error = ConversionError;
+
+    fn try_from(position: NodePosition) -> Result<Self, Self::Error> {
+        position.valid.then(|| Ok(common::NodePosition {
+            x: checked("x", position.x)?,
+            y: checked("y", position.y)?,
+            theta: from_f64("theta", position.theta)?,
+            allowed_deviation_xy: from_f64("allowedDeviationXY", position.allowed_deviation_xy)?,
+            allowed_deviation_theta: from_f64("allowedDeviationTheta", position.allowed_deviation_theta)?,
+            map_id: from_id(position.map_id),
+            map_description: from_string(position.map_description),
+        })).transpose()
+    }
+}
+
+impl From<order::Edge> for Edge {
+    fn from(edge: order::Edge) -> Self {
+        Self {
+            edge_id: to_id(edge.edge_id),
+            sequence_id: edge.sequence_id,
+            edge_description: to_string(edge.edge_description),
+            released: edge.released,
+            start_node_id: to_id(edge.start_node_id),
+            end_node_id: to_id(edge.end_node_id),
+            max_speed: to_f64(edge.max_speed),
+            max_height: to_f64(edge.max_height),
+            min_height: to_f64(edge.min_height),
+            orientation: to_f64(edge.orientation),
+            orientation_type: edge.orientation_type.map(to_orientation_type).unwrap_or_default(),
+            direction: to_string(edge.direction),
+            rotation_allowed: edge.rotation_allowed.unwrap_or_default(),
+            max_rotation_speed: to_f64(edge.max_rotation_speed),
+            trajectory: edge.trajectory.map(Trajectory::from).unwrap_or_default(),
+            length: to_f64(edge.length),
+            actions: to_vec(edge.actions),
+        }
+    }
+}
+
+impl TryFrom<Edge> for order::Edge {
+    type Error = ConversionError;
+
+    fn try_from(edge: Edge) -> Result<Self, Self::Error> {
+        Ok(Self {
+            edge_id: from_id(edge.edge_id),
+            sequence_id: edge.sequence_id,
+            edge_description: from_string(edge.edge_description),
+            released: edge.released,
+            start_node_id: from_id(edge.start_node_id),
+            end_node_id: from_id(edge.end_node_id),
+            max_speed: from_f64("maxSpeed", edge.max_speed)?,
+            max_height: from_f64("maxHeight", edge.max_height)?,
+            min_height: from_f64("minHeight", edge.min_height)?,
+            orientation: from_f64("orientation", edge.orientation)?,
+            orientation_type: from_string(edge.orientation_type).map(from_orientation_type).transpose()?,
+            direction: from_string(edge.direction),
+            rotation_allowed: Some(edge.rotation_allowed),
+            max_rotation_speed: from_f64("maxRotationSpeed", edge.max_rotation_speed)?,
+            length: from_f64("length", edge.length)?,
+            trajectory: edge.trajectory.try_into()?,
+            actions: from_vec(edge.actions)?,
+        })
+    }
+}
+
+impl From<common::Trajectory> for Trajectory {
+    fn from(trajectory: common::Trajectory) -> Self {
+        Self {
+            valid: true,
+            degree: trajectory.degree,
+            knot_vector: trajectory.knot_vector.into_iter().map(raw_f64).collect(),
+            control_points: trajectory.control_points.into_iter()
+                .map(|point| ControlPoint {
+                    x: raw_f64(point.x),
+                    y: raw_f64(point.y),
+                    weight: to_f64(point.weight),
+                    orientation: to_f64(point.orientation),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<Trajectory> for Option<common::Trajectory> {
+    type Error = ConversionError;
+
+    fn try_from(trajectory: Trajectory) -> Result<Self, Self::Error> {
+        trajectory.valid.then(|| Ok(common::Trajectory {
+            degree: trajectory.degree,
+            knot_vector: trajectory.knot_vector.into_iter().map(|knot| checked("knotVector", knot)).collect::<Result<_, _>>()?,
+            control_points: trajectory.control_points.into_iter()
+                .map(|point| Ok(common::ControlPoint {
+                    x: checked("x", point.x)?,
+                    y: checked("y", point.y)?,
+                    weight: from_f64("weight", point.weight)?,
+                    orientation: from_f64("orientation", point.orientation)?,
+                }))
+                .collect::<Result<_, _>>()?,
+        })).transpose()
+    }
+}
+
+impl From<action::Action> for Action {
+    fn from(action: action::Action) -> Self {
+        Self {
+            action_type: action.action_type,
+            action_id: to_id(action.action_id),
+            action_description: to_string(action.action_description),
+            blocking_type: to_blocking_type(action.blocking_type),
+            action_parameters: action.action_parameters.into_iter()
+                .map(|parameter| ActionParameter {
+                    key: parameter.key,
+                    value: to_action_parameter_value(parameter.value),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<Action> for action::Action {
+    type Error = ConversionError;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        Ok(Self {
+            action_type: action.action_type,
+            action_id: from_id(action.action_id),
+            action_description: from_string(action.action_description),
+            blocking_type: from_blocking_type(action.blocking_type)?,
+            action_parameters: action.action_parameters.into_iter()
+                .map(|parameter| action::ActionParameter {
+                    key: parameter.key,
+                    value: from_action_parameter_value(parameter.value),
+                })
+                .collect(),
+        })
+    }
+}
+
+impl From<instant_actions::InstantActions> for InstantActions {
+    fn from(instant_actions: instant_actions::InstantActions) -> Self {
+        Self {
+            header_id: instant_actions.header_id,
+            timestamp: to_timestamp(&instant_actions.timestamp),
+            version: instant_actions.version,
+            manufacturer: instant_actions.manufacturer,
+            serial_number: instant_actions.serial_number,
+            instant_actions: to_vec(instant_actions.instant_actions),
+        }
+    }
+}
+
+impl TryFrom<InstantActions> for instant_actions::InstantActions {
+    type Error = ConversionError;
+
+    fn try_from(instant_actions: InstantActions) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header_id: instant_actions.header_id,
+            timestamp: from_timestamp(instant_actions.timestamp)?,
+            version: instant_actions.version,
+            manufacturer: instant_actions.manufacturer,
+            serial_number: instant_actions.serial_number,
+            instant_actions: from_vec(instant_actions.instant_actions)?,
+        })
+    }
+}
+
+impl From<connection::Connection> for Connection {
+    fn from(connection: connection::Connection) -> Self {
+        Self {
+            header_id: connection.header_id,
+            timestamp: to_timestamp(&connection.timestamp),
+            version: connection.version,
+            manufacturer: connection.manufacturer,
+            serial_number: connection.serial_number,
+            connection_state: to_connection_state(connection.connection_state),
+        }
+    }
+}
+
+impl TryFrom<Connection> for connection::Connection {
+    type Error = ConversionError;
+
+    fn try_from(connection: Connection) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header_id: connection.header_id,
+            timestamp: from_timestamp(connection.timestamp)?,
+            version: connection.version,
+            manufacturer: connection.manufacturer,
+            serial_number: connection.serial_number,
+            connection_state: from_connection_state(connection.connection_state)?,
+        })
+    }
+}
+
+impl From<state::State> for State {
+    fn from(state: state::State) -> Self {
+        Self {
+            header_id: state.header_id,
+            timestamp: to_timestamp(&state.timestamp),
+            version: state.version,
+            manufacturer: state.manufacturer,
+            serial_number: state.serial_number,
+            order_id: to_id(state.order_id),
+            order_update_id: state.order_update_id,
+            zone_set_id: to_string(state.zone_set_id),
+            last_node_id: to_id(state.last_node_id),
+            last_node_sequence_id: state.last_node_sequence_id,
+            driving: state.driving,
+            paused: state.paused.unwrap_or_default(),
+            new_base_request: state.new_base_request.unwrap_or_default(),
+            distance_since_last_node: to_f64(state.distance_since_last_node),
+            operating_mode: to_operating_mode(state.operating_mode),
+            node_states: state.node_states.into_iter()
+                .map(|node| NodeState {
+                    node_id: to_id(node.node_id),
+                    sequence_id: node.sequence_id,
+                    node_description: to_string(node.node_description),
+                    node_position: node.node_position.map(NodePosition::from).unwrap_or_default(),
+                    released: node.released,
+                })
+                .collect(),
+            edge_states: state.edge_states.into_iter()
+                .map(|edge| EdgeState {
+                    edge_id: to_id(edge.edge_id),
+                    sequence_id: edge.sequence_id,
+                    edge_description: to_string(edge.edge_description),
+                    released: edge.released,
+                    trajectory: edge.trajectory.map(Trajectory::from).unwrap_or_default(),
+                })
+                .collect(),
+            agv_position: state.agv_position
+                .map(|position| AgvPosition {
+                    valid: true,
+                    x: raw_f64(position.x),
+                    y: raw_f64(position.y),
+                    theta: raw_f64(position.theta),
+                    map_id: to_id(position.map_id),
+                    map_description: to_string(position.map_description),
+                    position_initialized: position.position_initialized,
+                    localization_score: to_f64(position.localization_score),
+                    deviation_range: to_f64(position.deviation_range),
+                })
+                .unwrap_or_default(),
+            velocity: state.velocity
+                .map(|velocity| Velocity {
+                    valid: true,
+                    vx: to_f64(velocity.vx),
+                    vy: to_f64(velocity.vy),
+                    omega: to_f64(velocity.omega),
+                })
+                .unwrap_or_default(),
+            loads: state.loads.into_iter()
+                .map(|load| Load {
+                    load_id: to_string(load.load_id),
+                    load_type: to_string(load.load_type),
+                    load_position: to_string(load.load_position),
+                    bounding_box_reference: load.bounding_box_reference
+                        .map(|reference| BoundingBoxReference {
+                            valid: true,
+                            x: raw_f64(reference.x),
+                            y: raw_f64(reference.y),
+                            z: raw_f64(reference.z),
+                            theta: to_f64(reference.theta),
+                        })
+                        .unwrap_or_default(),
+                    load_dimensions: load.load_dimensions
+                        .map(|dimensions| LoadDimensions {
+                            valid: true,
+                            length: raw_f64(dimensions.length),
+                            width: raw_f64(dimensions.width),
+                            height: to_f64(dimensions.height),
+                        })
+                        .unwrap_or_default(),
+                    weight: to_f64(load.weight),
+                })
+                .collect(),
+            action_states: state.action_states.into_iter()
+                .map(|action| ActionState {
+                    action_id: to_id(action.action_id),
+                    action_type: to_string(action.action_type),
+                    action_description: to_string(action.action_description),
+                    action_status: to_action_status(action.action_status),
+                    result_description: to_string(action.result_description),
+                })
+                .collect(),
+            battery_state: BatteryState {
+                battery_charge: raw_f64(state.battery_state.battery_charge),
+                battery_voltage: to_f64(state.battery_state.battery_voltage),
+                battery_health: state.battery_state.battery_health.map_or(-1, i64::from),
+                charging: state.battery_state.charging,
+                reach: to_f64(state.battery_state.reach),
+            },
+            errors: state.errors.into_iter()
+                .map(|error| Error {
+                    error_type: error.error_type,
+                    error_references: error.error_references.into_iter()
+                        .map(|reference| Reference { reference_key: reference.reference_key, reference_value: reference.reference_value })
+                        .collect(),
+                    error_description: to_string(error.error_description),
+                    error_level: to_error_level(error.error_level),
+                })
+                .collect(),
+            information: state.information.into_iter()
+                .map(|information| Information {
+                    info_type: information.info_type,
+                    info_references: information.info_references.into_iter()
+                        .map(|reference| Reference { reference_key: reference.reference_key, reference_value: reference.reference_value })
+                        .collect(),
+                    info_description: to_string(information.info_description),
+                    info_level: to_info_level(information.info_level),
+                })
+                .collect(),
+            safety_state: SafetyState {
+                e_stop: to_e_stop(state.safety_state.e_stop),
+                field_violation: state.safety_state.field_violation,
+            },
+        }
+    }
+}
+
+impl TryFrom<State> for state::State {
+    type Error = ConversionError;
+
+    fn try_from(state: State) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header_id: state.header_id,
+            timestamp: from_timestamp(state.timestamp)?,
+            version: state.version,
+            manufacturer: state.manufacturer,
+            serial_number: state.serial_number,
+            order_id: from_id(state.order_id),
+            order_update_id: state.order_update_id,
+            zone_set_id: from_string(state.zone_set_id),
+            last_node_id: from_id(state.last_node_id),
+            last_node_sequence_id: state.last_node_sequence_id,
+            driving: state.driving,
+            paused: Some(state.paused),
+            new_base_request: Some(state.new_base_request),
+            distance_since_last_node: from_f64("distanceSinceLastNode", state.distance_since_last_node)?,
+            operating_mode: from_operating_mode(state.operating_mode)?,
+            node_states: state.node_states.into_iter()
+                .map(|node| Ok(state::NodeState {
+                    node_id: from_id(node.node_id),
+                    sequence_id: node.sequence_id,
+                    node_description: from_string(node.node_description),
+                    node_position: node.node_position.try_into()?,
+                    released: node.released,
+                }))
+                .collect::<Result<_, _>>()?,
+            edge_states: state.edge_states.into_iter()
+                .map(|edge| Ok(state::EdgeState {
+                    edge_id: from_id(edge.edge_id),
+                    sequence_id: edge.sequence_id,
+                    edge_description: from_string(edge.edge_description),
+                    released: edge.released,
+                    trajectory: edge.trajectory.try_into()?,
+                }))
+                .collect::<Result<_, _>>()?,
+            agv_position: state.agv_position.valid.then(|| Ok(common::AgvPosition {
+                x: checked("x", state.agv_position.x)?,
+                y: checked("y", state.agv_position.y)?,
+                theta: checked("theta", state.agv_position.theta)?,
+                map_id: from_id(state.agv_position.map_id),
+                map_description: from_string(state.agv_position.map_description),
+                position_initialized: state.agv_position.position_initialized,
+                localization_score: from_f64("localizationScore", state.agv_position.localization_score)?,
+                deviation_range: from_f64("deviationRange", state.agv_position.deviation_range)?,
+            })).transpose()?,
+            velocity: state.velocity.valid.then(|| Ok(common::Velocity {
+                vx: from_f64("vx", state.velocity.vx)?,
+                vy: from_f64("vy", state.velocity.vy)?,
+                omega: from_f64("omega", state.velocity.omega)?,
+            })).transpose()?,
+            loads: state.loads.into_iter()
+                .map(|load| Ok(state::Load {
+                    load_id: from_string(load.load_id),
+                    load_type: from_string(load.load_type),
+                    load_position: from_string(load.load_position),
+                    bounding_box_reference: load.bounding_box_reference.valid.then(|| Ok(common::BoundingBoxReference {
+                        x: checked("x", load.bounding_box_reference.x)?,
+                        y: checked("y", load.bounding_box_reference.y)?,
+                        z: checked("z", load.bounding_box_reference.z)?,
+                        theta: from_f64("theta", load.bounding_box_reference.theta)?,
+                    })).transpose()?,
+                    load_dimensions: load.load_dimensions.valid.then(|| Ok(common::LoadDimensions {
+                        length: checked("length", load.load_dimensions.length)?,
+                        width: checked("width", load.load_dimensions.width)?,
+                        height: from_f64("height", load.load_dimensions.height)?,
+                    })).transpose()?,
+                    weight: from_f64("weight", load.weight)?,
+                }))
+                .collect::<Result<_, _>>()?,
+            action_states: state.action_states.into_iter()
+                .map(|action| Ok(state::ActionState {
+                    action_id: from_id(action.action_id),
+                    action_type: from_string(action.action_type),
+                    action_description: from_string(action.action_description),
+                    action_status: from_action_status(action.action_status)?,
+                    result_description: from_string(action.result_description),
+                }))
+                .collect::<Result<_, _>>()?,
+            battery_state: state::BatteryState {
+                battery_charge: checked("batteryCharge", state.battery_state.battery_charge)?,
+                battery_voltage: from_f64("batteryVoltage", state.battery_state.battery_voltage)?,
+                battery_health: u32::try_from(state.battery_state.battery_health).ok(),
+                charging: state.battery_state.charging,
+                reach: from_f64("reach", state.battery_state.reach)?,
+            },
+            errors: state.errors.into_iter()
+                .map(|error| Ok(state::Error {
+                    error_type: error.error_type,
+                    error_references: error.error_references.into_iter()
+                        .map(|reference| state::ErrorReference { reference_key: reference.reference_key, reference_value: reference.reference_value })
+                        .collect(),
+                    error_description: from_string(error.error_description),
+                    error_level: from_error_level(error.error_level)?,
+                }))
+                .collect::<Result<_, _>>()?,
+            information: state.information.into_iter()
+                .map(|information| Ok(state::Information {
+                    info_type: information.info_type,
+                    info_references: information.info_references.into_iter()
+                        .map(|reference| state::InfoReference { reference_key: reference.reference_key, reference_value: reference.reference_value })
+                        .collect(),
+                    info_description: from_string(information.info_description),
+                    info_level: from_info_level(information.info_level)?,
+                }))
+                .collect::<Result<_, _>>()?,
+            safety_state: state::SafetyState {
+                e_stop: from_e_stop(state.safety_state.e_stop)?,
+                field_violation: state.safety_state.field_violation,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::{checked, Magnitude};
+    use crate::finite::double;
+    use super::*;
+
+    fn ros_order() -> Order {
+        Order {
+            header_id: 7,
+            timestamp: String::from("2017-04-15T11:40:03.120Z"),
+            version: String::from("2.0.0"),
+            manufacturer: String::from("Fubar Co."),
+            serial_number: String::from("1234"),
+            order_id: String::from("o1"),
+            order_update_id: 1,
+            nodes: vec![Node {
+                node_id: String::from("n1"),
+                released: true,
+                node_position: NodePosition {
+                    valid: true,
+                    x: 1.5,
+                    y: -2.0,
+                    theta: f64::NAN,
+                    allowed_deviation_xy: 0.5,
+                    allowed_deviation_theta: f64::NAN,
+                    map_id: String::from("map"),
+                    ..NodePosition::default()
+                },
+                actions: vec![Action {
+                    action_type: String::from("pick"),
+                    action_id: String::from("a1"),
+                    blocking_type: String::from("HARD"),
+                    action_parameters: vec![
+                        ActionParameter { key: String::from("height"), value: String::from("0.5") },
+                        ActionParameter { key: String::from("stationType"), value: String::from("floor") },
+                    ],
+                    ..Action::default()
+                }],
+                ..Node::default()
+            }],
+            ..Order::default()
+        }
+    }
+
+    #[rstest]
+    fn test_ros2_Order_try_into_Order() {
+        let order = order::Order::try_from(ros_order());
+
+        assert_that!(order, ok(matches_pattern!(order::Order {
+            header_id: eq(&7),
+            zone_set_id: none(),
+            nodes: elements_are![matches_pattern!(order::Node {
+                node_description: none(),
+                node_position: some(matches_pattern!(common::NodePosition {
+                    x: eq(&1.5),
+                    theta: none(),
+                    allowed_deviation_xy: some(eq(&checked::<Magnitude>(0.5))),
+                })),
+                actions: elements_are![matches_pattern!(action::Action {
+                    blocking_type: matches_pattern!(action::BlockingType::Hard),
+                    action_parameters: elements_are![
+                        matches_pattern!(action::ActionParameter { value: eq(&action::ActionParameterValue::Float(double(0.5))) }),
+                        matches_pattern!(action::ActionParameter { value: eq(&action::ActionParameterValue::String(String::from("floor"))) }),
+                    ],
+                })],
+            })],
+        })));
+    }
+
+    #[rstest]
+    fn test_ros2_Order_round_trip() {
+        let order = order::Order::try_from(ros_order()).unwrap();
+
+        let ros = Order::from(order);
+
+        assert_that!(ros.timestamp, eq("2017-04-15T11:40:03.120Z"));
+        assert_that!(ros.nodes[0].node_position.allowed_deviation_xy, eq(0.5));
+        assert_that!(ros.nodes[0].node_position.theta.is_nan(), eq(true));
+        assert_that!(ros.nodes[0].actions[0].action_parameters[0].value, eq("0.5"));
+        assert_that!(ros.nodes[0].actions[0].action_parameters[1].value, eq("floor"));
+    }
+
+    #[rstest]
+    #[case::float(action::ActionParameterValue::Float(double(0.5)), "0.5", action::ActionParameterValue::Float(double(0.5)))]
+    #[case::integral_float(action::ActionParameterValue::Float(double(42.0)), "42.0", action::ActionParameterValue::Float(double(42.0)))]
+    #[case::integer(action::ActionParameterValue::Integer(42), "42", action::ActionParameterValue::Integer(42))]
+    #[case::boolean(action::ActionParameterValue::Boolean(true), "true", action::ActionParameterValue::Boolean(true))]
+    #[case::null(action::ActionParameterValue::Null, "", action::ActionParameterValue::Null)]
+    #[case::string(action::ActionParameterValue::String(String::from("floor")), "floor", action::ActionParameterValue::String(String::from("floor")))]
+    #[case::empty_string(action::ActionParameterValue::String(String::new()), "", action::ActionParameterValue::Null)]
+    #[case::boolean_string(action::ActionParameterValue::String(String::from("true")), "true", action::ActionParameterValue::Boolean(true))]
+    #[case::integer_string(action::ActionParameterValue::String(String::from("7")), "7", action::ActionParameterValue::Integer(7))]
+    fn test_ros2_action_parameter_value_round_trip(#[case] value: action::ActionParameterValue, #[case] ros: &str, #[case] expected: action::ActionParameterValue) {
+        let value = to_action_parameter_value(value);
+
+        assert_that!(value, eq(ros));
+        assert_that!(from_action_parameter_value(value), eq(&expected));
+    }
+
+    #[rstest]
+    fn test_ros2_Order_round_trip_turns_empty_strings_into_absent_strings() {
+        let order = order::Order { zone_set_id: Some("".into()), ..order::Order::try_from(ros_order()).unwrap() };
+
+        let order = order::Order::try_from(Order::from(order));
+
+        assert_that!(order, ok(matches_pattern!(order::Order { zone_set_id: none() })));
+    }
+
+    #[rstest]
+    #[case::timestamp(Order { timestamp: String::from("yesterday"), ..ros_order() }, ConversionError::InvalidTimestamp(String::from("yesterday")))]
+    #[case::blocking_type(Order { nodes: vec![Node { actions: vec![Action { blocking_type: String::from("hard"), ..Action::default() }], ..Node::default() }], ..ros_order() },
+        ConversionError::UnknownValue { field: "blockingType", value: String::from("hard") })]
+    fn test_ros2_Order_try_into_Order_fails(#[case] ros: Order, #[case] expected: ConversionError) {
+        assert_that!(order::Order::try_from(ros).err(), some(eq(&expected)));
+    }
+
+    #[rstest]
+    fn test_ros2_State_round_trip() {
+        let ros = State {
+            timestamp: String::from("2017-04-15T11:40:03.120Z"),
+            operating_mode: String::from("AUTOMATIC"),
+            battery_state: BatteryState { battery_charge: 80.0, battery_health: -1, ..BatteryState::default() },
+            action_states: vec![ActionState { action_id: String::from("a1"), action_status: String::from("RUNNING"), ..ActionState::default() }],
+            safety_state: SafetyState { e_stop: String::from("NONE"), field_violation: false },
+            ..State::default()
+        };
+
+        let state = state::State::try_from(ros.clone()).unwrap();
+
+        assert_that!(state, matches_pattern!(state::State {
+            agv_position: none(),
+            velocity: none(),
+            battery_state: matches_pattern!(state::BatteryState { battery_health: none() }),
+            action_states: elements_are![matches_pattern!(state::ActionState { action_status: matches_pattern!(state::ActionStatus::Running) })],
+        }));
+        assert_that!(State::from(state), matches_pattern!(State {
+            operating_mode: eq("AUTOMATIC"),
+            action_states: eq(&ros.action_states),
+            safety_state: eq(&ros.safety_state),
+        }));
+    }
+}
@@ -0,0 +1,278 @@
+//!
+//! Traversal of the nodes and edges of an order along their sequence ids and lookups by their ids.
+//!
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::order::{Edge, Node, Order};
+
+impl Order {
+
+    /// Returns the nodes and edges of the order in the sequence they are traversed, i.e. ordered by their sequence ids.
+    ///
+    /// The route has to start with a node, alternate between nodes and edges and end with a node, where each edge
+    /// starts at the preceding node and ends at the succeeding node. Otherwise, the route yields a [`RouteError`] at
+    /// the first element breaking these rules and ends.
+    pub fn route(&self) -> Route<'_> {
+        let mut elements = self.nodes.iter().map(RouteElement::Node)
+            .chain(self.edges.iter().map(RouteElement::Edge))
+            .collect::<Vec<_>>();
+        elements.sort_by_key(RouteElement::sequence_id);
+        Route { elements: elements.into_iter(), previous: None, failed: false }
+    }
+
+    /// Returns the first node with the id `node_id`. A node passed more than once is contained with each of its
+    /// sequence ids, see [`Order::element_by_sequence_id`].
+    ///
+    /// Scans the nodes linearly, use [`Order::index`] for repeated lookups in large orders.
+    pub fn node_by_id(&self, node_id: &str) -> Option<&Node> {
+        self.nodes.iter().find(|node| node.node_id == node_id)
+    }
+
+    /// Returns the first edge with the id `edge_id`.
+    ///
+    /// Scans the edges linearly, use [`Order::index`] for repeated lookups in large orders.
+    pub fn edge_by_id(&self, edge_id: &str) -> Option<&Edge> {
+        self.edges.iter().find(|edge| edge.edge_id == edge_id)
+    }
+
+    /// Returns the node or edge with the sequence id `sequence_id`.
+    ///
+    /// Scans the nodes and edges linearly, use [`Order::index`] for repeated lookups in large orders.
+    pub fn element_by_sequence_id(&self, sequence_id: u64) -> Option<RouteElement<'_>> {
+        self.nodes.iter().find(|node| node.sequence_id == sequence_id).map(RouteElement::Node)
+            .or_else(|| self.edges.iter().find(|edge| edge.sequence_id == sequence_id).map(RouteElement::Edge))
+    }
+
+    /// Returns an index of the nodes and edges of the order by their ids and sequence ids.
+    pub fn index(&self) -> OrderIndex<'_> {
+        OrderIndex::new(self)
+    }
+}
+
+/// Index of the nodes and edges of an [`Order`] by their ids and sequence ids, see [`Order::index`].
+///
+/// The lookups behave like the ones of [`Order`], but take logarithmic instead of linear time.
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct OrderIndex<'a> {
+    nodes: BTreeMap<&'a str, &'a Node>,
+    edges: BTreeMap<&'a str, &'a Edge>,
+    elements: BTreeMap<u64, RouteElement<'a>>,
+}
+
+impl<'a> OrderIndex<'a> {
+
+    /// Returns the index of the nodes and edges of `order`.
+    pub fn new(order: &'a Order) -> Self {
+        let mut index = OrderIndex { nodes: BTreeMap::new(), edges: BTreeMap::new(), elements: BTreeMap::new() };
+        for node in &order.nodes {
+            index.nodes.entry(&node.node_id).or_insert(node);
+            index.elements.entry(node.sequence_id).or_insert(RouteElement::Node(node));
+        }
+        for edge in &order.edges {
+            index.edges.entry(&edge.edge_id).or_insert(edge);
+            index.elements.entry(edge.sequence_id).or_insert(RouteElement::Edge(edge));
+        }
+        index
+    }
+
+    /// Returns the first node with the id `node_id`, see [`Order::node_by_id`].
+    pub fn node_by_id(&self, node_id: &str) -> Option<&'a Node> {
+        self.nodes.get(node_id).copied()
+    }
+
+    /// Returns the first edge with the id `edge_id`, see [`Order::edge_by_id`].
+    pub fn edge_by_id(&self, edge_id: &str) -> Option<&'a Edge> {
+        self.edges.get(edge_id).copied()
+    }
+
+    /// Returns the node or edge with the sequence id `sequence_id`, see [`Order::element_by_sequence_id`].
+    pub fn element_by_sequence_id(&self, sequence_id: u64) -> Option<RouteElement<'a>> {
+        self.elements.get(&sequence_id).copied()
+    }
+}
+
+/// A node or edge of the route of an order, see [`Order::route`].
+#[derive(Clone, Copy)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum RouteElement<'a> {
+    /// A node of the order.
+    Node(&'a Node),
+    /// An edge of the order.
+    Edge(&'a Edge),
+}
+
+impl RouteElement<'_> {
+
+    /// Returns the sequence id of the node or edge.
+    pub fn sequence_id(&self) -> u64 {
+        match self {
+            RouteElement::Node(node) => node.sequence_id,
+            RouteElement::Edge(edge) => edge.sequence_id,
+        }
+    }
+
+    /// Returns `true` if the node or edge is part of the base.
+    pub fn released(&self) -> bool {
+        match self {
+            RouteElement::Node(node) => node.released,
+            RouteElement::Edge(edge) => edge.released,
+        }
+    }
+}
+
+/// Error yielded by a [`Route`] whose nodes and edges do not form a path.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum RouteError {
+    /// The node or edge with the sequence id follows a node or edge of the same kind, or the route starts with it
+    /// although it is an edge.
+    NotAlternating(u64),
+    /// The edge with the sequence id does not start at the preceding node, or the node with the sequence id is not the
+    /// end node of the preceding edge.
+    Disconnected(u64),
+    /// The route ends with the edge with the sequence id instead of a node.
+    EndsWithEdge(u64),
+}
+
+impl core::fmt::Display for RouteError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RouteError::NotAlternating(sequence_id) => write!(formatter, "element {sequence_id} does not alternate between nodes and edges"),
+            RouteError::Disconnected(sequence_id) => write!(formatter, "element {sequence_id} is not connected to its predecessor"),
+            RouteError::EndsWithEdge(sequence_id) => write!(formatter, "route ends with edge {sequence_id}"),
+        }
+    }
+}
+
+/// Iterator over the nodes and edges of an order in the sequence they are traversed, see [`Order::route`].
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct Route<'a> {
+    elements: alloc::vec::IntoIter<RouteElement<'a>>,
+    previous: Option<RouteElement<'a>>,
+    failed: bool,
+}
+
+impl<'a> Route<'a> {
+
+    fn check(&self, element: &RouteElement<'a>) -> Result<(), RouteError> {
+        match (self.previous, element) {
+            (None, RouteElement::Node(_)) => Ok(()),
+            (Some(RouteElement::Node(node)), RouteElement::Edge(edge)) if node.node_id == edge.start_node_id => Ok(()),
+            (Some(RouteElement::Edge(edge)), RouteElement::Node(node)) if node.node_id == edge.end_node_id => Ok(()),
+            (Some(RouteElement::Node(_)), RouteElement::Edge(_)) | (Some(RouteElement::Edge(_)), RouteElement::Node(_)) => Err(RouteError::Disconnected(element.sequence_id())),
+            _ => Err(RouteError::NotAlternating(element.sequence_id())),
+        }
+    }
+}
+
+impl<'a> Iterator for Route<'a> {
+    type Item = Result<RouteElement<'a>, RouteError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let result = match self.elements.next() {
+            Some(element) => self.check(&element).map(|_| element),
+            None => match self.previous.take() {
+                Some(RouteElement::Edge(edge)) => Err(RouteError::EndsWithEdge(edge.sequence_id)),
+                _ => return None,
+            },
+        };
+        match result {
+            Ok(element) => self.previous = Some(element),
+            Err(_) => self.failed = true,
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::order::Order;
+    use crate::timestamp::from_unix_millis;
+    use super::{RouteElement, RouteError};
+
+    fn order() -> Order {
+        Order::builder("o1", from_unix_millis(0).unwrap())
+            .node("n1")
+            .edge_to("e1", "n2")
+            .horizon_node("e2", "n3")
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_Order_route_interleaves_nodes_and_edges() {
+        let mut order = order();
+        order.nodes.reverse();
+
+        let route = order.route()
+            .map(|element| element.map(|element| match element {
+                RouteElement::Node(node) => (node.node_id.as_str(), element.released()),
+                RouteElement::Edge(edge) => (edge.edge_id.as_str(), element.released()),
+            }))
+            .collect::<core::result::Result<Vec<_>, _>>();
+
+        assert_that!(route, ok(eq(&vec![("n1", true), ("e1", true), ("n2", true), ("e2", false), ("n3", false)])));
+    }
+
+    #[rstest]
+    fn test_Order_route_of_empty_order_is_empty() {
+        let order = Order { nodes: vec![], edges: vec![], ..order() };
+
+        assert_that!(order.route().count(), eq(0));
+    }
+
+    #[rstest]
+    #[case::starts_with_edge(|order: &mut Order| { order.nodes.remove(0); }, RouteError::NotAlternating(1))]
+    #[case::consecutive_nodes(|order: &mut Order| { order.edges.remove(0); }, RouteError::NotAlternating(2))]
+    #[case::edge_from_other_node(|order: &mut Order| { order.edges[1].start_node_id = "n1".into(); }, RouteError::Disconnected(3))]
+    #[case::edge_to_other_node(|order: &mut Order| { order.edges[0].end_node_id = "n3".into(); }, RouteError::Disconnected(2))]
+    #[case::ends_with_edge(|order: &mut Order| { order.nodes.pop(); }, RouteError::EndsWithEdge(3))]
+    fn test_Order_route_ends_at_first_error(#[case] modify: fn(&mut Order), #[case] expected: RouteError) {
+        let mut order = order();
+        modify(&mut order);
+
+        let route = order.route().collect::<Vec<_>>();
+
+        assert_that!(route.last(), some(err(eq(&expected))));
+        assert_that!(route.iter().filter(|element| element.is_err()).count(), eq(1));
+    }
+
+    #[rstest]
+    fn test_Order_lookups_match_OrderIndex() {
+        let order = Order::builder("o1", from_unix_millis(0).unwrap())
+            .node("n1")
+            .edge_to("e1", "n2")
+            .edge_to("e2", "n1")
+            .build()
+            .unwrap();
+        let index = order.index();
+
+        for node_id in ["n1", "n2", "n3"] {
+            let expected = order.node_by_id(node_id).map(|node| node.sequence_id);
+            assert_that!(index.node_by_id(node_id).map(|node| node.sequence_id), eq(expected));
+        }
+        for edge_id in ["e1", "e2", "e3"] {
+            let expected = order.edge_by_id(edge_id).map(|edge| edge.sequence_id);
+            assert_that!(index.edge_by_id(edge_id).map(|edge| edge.sequence_id), eq(expected));
+        }
+        for sequence_id in 0..6 {
+            let expected = order.element_by_sequence_id(sequence_id).map(|element| element.sequence_id());
+            assert_that!(index.element_by_sequence_id(sequence_id).map(|element| element.sequence_id()), eq(expected));
+        }
+        assert_that!(order.node_by_id("n1").map(|node| node.sequence_id), some(eq(0)));
+        assert_that!(order.element_by_sequence_id(3), some(matches_pattern!(RouteElement::Edge(anything()))));
+        assert_that!(order.element_by_sequence_id(5), none());
+    }
+}
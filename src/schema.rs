@@ -0,0 +1,122 @@
+//!
+//! Validation of raw messages against the JSON schemas of their topic, e.g. to reject non-conforming messages of
+//! third-party AGVs in a gateway with the messages of the schema rather than those of serde.
+//!
+use std::sync::OnceLock;
+
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::validation::{RULE_SCHEMA, Violation};
+
+/// Precompiled JSON schema of a topic, see [`order`], [`instant_actions`], [`state`], [`connection`], [`factsheet`] and
+/// [`visualization`].
+///
+/// Like the schemas published with the specification, they reject `null` for absent optional fields.
+#[derive(Clone)]
+pub struct SchemaValidator {
+    validator: jsonschema::Validator,
+}
+
+impl SchemaValidator {
+
+    fn compile(schema: &str) -> Self {
+        let schema = serde_json::from_str(schema).expect("bundled JSON schema to be valid JSON");
+        let validator = jsonschema::validator_for(&schema).expect("bundled JSON schema to be a valid JSON schema");
+        Self { validator }
+    }
+
+    /// Validates the raw message `bytes` against the schema.
+    ///
+    /// Returns a violation of [`RULE_SCHEMA`] per error reported by the schema, located at the JSON pointer of the
+    /// offending value, or a single violation located at the root if `bytes` is not valid JSON.
+    pub fn validate(&self, bytes: &[u8]) -> Vec<Violation> {
+        match serde_json::from_slice(bytes) {
+            Ok(value) => self.validate_value(&value),
+            Err(error) => vec![Violation { path: "".into(), rule: RULE_SCHEMA, message: error.to_string() }],
+        }
+    }
+
+    /// Validates the already parsed message `value` against the schema.
+    pub fn validate_value(&self, value: &serde_json::Value) -> Vec<Violation> {
+        self.validator.iter_errors(value)
+            .map(|error| Violation { path: error.instance_path().as_str().into(), rule: RULE_SCHEMA, message: error.to_string() })
+            .collect()
+    }
+
+    /// Returns `true` if the raw message `bytes` is valid JSON conforming to the schema.
+    pub fn is_valid(&self, bytes: &[u8]) -> bool {
+        serde_json::from_slice(bytes).is_ok_and(|value| self.validator.is_valid(&value))
+    }
+}
+
+macro_rules! schemas {
+    ($($(#[$doc:meta])* $name:ident => $file:literal;)*) => {
+        $(
+            $(#[$doc])*
+            pub fn $name() -> &'static SchemaValidator {
+                static VALIDATOR: OnceLock<SchemaValidator> = OnceLock::new();
+                VALIDATOR.get_or_init(|| SchemaValidator::compile(include_str!(concat!("../schemas/", $file))))
+            }
+        )*
+    };
+}
+
+schemas! {
+    /// Returns the validator of messages on the `order` topic, compiled on first use.
+    order => "order.schema";
+    /// Returns the validator of messages on the `instantActions` topic, compiled on first use.
+    instant_actions => "instantActions.schema";
+    /// Returns the validator of messages on the `state` topic, compiled on first use.
+    state => "state.schema";
+    /// Returns the validator of messages on the `connection` topic, compiled on first use.
+    connection => "connection.schema";
+    /// Returns the validator of messages on the `factsheet` topic, compiled on first use.
+    factsheet => "factsheet.schema";
+    /// Returns the validator of messages on the `visualization` topic, compiled on first use.
+    visualization => "visualization.schema";
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::format;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::validation::RULE_SCHEMA;
+    use super::{connection, order, SchemaValidator};
+
+    const HEADER: &str = r#""headerId": 1, "timestamp": "2017-04-15T11:40:03.12Z", "version": "2.0.0", "manufacturer": "Fubar Co.", "serialNumber": "1234""#;
+
+    #[rstest]
+    fn test_SchemaValidator_accepts_conforming_message() {
+        let message = format!(r#"{{ {HEADER}, "connectionState": "ONLINE" }}"#);
+
+        assert_that!(connection().validate(message.as_bytes()), empty());
+        assert_that!(connection().is_valid(message.as_bytes()), eq(true));
+    }
+
+    #[rstest]
+    #[case::unknown_enum_value(connection(), r#""connectionState": "ASLEEP""#, "/connectionState")]
+    #[case::missing_field(connection(), r#""foo": 1"#, "")]
+    #[case::negative_sequence_id(order(), r#""orderId": "o1", "orderUpdateId": 0, "edges": [], "nodes": [{ "nodeId": "n1", "sequenceId": -2, "released": true, "actions": [] }]"#, "/nodes/0/sequenceId")]
+    fn test_SchemaValidator_reports_violation(#[case] validator: &SchemaValidator, #[case] fields: &str, #[case] path: &str) {
+        let message = format!(r#"{{ {HEADER}, {fields} }}"#);
+
+        let violations = validator.validate(message.as_bytes());
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![(path, RULE_SCHEMA)]));
+    }
+
+    #[rstest]
+    fn test_SchemaValidator_reports_invalid_json() {
+        let violations = order().validate(b"{ \"headerId\": ");
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![("", RULE_SCHEMA)]));
+        assert_that!(order().is_valid(b"{ \"headerId\": "), eq(false));
+    }
+}
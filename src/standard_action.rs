@@ -0,0 +1,749 @@
+//!
+//! Construction of the predefined actions of the VDA 5050 with the parameters and blocking types of the specification.
+//!
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::action::{Action, ActionParameter, ActionParameterValue, BlockingType};
+use crate::finite::{Double, RawDouble};
+use crate::header::Topic;
+use crate::id::{ActionId, IdFactory};
+use crate::state::{ActionState, ActionStatus, State};
+
+/// Parameters of the actions `pick` and `drop`.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct LoadHandlingParameters {
+    /// Load handling device (`lhd`) to use, if the AGV has more than one.
+    pub lhd: Option<String>,
+    /// Type of the station (`stationType`), e.g. `floor` or `rack`.
+    pub station_type: String,
+    /// Name of the station (`stationName`).
+    pub station_name: Option<String>,
+    /// Type of the load (`loadType`), e.g. `EPAL`.
+    pub load_type: String,
+    /// Identification of the load (`loadId`).
+    pub load_id: Option<String>,
+    /// Height of the load handling device in meters (`height`).
+    pub height: Option<Double>,
+    /// Depth of the load handling device in meters (`depth`), e.g. of the forks of a forklift.
+    pub depth: Option<Double>,
+    /// Side of the station (`side`), e.g. `left` or `right`.
+    pub side: Option<String>,
+}
+
+impl LoadHandlingParameters {
+
+    /// Returns the parameters of handling a load of `load_type` at a station of `station_type`.
+    pub fn new(station_type: impl Into<String>, load_type: impl Into<String>) -> Self {
+        LoadHandlingParameters {
+            lhd: None,
+            station_type: station_type.into(),
+            station_name: None,
+            load_type: load_type.into(),
+            load_id: None,
+            height: None,
+            depth: None,
+            side: None,
+        }
+    }
+
+    fn into_parameters(self) -> Vec<ActionParameter> {
+        let mut parameters = Vec::new();
+        push(&mut parameters, "lhd", self.lhd.map(ActionParameterValue::String));
+        push(&mut parameters, "stationType", Some(ActionParameterValue::String(self.station_type)));
+        push(&mut parameters, "stationName", self.station_name.map(ActionParameterValue::String));
+        push(&mut parameters, "loadType", Some(ActionParameterValue::String(self.load_type)));
+        push(&mut parameters, "loadId", self.load_id.map(ActionParameterValue::String));
+        push(&mut parameters, "height", self.height.map(ActionParameterValue::Float));
+        push(&mut parameters, "depth", self.depth.map(ActionParameterValue::Float));
+        push(&mut parameters, "side", self.side.map(ActionParameterValue::String));
+        parameters
+    }
+}
+
+/// Parameters of the action `initPosition`, whose coordinates are floating point numbers and whose map and node are
+/// strings.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct InitPositionParameters {
+    /// Position in x direction on the map in meters (`x`).
+    pub x: Double,
+    /// Position in y direction on the map in meters (`y`).
+    pub y: Double,
+    /// Orientation on the map in radians (`theta`).
+    pub theta: Double,
+    /// Map on which the position is given (`mapId`).
+    pub map_id: String,
+    /// Node at which the AGV is located (`lastNodeId`).
+    pub last_node_id: String,
+}
+
+impl InitPositionParameters {
+
+    /// Returns the action `initPosition` with id `action_id` and blocking type `HARD`.
+    pub fn into_action(self, action_id: impl Into<ActionId>) -> Action {
+        standard("initPosition", action_id, BlockingType::Hard, alloc::vec![
+            parameter("x", ActionParameterValue::Float(self.x)),
+            parameter("y", ActionParameterValue::Float(self.y)),
+            parameter("theta", ActionParameterValue::Float(self.theta)),
+            parameter("mapId", ActionParameterValue::String(self.map_id)),
+            parameter("lastNodeId", ActionParameterValue::String(self.last_node_id)),
+        ])
+    }
+}
+
+impl TryFrom<&Action> for InitPositionParameters {
+    type Error = StandardActionError;
+
+    /// Returns the parameters of the action `initPosition`, of which the coordinates may be given as integers.
+    fn try_from(action: &Action) -> Result<Self, Self::Error> {
+        match StandardAction::try_from(action)? {
+            StandardAction::InitPosition(parameters) => Ok(parameters),
+            _ => Err(StandardActionError::UnexpectedActionType(action.action_type.clone())),
+        }
+    }
+}
+
+/// Instant action `stateRequest`, to which the AGV responds with a message on the topic `state`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct StateRequest;
+
+impl StateRequest {
+
+    /// Topic of the message with which the AGV responds.
+    pub const RESPONSE_TOPIC: Topic = Topic::State;
+
+    /// Returns the action `stateRequest` with id `action_id` and blocking type `NONE`.
+    pub fn into_action(self, action_id: impl Into<ActionId>) -> Action {
+        standard("stateRequest", action_id, BlockingType::None, Vec::new())
+    }
+}
+
+impl TryFrom<&Action> for StateRequest {
+    type Error = StandardActionError;
+
+    fn try_from(action: &Action) -> Result<Self, Self::Error> {
+        match StandardAction::try_from(action)? {
+            StandardAction::StateRequest => Ok(StateRequest),
+            _ => Err(StandardActionError::UnexpectedActionType(action.action_type.clone())),
+        }
+    }
+}
+
+/// Instant action `factsheetRequest`, to which the AGV responds with a message on the topic `factsheet`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct FactsheetRequest;
+
+impl FactsheetRequest {
+
+    /// Topic of the message with which the AGV responds.
+    pub const RESPONSE_TOPIC: Topic = Topic::Factsheet;
+
+    /// Returns the action `factsheetRequest` with id `action_id` and blocking type `NONE`.
+    pub fn into_action(self, action_id: impl Into<ActionId>) -> Action {
+        standard("factsheetRequest", action_id, BlockingType::None, Vec::new())
+    }
+}
+
+impl TryFrom<&Action> for FactsheetRequest {
+    type Error = StandardActionError;
+
+    fn try_from(action: &Action) -> Result<Self, Self::Error> {
+        match StandardAction::try_from(action)? {
+            StandardAction::FactsheetRequest => Ok(FactsheetRequest),
+            _ => Err(StandardActionError::UnexpectedActionType(action.action_type.clone())),
+        }
+    }
+}
+
+/// Instant action `logReport`, which requests the AGV to generate and store a log report. The AGV reports the name of
+/// the stored report as result description of the finished action.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct LogReport {
+    /// Reason of the report (`reason`).
+    pub reason: String,
+}
+
+impl LogReport {
+
+    /// Returns the request of a log report for `reason`.
+    pub fn new(reason: impl Into<String>) -> Self {
+        LogReport { reason: reason.into() }
+    }
+
+    /// Returns the action `logReport` with id `action_id` and blocking type `NONE`.
+    pub fn into_action(self, action_id: impl Into<ActionId>) -> Action {
+        standard("logReport", action_id, BlockingType::None, alloc::vec![
+            parameter("reason", ActionParameterValue::String(self.reason)),
+        ])
+    }
+
+    /// Returns the name of the stored report, which is the result description of the action once it is finished.
+    pub fn report_name(action_state: &ActionState) -> Option<&str> {
+        match action_state.action_status {
+            ActionStatus::Finished => action_state.result_description.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<&Action> for LogReport {
+    type Error = StandardActionError;
+
+    fn try_from(action: &Action) -> Result<Self, Self::Error> {
+        match StandardAction::try_from(action)? {
+            StandardAction::LogReport { reason } => Ok(LogReport { reason }),
+            _ => Err(StandardActionError::UnexpectedActionType(action.action_type.clone())),
+        }
+    }
+}
+
+impl Action {
+
+    /// Returns the instant action `startPause` with blocking type `HARD`, which pauses the AGV.
+    pub fn start_pause(action_id: impl Into<ActionId>) -> Self {
+        standard("startPause", action_id, BlockingType::Hard, Vec::new())
+    }
+
+    /// Returns the instant action `stopPause` with blocking type `HARD`, which resumes the AGV.
+    pub fn stop_pause(action_id: impl Into<ActionId>) -> Self {
+        standard("stopPause", action_id, BlockingType::Hard, Vec::new())
+    }
+
+    /// Returns the action `startCharging` with blocking type `HARD`.
+    pub fn start_charging(action_id: impl Into<ActionId>) -> Self {
+        standard("startCharging", action_id, BlockingType::Hard, Vec::new())
+    }
+
+    /// Returns the action `stopCharging` with blocking type `HARD`.
+    pub fn stop_charging(action_id: impl Into<ActionId>) -> Self {
+        standard("stopCharging", action_id, BlockingType::Hard, Vec::new())
+    }
+
+    /// Returns the instant action `cancelOrder` with blocking type `NONE`, which cancels the current order.
+    pub fn cancel_order(action_id: impl Into<ActionId>) -> Self {
+        standard("cancelOrder", action_id, BlockingType::None, Vec::new())
+    }
+
+    /// Returns the instant action `stateRequest` with blocking type `NONE`, which requests a state message.
+    pub fn state_request(action_id: impl Into<ActionId>) -> Self {
+        StateRequest.into_action(action_id)
+    }
+
+    /// Returns the instant action `factsheetRequest` with blocking type `NONE`, which requests the factsheet.
+    pub fn factsheet_request(action_id: impl Into<ActionId>) -> Self {
+        FactsheetRequest.into_action(action_id)
+    }
+
+    /// Returns the instant action `logReport` with blocking type `NONE`, which requests a log report for `reason`.
+    pub fn log_report(action_id: impl Into<ActionId>, reason: impl Into<String>) -> Self {
+        LogReport::new(reason).into_action(action_id)
+    }
+
+    /// Returns the action `initPosition` with blocking type `HARD`, which resets the position of the AGV.
+    pub fn init_position(action_id: impl Into<ActionId>, parameters: InitPositionParameters) -> Self {
+        parameters.into_action(action_id)
+    }
+
+    /// Returns the action `pick` with blocking type `HARD`, which picks up a load.
+    pub fn pick(action_id: impl Into<ActionId>, parameters: LoadHandlingParameters) -> Self {
+        standard("pick", action_id, BlockingType::Hard, parameters.into_parameters())
+    }
+
+    /// Returns the action `drop` with blocking type `HARD`, which drops a load.
+    pub fn drop(action_id: impl Into<ActionId>, parameters: LoadHandlingParameters) -> Self {
+        standard("drop", action_id, BlockingType::Hard, parameters.into_parameters())
+    }
+
+    /// Returns the action `detectObject` with blocking type `NONE`, which detects an object of `object_type`, if
+    /// given.
+    pub fn detect_object(action_id: impl Into<ActionId>, object_type: Option<String>) -> Self {
+        let mut parameters = Vec::new();
+        push(&mut parameters, "objectType", object_type.map(ActionParameterValue::String));
+        standard("detectObject", action_id, BlockingType::None, parameters)
+    }
+
+    /// Returns the action `finePositioning` with blocking type `NONE`, which positions the AGV exactly at a station
+    /// of `station_type` named `station_name`, if given.
+    pub fn fine_positioning(action_id: impl Into<ActionId>, station_type: Option<String>, station_name: Option<String>) -> Self {
+        let mut parameters = Vec::new();
+        push(&mut parameters, "stationType", station_type.map(ActionParameterValue::String));
+        push(&mut parameters, "stationName", station_name.map(ActionParameterValue::String));
+        standard("finePositioning", action_id, BlockingType::None, parameters)
+    }
+
+    /// Returns the action `waitForTrigger` with blocking type `HARD`, which waits for a trigger of `trigger_type`.
+    pub fn wait_for_trigger(action_id: impl Into<ActionId>, trigger_type: impl Into<String>) -> Self {
+        standard("waitForTrigger", action_id, BlockingType::Hard, alloc::vec![
+            parameter("triggerType", ActionParameterValue::String(trigger_type.into())),
+        ])
+    }
+}
+
+/// Generator of action ids unique among the actions of a master control, e.g. `mc-1`, `mc-2`, … for the prefix `mc`.
+#[derive(Clone)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct ActionIdGenerator {
+    prefix: String,
+    next: u64,
+}
+
+impl ActionIdGenerator {
+
+    /// Creates a generator of the action ids `prefix-1`, `prefix-2`, …
+    pub fn new(prefix: impl Into<String>) -> Self {
+        ActionIdGenerator { prefix: prefix.into(), next: 1 }
+    }
+
+    /// Returns the next action id.
+    pub fn next_id(&mut self) -> String {
+        let action_id = alloc::format!("{}-{}", self.prefix, self.next);
+        self.next += 1;
+        action_id
+    }
+
+    /// Returns the instant action `cancelOrder` with the next action id, see [`Action::cancel_order`].
+    pub fn cancel_order(&mut self) -> Action {
+        Action::cancel_order(self.next_id())
+    }
+
+    /// Returns the instant action `startPause` with the next action id, see [`Action::start_pause`].
+    pub fn start_pause(&mut self) -> Action {
+        Action::start_pause(self.next_id())
+    }
+
+    /// Returns the instant action `stopPause` with the next action id, see [`Action::stop_pause`].
+    pub fn stop_pause(&mut self) -> Action {
+        Action::stop_pause(self.next_id())
+    }
+}
+
+impl IdFactory for ActionIdGenerator {
+    fn next_id(&mut self) -> String {
+        ActionIdGenerator::next_id(self)
+    }
+}
+
+/// Change of the state the master control is to expect after the AGV finished an instant action.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum ExpectedStateChange {
+    /// After `cancelOrder`, the AGV stands still and reports neither node states nor edge states, while the actions of
+    /// the cancelled order are reported as failed.
+    OrderCancelled,
+    /// After `startPause`, the AGV stands still and reports `paused`.
+    Paused,
+    /// After `stopPause`, the AGV no longer reports `paused`.
+    Resumed,
+}
+
+impl ExpectedStateChange {
+
+    /// Returns the change of the state expected after `action`, if it is `cancelOrder`, `startPause` or `stopPause`.
+    pub fn of(action: &Action) -> Option<Self> {
+        match action.action_type.as_str() {
+            "cancelOrder" => Some(ExpectedStateChange::OrderCancelled),
+            "startPause" => Some(ExpectedStateChange::Paused),
+            "stopPause" => Some(ExpectedStateChange::Resumed),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `state` reflects the change.
+    pub fn is_reached(&self, state: &State) -> bool {
+        match self {
+            ExpectedStateChange::OrderCancelled => !state.driving && state.node_states.is_empty() && state.edge_states.is_empty(),
+            ExpectedStateChange::Paused => !state.driving && state.paused == Some(true),
+            ExpectedStateChange::Resumed => state.paused != Some(true),
+        }
+    }
+}
+
+/// Predefined action of the VDA 5050 with typed parameters, see [`StandardAction::try_from`].
+#[derive(Clone, PartialEq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum StandardAction {
+    /// `startPause`
+    StartPause,
+    /// `stopPause`
+    StopPause,
+    /// `startCharging`
+    StartCharging,
+    /// `stopCharging`
+    StopCharging,
+    /// `initPosition`
+    InitPosition(InitPositionParameters),
+    /// `stateRequest`
+    StateRequest,
+    /// `logReport`
+    LogReport {
+        /// Reason of the log report.
+        reason: String,
+    },
+    /// `pick`
+    Pick(LoadHandlingParameters),
+    /// `drop`
+    Drop(LoadHandlingParameters),
+    /// `detectObject`
+    DetectObject {
+        /// Type of the object to detect.
+        object_type: Option<String>,
+    },
+    /// `finePositioning`
+    FinePositioning {
+        /// Type of the station to position at.
+        station_type: Option<String>,
+        /// Name of the station to position at.
+        station_name: Option<String>,
+    },
+    /// `waitForTrigger`
+    WaitForTrigger {
+        /// Type of the trigger to wait for.
+        trigger_type: String,
+    },
+    /// `cancelOrder`
+    CancelOrder,
+    /// `factsheetRequest`
+    FactsheetRequest,
+}
+
+impl StandardAction {
+
+    /// Returns the action type, e.g. `cancelOrder`.
+    pub fn action_type(&self) -> &'static str {
+        match self {
+            StandardAction::StartPause => "startPause",
+            StandardAction::StopPause => "stopPause",
+            StandardAction::StartCharging => "startCharging",
+            StandardAction::StopCharging => "stopCharging",
+            StandardAction::InitPosition(_) => "initPosition",
+            StandardAction::StateRequest => "stateRequest",
+            StandardAction::LogReport { .. } => "logReport",
+            StandardAction::Pick(_) => "pick",
+            StandardAction::Drop(_) => "drop",
+            StandardAction::DetectObject { .. } => "detectObject",
+            StandardAction::FinePositioning { .. } => "finePositioning",
+            StandardAction::WaitForTrigger { .. } => "waitForTrigger",
+            StandardAction::CancelOrder => "cancelOrder",
+            StandardAction::FactsheetRequest => "factsheetRequest",
+        }
+    }
+
+    /// Returns the action `action_id` with the parameters of the standard action and the blocking type of the
+    /// specification, see the constructors of [`Action`], e.g. [`Action::pick`].
+    pub fn into_action(self, action_id: impl Into<ActionId>) -> Action {
+        match self {
+            StandardAction::StartPause => Action::start_pause(action_id),
+            StandardAction::StopPause => Action::stop_pause(action_id),
+            StandardAction::StartCharging => Action::start_charging(action_id),
+            StandardAction::StopCharging => Action::stop_charging(action_id),
+            StandardAction::InitPosition(parameters) => Action::init_position(action_id, parameters),
+            StandardAction::StateRequest => Action::state_request(action_id),
+            StandardAction::LogReport { reason } => Action::log_report(action_id, reason),
+            StandardAction::Pick(parameters) => Action::pick(action_id, parameters),
+            StandardAction::Drop(parameters) => Action::drop(action_id, parameters),
+            StandardAction::DetectObject { object_type } => Action::detect_object(action_id, object_type),
+            StandardAction::FinePositioning { station_type, station_name } => Action::fine_positioning(action_id, station_type, station_name),
+            StandardAction::WaitForTrigger { trigger_type } => Action::wait_for_trigger(action_id, trigger_type),
+            StandardAction::CancelOrder => Action::cancel_order(action_id),
+            StandardAction::FactsheetRequest => Action::factsheet_request(action_id),
+        }
+    }
+}
+
+impl TryFrom<&Action> for StandardAction {
+    type Error = StandardActionError;
+
+    /// Returns the standard action of the action type of `action` with its parameters, of which parameters not
+    /// defined for the action type are ignored and floating point parameters may be given as integers.
+    fn try_from(action: &Action) -> Result<Self, Self::Error> {
+        let parameters = Parameters(&action.action_parameters);
+        Ok(match action.action_type.as_str() {
+            "startPause" => StandardAction::StartPause,
+            "stopPause" => StandardAction::StopPause,
+            "startCharging" => StandardAction::StartCharging,
+            "stopCharging" => StandardAction::StopCharging,
+            "initPosition" => StandardAction::InitPosition(InitPositionParameters {
+                x: parameters.required("x", Parameters::double)?,
+                y: parameters.required("y", Parameters::double)?,
+                theta: parameters.required("theta", Parameters::double)?,
+                map_id: parameters.required("mapId", Parameters::string)?,
+                last_node_id: parameters.required("lastNodeId", Parameters::string)?,
+            }),
+            "stateRequest" => StandardAction::StateRequest,
+            "logReport" => StandardAction::LogReport { reason: parameters.required("reason", Parameters::string)? },
+            "pick" => StandardAction::Pick(parameters.load_handling()?),
+            "drop" => StandardAction::Drop(parameters.load_handling()?),
+            "detectObject" => StandardAction::DetectObject { object_type: parameters.optional("objectType", Parameters::string)? },
+            "finePositioning" => StandardAction::FinePositioning {
+                station_type: parameters.optional("stationType", Parameters::string)?,
+                station_name: parameters.optional("stationName", Parameters::string)?,
+            },
+            "waitForTrigger" => StandardAction::WaitForTrigger { trigger_type: parameters.required("triggerType", Parameters::string)? },
+            "cancelOrder" => StandardAction::CancelOrder,
+            "factsheetRequest" => StandardAction::FactsheetRequest,
+            other => return Err(StandardActionError::UnknownActionType(other.into())),
+        })
+    }
+}
+
+impl TryFrom<Action> for StandardAction {
+    type Error = StandardActionError;
+
+    fn try_from(action: Action) -> Result<Self, Self::Error> {
+        StandardAction::try_from(&action)
+    }
+}
+
+/// Error returned when an action is not a valid standard action.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum StandardActionError {
+    /// The action type is not defined by the specification.
+    UnknownActionType(String),
+    /// The action type differs from the one expected, e.g. of [`InitPositionParameters`].
+    UnexpectedActionType(String),
+    /// A parameter required by the action type is missing.
+    MissingParameter(&'static str),
+    /// The value of a parameter does not match its type, e.g. a string for a floating point parameter.
+    InvalidParameter(&'static str),
+}
+
+impl core::fmt::Display for StandardActionError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StandardActionError::UnknownActionType(action_type) => write!(formatter, "unknown action type `{action_type}`"),
+            StandardActionError::UnexpectedActionType(action_type) => write!(formatter, "unexpected action type `{action_type}`"),
+            StandardActionError::MissingParameter(key) => write!(formatter, "missing parameter `{key}`"),
+            StandardActionError::InvalidParameter(key) => write!(formatter, "invalid value of parameter `{key}`"),
+        }
+    }
+}
+
+struct Parameters<'a>(&'a [ActionParameter]);
+
+impl Parameters<'_> {
+
+    fn optional<T>(&self, key: &'static str, convert: fn(&ActionParameterValue) -> Option<T>) -> Result<Option<T>, StandardActionError> {
+        match self.0.iter().find(|parameter| parameter.key == key) {
+            None | Some(ActionParameter { value: ActionParameterValue::Null, .. }) => Ok(None),
+            Some(parameter) => convert(&parameter.value).map(Some).ok_or(StandardActionError::InvalidParameter(key)),
+        }
+    }
+
+    fn required<T>(&self, key: &'static str, convert: fn(&ActionParameterValue) -> Option<T>) -> Result<T, StandardActionError> {
+        self.optional(key, convert)?.ok_or(StandardActionError::MissingParameter(key))
+    }
+
+    fn load_handling(&self) -> Result<LoadHandlingParameters, StandardActionError> {
+        Ok(LoadHandlingParameters {
+            lhd: self.optional("lhd", Parameters::string)?,
+            station_type: self.required("stationType", Parameters::string)?,
+            station_name: self.optional("stationName", Parameters::string)?,
+            load_type: self.required("loadType", Parameters::string)?,
+            load_id: self.optional("loadId", Parameters::string)?,
+            height: self.optional("height", Parameters::double)?,
+            depth: self.optional("depth", Parameters::double)?,
+            side: self.optional("side", Parameters::string)?,
+        })
+    }
+
+    fn string(value: &ActionParameterValue) -> Option<String> {
+        match value {
+            ActionParameterValue::String(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    fn double(value: &ActionParameterValue) -> Option<Double> {
+        match value {
+            ActionParameterValue::Float(value) => Some(*value),
+            ActionParameterValue::Integer(value) => Double::try_from_raw(*value as f64).ok(),
+            _ => None,
+        }
+    }
+}
+
+fn standard(action_type: &str, action_id: impl Into<ActionId>, blocking_type: BlockingType, action_parameters: Vec<ActionParameter>) -> Action {
+    Action {
+        action_type: action_type.into(),
+        action_id: action_id.into(),
+        action_description: None,
+        blocking_type,
+        action_parameters,
+    }
+}
+
+fn parameter(key: &str, value: ActionParameterValue) -> ActionParameter {
+    ActionParameter { key: key.into(), value }
+}
+
+fn push(parameters: &mut Vec<ActionParameter>, key: &str, value: Option<ActionParameterValue>) {
+    if let Some(value) = value {
+        parameters.push(parameter(key, value));
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::action::{Action, ActionParameter, ActionParameterValue, BlockingType};
+    use crate::finite::{double, Double};
+    use crate::state::{ActionState, ActionStatus, NodeState, State};
+    use crate::timestamp::from_unix_millis;
+    use super::{ActionIdGenerator, ExpectedStateChange, FactsheetRequest, InitPositionParameters, LoadHandlingParameters, LogReport, StandardAction, StandardActionError, StateRequest};
+
+    #[rstest]
+    fn test_Action_pick() {
+        let parameters = LoadHandlingParameters { height: Some(double(0.5)), ..LoadHandlingParameters::new("rack", "EPAL") };
+
+        let action = Action::pick("a1", parameters);
+
+        assert_that!(action, matches_pattern!(Action {
+            action_type: eq("pick"),
+            action_id: eq("a1"),
+            blocking_type: matches_pattern!(BlockingType::Hard),
+            action_parameters: elements_are![
+                matches_pattern!(ActionParameter { key: eq("stationType"), value: eq(&ActionParameterValue::String("rack".into())) }),
+                matches_pattern!(ActionParameter { key: eq("loadType"), value: eq(&ActionParameterValue::String("EPAL".into())) }),
+                matches_pattern!(ActionParameter { key: eq("height"), value: eq(&ActionParameterValue::Float(double(0.5))) }),
+            ]
+        }));
+    }
+
+    fn init_position_parameters() -> InitPositionParameters {
+        InitPositionParameters { x: double(1.0), y: double(2.0), theta: double(0.5), map_id: "map".into(), last_node_id: "n1".into() }
+    }
+
+    #[rstest]
+    fn test_Action_init_position() {
+        let action = Action::init_position("a1", init_position_parameters());
+
+        assert_that!(action, matches_pattern!(Action {
+            action_type: eq("initPosition"),
+            blocking_type: matches_pattern!(BlockingType::Hard),
+            action_parameters: elements_are![
+                matches_pattern!(ActionParameter { key: eq("x"), value: eq(&ActionParameterValue::Float(double(1.0))) }),
+                matches_pattern!(ActionParameter { key: eq("y") }),
+                matches_pattern!(ActionParameter { key: eq("theta") }),
+                matches_pattern!(ActionParameter { key: eq("mapId"), value: eq(&ActionParameterValue::String("map".into())) }),
+                matches_pattern!(ActionParameter { key: eq("lastNodeId"), value: eq(&ActionParameterValue::String("n1".into())) }),
+            ]
+        }));
+    }
+
+    #[rstest]
+    #[case::start_pause(StandardAction::StartPause)]
+    #[case::init_position(StandardAction::InitPosition(init_position_parameters()))]
+    #[case::log_report(StandardAction::LogReport { reason: "crash".into() })]
+    #[case::drop(StandardAction::Drop(LoadHandlingParameters { lhd: Some("forks".into()), depth: Some(double(1.2)), ..LoadHandlingParameters::new("floor", "EPAL") }))]
+    #[case::fine_positioning(StandardAction::FinePositioning { station_type: None, station_name: Some("s1".into()) })]
+    #[case::wait_for_trigger(StandardAction::WaitForTrigger { trigger_type: "button".into() })]
+    fn test_StandardAction_roundtrip(#[case] standard_action: StandardAction) {
+        let action = standard_action.clone().into_action("a1");
+
+        assert_that!(action.action_type, eq(standard_action.action_type()));
+        assert_that!(StandardAction::try_from(action), ok(eq(&standard_action)));
+    }
+
+    #[rstest]
+    fn test_InitPositionParameters_try_from_coerces_integers() {
+        let mut action = Action::init_position("a1", init_position_parameters());
+        action.action_parameters[0].value = ActionParameterValue::Integer(3);
+
+        assert_that!(InitPositionParameters::try_from(&action), ok(matches_pattern!(InitPositionParameters {
+            x: eq(&double::<Double>(3.0)),
+            map_id: eq("map"),
+            last_node_id: eq("n1")
+        })));
+    }
+
+    #[rstest]
+    #[case::finished(ActionStatus::Finished, Some("log-2024.zip"))]
+    #[case::running(ActionStatus::Running, None)]
+    fn test_LogReport_report_name(#[case] action_status: ActionStatus, #[case] expected: Option<&str>) {
+        let action_state = ActionState {
+            action_id: "a1".into(),
+            action_type: Some("logReport".into()),
+            action_description: None,
+            action_status,
+            result_description: Some("log-2024.zip".into()),
+        };
+
+        assert_that!(LogReport::report_name(&action_state), eq(expected));
+    }
+
+    #[rstest]
+    fn test_informational_actions_try_from() {
+        assert_that!(LogReport::try_from(&Action::log_report("a1", "crash")), ok(eq(&LogReport::new("crash"))));
+        assert_that!(StateRequest::try_from(&Action::state_request("a1")), ok(eq(&StateRequest)));
+        assert_that!(FactsheetRequest::try_from(&Action::state_request("a1")), err(eq(&StandardActionError::UnexpectedActionType("stateRequest".into()))));
+    }
+
+    #[rstest]
+    fn test_ActionIdGenerator() {
+        let mut generator = ActionIdGenerator::new("mc");
+
+        let actions = [generator.start_pause(), generator.stop_pause(), generator.cancel_order()];
+
+        assert_that!(actions, elements_are![
+            matches_pattern!(Action { action_type: eq("startPause"), action_id: eq("mc-1"), blocking_type: matches_pattern!(BlockingType::Hard) }),
+            matches_pattern!(Action { action_type: eq("stopPause"), action_id: eq("mc-2"), blocking_type: matches_pattern!(BlockingType::Hard) }),
+            matches_pattern!(Action { action_type: eq("cancelOrder"), action_id: eq("mc-3"), blocking_type: matches_pattern!(BlockingType::None) }),
+        ]);
+    }
+
+    #[rstest]
+    #[case::paused(Action::start_pause("a1"), State::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap()).paused(true).build(), true)]
+    #[case::paused_while_driving(Action::start_pause("a1"), State::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap()).paused(true).driving(true).build(), false)]
+    #[case::resumed(Action::stop_pause("a1"), State::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap()).paused(false).build(), true)]
+    #[case::cancelled(Action::cancel_order("a1"), State::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap()).build(), true)]
+    #[case::not_cancelled(
+        Action::cancel_order("a1"),
+        State::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap())
+            .node_states(vec![NodeState { node_id: "n1".into(), sequence_id: 0, node_description: None, node_position: None, released: true }])
+            .build(),
+        false
+    )]
+    fn test_ExpectedStateChange_is_reached(#[case] action: Action, #[case] state: State, #[case] expected: bool) {
+        assert_that!(ExpectedStateChange::of(&action).map(|change| change.is_reached(&state)), some(eq(expected)));
+    }
+
+    #[rstest]
+    fn test_InitPositionParameters_try_from_other_action() {
+        assert_that!(InitPositionParameters::try_from(&Action::cancel_order("a1")), err(eq(&StandardActionError::UnexpectedActionType("cancelOrder".into()))));
+    }
+
+    #[rstest]
+    #[case::unknown_action_type(Action { action_type: "beep".into(), ..Action::cancel_order("a1") }, StandardActionError::UnknownActionType("beep".into()))]
+    #[case::missing_parameter(Action { action_parameters: vec![], ..Action::log_report("a1", "crash") }, StandardActionError::MissingParameter("reason"))]
+    #[case::invalid_parameter(
+        Action { action_parameters: vec![ActionParameter { key: "reason".into(), value: ActionParameterValue::Boolean(true) }], ..Action::log_report("a1", "crash") },
+        StandardActionError::InvalidParameter("reason")
+    )]
+    fn test_StandardAction_try_from_fails(#[case] action: Action, #[case] error: StandardActionError) {
+        assert_that!(StandardAction::try_from(action), err(eq(&error)));
+    }
+
+    #[rstest]
+    #[case::cancel_order(Action::cancel_order("a1"), "cancelOrder")]
+    #[case::state_request(Action::state_request("a1"), "stateRequest")]
+    #[case::factsheet_request(Action::factsheet_request("a1"), "factsheetRequest")]
+    #[case::detect_object(Action::detect_object("a1", None), "detectObject")]
+    #[case::fine_positioning(Action::fine_positioning("a1", None, None), "finePositioning")]
+    fn test_Action_standard_without_blocking(#[case] action: Action, #[case] action_type: &str) {
+        assert_that!(action, matches_pattern!(Action {
+            action_type: eq(action_type),
+            blocking_type: matches_pattern!(BlockingType::None),
+            action_parameters: empty()
+        }));
+    }
+}
@@ -1,18 +1,32 @@
+#[cfg(feature = "json")]
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
 
+use crate::checked::{Percent, Raw};
 use crate::common::{AgvPosition, BoundingBoxReference, HeaderId, LoadDimensions, NodePosition, Timestamp, Trajectory, Velocity};
+use crate::finite::Float;
+use crate::id::{ActionId, EdgeId, MapId, NodeId, OrderId, ZoneSetId};
+#[cfg(feature = "json")]
+use serde_json::value::RawValue;
 
 /// All encompassing state of the AGV.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct State {
     /// header_id of the message. The header_id is defined per topic and incremented by 1 with each sent (but not necessarily received) message.
+    #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
     pub header_id: HeaderId,
     /// Timestamp (ISO8601, UTC); YYYY-MM-DDTHH:mm:ss.ssZ; e.g. 2017-04-15T11:40:03.12Z
+    #[cfg_attr(feature = "defmt", defmt(Display2Format))]
+    #[cfg_attr(feature = "serde", serde(with = "crate::timestamp"))]
+    #[cfg_attr(feature = "ts-rs", ts(as = "String"))]
     pub timestamp: Timestamp,
     /// Version of the protocol [Major].[Minor].[Patch], e.g. 1.3.2
     pub version: String,
@@ -21,14 +35,16 @@ pub struct State {
     /// Serial number of the AGV
     pub serial_number: String,
     /// Unique order identification of the current order or the previous finished order. The order_id is kept until a new order is received. Empty string ("") if no previous order_id is available.
-    pub order_id: String,
+    pub order_id: OrderId,
     /// Order Update Identification to identify that an order update has been accepted by the AGV. 0 if no previous order_update_id is available.
+    #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
     pub order_update_id: u64,
     /// Unique ID of the zone set that the AGV currently uses for path planning. Must be the same as the one used in the order, otherwise the AGV is to reject the order. Optional: If the AGV does not use zones, this field can be omitted.
-    pub zone_set_id: Option<String>,
+    pub zone_set_id: Option<ZoneSetId>,
     /// nodeID of last reached node or, if AGV is currently on a node, current node (e. g. node7). Empty string ("") if no last_node_id is available.
-    pub last_node_id: String,
+    pub last_node_id: NodeId,
     /// sequence_id of the last reached node or, if the AGV is currently on a node, sequence_id of current node. 0 if no last_node_sequence_id is available.
+    #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
     pub last_node_sequence_id: u64,
     /// True: indicates that the AGV is driving and/or rotating. Other movements of the AGV (e.g. lift movements) are not included here. False: indicates that the AGV is neither driving nor rotating driving: bool,
     pub driving: bool,
@@ -37,7 +53,8 @@ pub struct State {
     /// True: AGV is almost at the end of the base and will reduce speed if no new base is transmitted. Trigger for MC to send new base False: no base update required
     pub new_base_request: Option<bool>,
     /// Used by line guided vehicles to indicate the distance it has been driving past the last_node_id. Distance is in meters
-    pub distance_since_last_node: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub distance_since_last_node: Option<Float>,
     /// Current operating mode of the AGV. For additional information, see the table OperatingModes in chapter 6.10.6.
     pub operating_mode: OperatingMode,
     /// Information about the nodes the AGV still has to drive over. Empty list if idle.
@@ -62,15 +79,185 @@ pub struct State {
     pub safety_state: SafetyState
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+impl State {
+
+    /// Returns `true` if the AGV is idle, i.e. it is neither driving nor has nodes or edges left to traverse, and all
+    /// its actions are either `FINISHED` or `FAILED`.
+    pub fn is_idle(&self) -> bool {
+        !self.driving
+            && self.node_states.is_empty()
+            && self.edge_states.is_empty()
+            && self.action_states.iter().all(|action_state| matches!(action_state.action_status, ActionStatus::Finished | ActionStatus::Failed))
+    }
+
+    /// Returns `true` if the AGV reports at least one error of level `FATAL`, i.e. it requires user intervention.
+    pub fn has_fatal_error(&self) -> bool {
+        self.fatal_errors().next().is_some()
+    }
+
+    /// Returns the errors of level `FATAL`.
+    pub fn fatal_errors(&self) -> impl Iterator<Item = &Error> {
+        self.errors.iter().filter(|error| error.error_level == ErrorLevel::Fatal)
+    }
+
+    /// Returns the highest level of the reported errors or `None` if the AGV reports no errors.
+    pub fn max_error_level(&self) -> Option<ErrorLevel> {
+        self.errors.iter().map(|error| error.error_level).max()
+    }
+
+    /// Returns the states of the actions with status `RUNNING`.
+    pub fn running_actions(&self) -> impl Iterator<Item = &ActionState> {
+        self.action_states.iter().filter(|action_state| action_state.action_status == ActionStatus::Running)
+    }
+
+    /// Returns the states of the released nodes the AGV still has to traverse, i.e. the remaining nodes of the base.
+    pub fn remaining_base_nodes(&self) -> impl Iterator<Item = &NodeState> {
+        self.node_states.iter().filter(|node_state| node_state.released)
+    }
+
+    /// Returns the id of the map the AGV is currently localized on or `None` if the AGV does not report its position.
+    pub fn current_map_id(&self) -> Option<&MapId> {
+        self.agv_position.as_ref().map(|agv_position| &agv_position.map_id)
+    }
+
+    /// Returns `true` if the AGV reports at least one load.
+    pub fn is_carrying(&self) -> bool {
+        !self.loads.is_empty()
+    }
+
+    /// Returns the load carried at the load handling unit `load_position`, e.g. `front`.
+    pub fn load_at_position(&self, load_position: &str) -> Option<&Load> {
+        self.loads.iter().find(|load| load.load_position.as_deref() == Some(load_position))
+    }
+
+    /// Returns the total weight of the loads in kg, which is zero without loads, or `None` if the weight of a load is
+    /// unknown.
+    pub fn total_load_weight(&self) -> Option<f32> {
+        self.loads.iter().map(|load| load.weight.map(Raw::raw)).sum()
+    }
+}
+
+/// A [`State`] whose node and edge states are not parsed until they are explicitly decoded.
+///
+/// Parsing the node and edge states (including trajectories) of a state is comparatively expensive. Consumers only
+/// interested in other parts of a state, e.g. the battery state or errors, can deserialize a `LazyState` instead.
+#[cfg(feature = "json")]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LazyState {
+    /// header_id of the message. The header_id is defined per topic and incremented by 1 with each sent (but not necessarily received) message.
+    pub header_id: HeaderId,
+    /// Timestamp (ISO8601, UTC); YYYY-MM-DDTHH:mm:ss.ssZ; e.g. 2017-04-15T11:40:03.12Z
+    #[serde(with = "crate::timestamp")]
+    pub timestamp: Timestamp,
+    /// Version of the protocol [Major].[Minor].[Patch], e.g. 1.3.2
+    pub version: String,
+    /// Manufacturer of the AGV
+    pub manufacturer: String,
+    /// Serial number of the AGV
+    pub serial_number: String,
+    /// Unique order identification of the current order or the previous finished order. The order_id is kept until a new order is received. Empty string ("") if no previous order_id is available.
+    pub order_id: OrderId,
+    /// Order Update Identification to identify that an order update has been accepted by the AGV. 0 if no previous order_update_id is available.
+    pub order_update_id: u64,
+    /// Unique ID of the zone set that the AGV currently uses for path planning. Must be the same as the one used in the order, otherwise the AGV is to reject the order. Optional: If the AGV does not use zones, this field can be omitted.
+    pub zone_set_id: Option<ZoneSetId>,
+    /// nodeID of last reached node or, if AGV is currently on a node, current node (e. g. node7). Empty string ("") if no last_node_id is available.
+    pub last_node_id: NodeId,
+    /// sequence_id of the last reached node or, if the AGV is currently on a node, sequence_id of current node. 0 if no last_node_sequence_id is available.
+    pub last_node_sequence_id: u64,
+    /// True: indicates that the AGV is driving and/or rotating. Other movements of the AGV (e.g. lift movements) are not included here. False: indicates that the AGV is neither driving nor rotating driving: bool,
+    pub driving: bool,
+    /// True: AGV is currently in a paused state, either because of the push of a physical button on the AGV or because of an instantAction. The AGV can resume the order. False: The AGV is currently not in a paused state.
+    pub paused: Option<bool>,
+    /// True: AGV is almost at the end of the base and will reduce speed if no new base is transmitted. Trigger for MC to send new base False: no base update required
+    pub new_base_request: Option<bool>,
+    /// Used by line guided vehicles to indicate the distance it has been driving past the last_node_id. Distance is in meters
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub distance_since_last_node: Option<Float>,
+    /// Current operating mode of the AGV. For additional information, see the table OperatingModes in chapter 6.10.6.
+    pub operating_mode: OperatingMode,
+    /// Information about the nodes the AGV still has to drive over, kept as raw JSON. See [`LazyState::node_states`].
+    pub node_states: Box<RawValue>,
+    /// Information about the edges the AGV still has to drive over including their trajectories, kept as raw JSON. See [`LazyState::edge_states`].
+    pub edge_states: Box<RawValue>,
+    /// Current position of the AGV on the map. Optional: Can only be omitted for AGVs without the capability to localize themselves, e.g. line guided AGVs.
+    pub agv_position: Option<AgvPosition>,
+    /// The AGVs velocity in vehicle coordinates.
+    pub velocity: Option<Velocity>,
+    /// Array for information about the loads that an AGV currently carries, if the AGV has any information about them. This array is optional: if an AGV cannot reason about its load state, it shall not send this field. If an empty field is sent, MC is to assume that the AGV can reason about its load state and that the AGV currently does not carry a load.
+    pub loads: Vec<Load>,
+    /// Contains a list of the current actions and the actions which are yet to be finished. This may include actions from previous nodes that are still in progress. When an action is completed, an updated state message is published with actionStatus set to finished and if applicable with the corresponding resultDescription. The action_states are kept until a new order is received.
+    pub action_states: Vec<ActionState>,
+    /// Contains all battery-related information.
+    pub battery_state: BatteryState,
+    /// Array of error objects. All active errors of the AGV should be in the list. An empty array indicates that the AGV has no active errors.
+    pub errors: Vec<Error>,
+    /// Array of information objects. An empty array indicates that the AGV has no information. This should only be used for visualization or debugging – it must not be used for logic in master control. Objects are only for visualization/debugging. There's no specification when these objects are deleted.
+    pub information: Vec<Information>,
+    /// Object that holds information about the safety status
+    pub safety_state: SafetyState
+}
+
+#[cfg(feature = "json")]
+impl LazyState {
+
+    /// Decodes the information about the nodes the AGV still has to drive over.
+    pub fn node_states(&self) -> serde_json::Result<Vec<NodeState>> {
+        serde_json::from_str(self.node_states.get())
+    }
+
+    /// Decodes the information about the edges the AGV still has to drive over.
+    pub fn edge_states(&self) -> serde_json::Result<Vec<EdgeState>> {
+        serde_json::from_str(self.edge_states.get())
+    }
+
+    /// Decodes the node and edge states and converts this into a [`State`].
+    pub fn into_state(self) -> serde_json::Result<State> {
+        Ok(State {
+            node_states: self.node_states()?,
+            edge_states: self.edge_states()?,
+            header_id: self.header_id,
+            timestamp: self.timestamp,
+            version: self.version,
+            manufacturer: self.manufacturer,
+            serial_number: self.serial_number,
+            order_id: self.order_id,
+            order_update_id: self.order_update_id,
+            zone_set_id: self.zone_set_id,
+            last_node_id: self.last_node_id,
+            last_node_sequence_id: self.last_node_sequence_id,
+            driving: self.driving,
+            paused: self.paused,
+            new_base_request: self.new_base_request,
+            distance_since_last_node: self.distance_since_last_node,
+            operating_mode: self.operating_mode,
+            agv_position: self.agv_position,
+            velocity: self.velocity,
+            loads: self.loads,
+            action_states: self.action_states,
+            battery_state: self.battery_state,
+            errors: self.errors,
+            information: self.information,
+            safety_state: self.safety_state,
+        })
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct NodeState {
     /// Unique node identification.
-    pub node_id: String,
+    pub node_id: NodeId,
     /// Sequence id of the node.
+    #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
     pub sequence_id: u64,
     /// Verbose node description.
     pub node_description: Option<String>,
@@ -80,15 +267,19 @@ pub struct NodeState {
     pub released: bool
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct EdgeState {
     /// Unique edge identification.
-    pub edge_id: String,
+    pub edge_id: EdgeId,
     /// sequence_id of the edge.
+    #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
     pub sequence_id: u64,
     /// Verbose Edge description
     pub edge_description: Option<String>,
@@ -98,14 +289,17 @@ pub struct EdgeState {
     pub trajectory: Option<Trajectory>
 }
 
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct ActionState {
     /// Unique action_id, e.g. blink_123jdaimoim234
-    pub action_id: String,
+    pub action_id: ActionId,
     /// action_type of the action. Optional: Only for informational or visualization purposes. Order knows the type.
     pub action_type: Option<String>,
     /// Additional information on the action.
@@ -117,7 +311,10 @@ pub struct ActionState {
 }
 
 /// Status of an Action.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -138,7 +335,10 @@ pub enum ActionStatus {
 }
 
 /// Load object that describes the load if the AGV has information about it.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -155,30 +355,40 @@ pub struct Load {
     /// Dimensions of the load's bounding box in meters.
     pub load_dimensions: Option<LoadDimensions>,
     /// Weight of load in kg
-    pub weight: Option<f32>
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub weight: Option<Float>
 }
 
 /// Contains all battery-related information.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct BatteryState {
     /// State of Charge in percent as a float value: If AGV only provides values for good or bad battery levels, these will be indicated as 20% (bad) and 80% (good).
-    pub battery_charge: f32,
+    #[cfg_attr(feature = "strict-floats", serde(serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub battery_charge: Percent,
     /// Battery voltage
-    pub battery_voltage: Option<f32>,
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub battery_voltage: Option<Float>,
     /// State of health in percent as an integer within range [0..100]
     pub battery_health: Option<u32>,
     /// If true: Charging in progress. If false: AGV is currently not charging.
     pub charging: bool,
     /// Estimated reach with current State of Charge (in meter as uint32)
-    pub reach: Option<f32>
+    #[cfg_attr(feature = "strict-floats", serde(default, serialize_with = "crate::float::serialize", deserialize_with = "crate::float::deserialize"))]
+    pub reach: Option<Float>
 }
 
 /// Current operating mode of the AGV. For additional information, see the table OperatingModes in chapter 6.10.6.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -192,7 +402,10 @@ pub enum OperatingMode {
 }
 
 /// An error object.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -209,7 +422,10 @@ pub struct Error {
 }
 
 /// Object that holds the error reference (e.g. order_id, order_update_id, action_id...) as key-value pairs.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -222,7 +438,12 @@ pub struct ErrorReference {
 }
 
 /// Error level.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+///
+/// Error levels are ordered by their severity, i.e. `WARNING` < `FATAL`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -235,7 +456,10 @@ pub enum ErrorLevel {
 }
 
 /// An information object.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -252,7 +476,10 @@ pub struct Information {
 }
 
 /// Object that holds the info reference (e.g. order_id, order_update_id, action_id...) as key-value pairs.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -265,7 +492,10 @@ pub struct InfoReference {
 }
 
 /// Info level.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -278,7 +508,10 @@ pub enum InfoLevel {
 }
 
 /// Object that holds information about the safety status.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
@@ -290,8 +523,24 @@ pub struct SafetyState {
     pub field_violation: bool
 }
 
+impl SafetyState {
+
+    /// Returns `true` if neither an e-stop is engaged nor a protective field is violated.
+    pub fn is_safe(&self) -> bool {
+        !self.e_stop.is_engaged() && !self.field_violation
+    }
+
+    /// Returns `true` if the engaged e-stop has to be acknowledged by a person, see [`EStop::requires_manual_reset`].
+    pub fn requires_manual_reset(&self) -> bool {
+        self.e_stop.requires_manual_reset()
+    }
+}
+
 /// Acknowledge type of e_stop.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "SCREAMING_SNAKE_CASE")
@@ -304,5 +553,234 @@ pub enum EStop {
     /// Facility e-stop has to be acknowledged remotely.
     Remote,
     /// No e-stop activated.
+    #[default]
     None
 }
+
+impl EStop {
+
+    /// Returns `true` if an e-stop is activated.
+    pub fn is_engaged(&self) -> bool {
+        *self != EStop::None
+    }
+
+    /// Returns `true` if the e-stop has to be acknowledged by a person, i.e. at the vehicle or remotely, instead of
+    /// being acknowledged automatically.
+    pub fn requires_manual_reset(&self) -> bool {
+        matches!(self, EStop::Manual | EStop::Remote)
+    }
+}
+
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::{checked, Raw};
+    use crate::common::AgvPosition;
+    use crate::timestamp::from_unix_millis;
+    use super::{ActionState, ActionStatus, EStop, Error, ErrorLevel, Load, NodeState, SafetyState, State};
+
+    fn state() -> State {
+        State::builder("Fubar Co.", "1234", from_unix_millis(0).unwrap()).build()
+    }
+
+    fn node_state(node_id: &str, released: bool) -> NodeState {
+        NodeState { node_id: node_id.into(), sequence_id: 0, node_description: None, node_position: None, released }
+    }
+
+    fn action_state(action_id: &str, action_status: ActionStatus) -> ActionState {
+        ActionState { action_id: action_id.into(), action_type: None, action_description: None, action_status, result_description: None }
+    }
+
+    fn error(error_level: ErrorLevel) -> Error {
+        Error { error_type: "someError".into(), error_references: vec![], error_description: None, error_level }
+    }
+
+    #[rstest]
+    #[case::initial(state(), true)]
+    #[case::driving(State { driving: true, ..state() }, false)]
+    #[case::remaining_nodes(State { node_states: vec![node_state("n1", true)], ..state() }, false)]
+    #[case::finished_actions(State { action_states: vec![action_state("a1", ActionStatus::Finished), action_state("a2", ActionStatus::Failed)], ..state() }, true)]
+    #[case::waiting_action(State { action_states: vec![action_state("a1", ActionStatus::Waiting)], ..state() }, false)]
+    fn test_State_is_idle(#[case] state: State, #[case] expected: bool) {
+        assert_that!(state.is_idle(), eq(expected));
+    }
+
+    #[rstest]
+    #[case::no_errors(vec![], false)]
+    #[case::warning(vec![error(ErrorLevel::Warning)], false)]
+    #[case::fatal(vec![error(ErrorLevel::Warning), error(ErrorLevel::Fatal)], true)]
+    fn test_State_has_fatal_error(#[case] errors: Vec<Error>, #[case] expected: bool) {
+        assert_that!(State { errors, ..state() }.has_fatal_error(), eq(expected));
+    }
+
+    #[rstest]
+    #[case::no_errors(vec![], None)]
+    #[case::warnings(vec![error(ErrorLevel::Warning), error(ErrorLevel::Warning)], Some(ErrorLevel::Warning))]
+    #[case::fatal(vec![error(ErrorLevel::Fatal), error(ErrorLevel::Warning)], Some(ErrorLevel::Fatal))]
+    fn test_State_max_error_level(#[case] errors: Vec<Error>, #[case] expected: Option<ErrorLevel>) {
+        let state = State { errors, ..state() };
+
+        assert_that!(state.max_error_level(), eq(expected));
+        assert_that!(state.fatal_errors().count(), eq(usize::from(expected == Some(ErrorLevel::Fatal))));
+    }
+
+    #[rstest]
+    fn test_State_running_actions_and_remaining_base_nodes() {
+        let state = State {
+            node_states: vec![node_state("n1", true), node_state("n2", false)],
+            action_states: vec![action_state("a1", ActionStatus::Running), action_state("a2", ActionStatus::Paused)],
+            ..state()
+        };
+
+        assert_that!(state.running_actions().map(|action_state| action_state.action_id.as_str()).collect::<Vec<_>>(), eq(&vec!["a1"]));
+        assert_that!(state.remaining_base_nodes().map(|node_state| node_state.node_id.as_str()).collect::<Vec<_>>(), eq(&vec!["n1"]));
+    }
+
+    fn load(load_position: Option<&str>, weight: Option<f32>) -> Load {
+        Load {
+            load_id: None,
+            load_type: None,
+            load_position: load_position.map(Into::into),
+            bounding_box_reference: None,
+            load_dimensions: None,
+            weight: weight.map(checked),
+        }
+    }
+
+    #[rstest]
+    #[case::no_loads(vec![], false, Some(0.0))]
+    #[case::weights(vec![load(Some("front"), Some(100.0)), load(Some("back"), Some(50.5))], true, Some(150.5))]
+    #[case::unknown_weight(vec![load(Some("front"), Some(100.0)), load(None, None)], true, None)]
+    fn test_State_loads(#[case] loads: Vec<Load>, #[case] is_carrying: bool, #[case] total_load_weight: Option<f32>) {
+        let state = State { loads, ..state() };
+
+        assert_that!(state.is_carrying(), eq(is_carrying));
+        assert_that!(state.total_load_weight(), eq(total_load_weight));
+    }
+
+    #[rstest]
+    fn test_State_load_at_position() {
+        let state = State { loads: vec![load(None, Some(1.0)), load(Some("front"), Some(2.0))], ..state() };
+
+        assert_that!(state.load_at_position("front").and_then(|load| load.weight).map(|weight| weight.raw()), some(eq(2.0)));
+        assert_that!(state.load_at_position("back").is_none(), eq(true));
+    }
+
+    #[rstest]
+    fn test_State_current_map_id() {
+        let agv_position = AgvPosition {
+            x: checked(0.0),
+            y: checked(0.0),
+            theta: checked(0.0),
+            map_id: "map".into(),
+            map_description: None,
+            position_initialized: true,
+            localization_score: None,
+            deviation_range: None,
+        };
+
+        assert_that!(state().current_map_id(), none());
+        assert_that!(State { agv_position: Some(agv_position), ..state() }.current_map_id(), some(eq("map")));
+    }
+
+    #[rstest]
+    #[case::none(EStop::None, false, false, true)]
+    #[case::autoack(EStop::Autoack, false, false, false)]
+    #[case::manual(EStop::Manual, false, true, false)]
+    #[case::remote(EStop::Remote, false, true, false)]
+    #[case::field_violation(EStop::None, true, false, false)]
+    fn test_SafetyState_predicates(#[case] e_stop: EStop, #[case] field_violation: bool, #[case] requires_manual_reset: bool, #[case] is_safe: bool) {
+        let safety_state = SafetyState { e_stop, field_violation };
+
+        assert_that!(e_stop.is_engaged(), eq(e_stop != EStop::None));
+        assert_that!(safety_state.requires_manual_reset(), eq(requires_manual_reset));
+        assert_that!(safety_state.is_safe(), eq(is_safe));
+    }
+
+    #[cfg(feature = "ts-rs")]
+    #[rstest::rstest]
+    fn test_ts_State_declaration_uses_json_representation() {
+        use googletest::prelude::*;
+        use ts_rs::TS;
+
+        let declaration = super::State::decl();
+
+        assert_that!(declaration, starts_with("type State = {"));
+        assert_that!(declaration, contains_substring("headerId: number"));
+        assert_that!(declaration, contains_substring("timestamp: string"));
+        assert_that!(declaration, contains_substring("zoneSetId: string | null"));
+        assert_that!(declaration, contains_substring("safetyState: SafetyState"));
+    }
+
+    #[cfg(feature = "ts-rs")]
+    #[rstest::rstest]
+    fn test_ts_EStop_declaration_uses_screaming_snake_case() {
+        use googletest::prelude::*;
+        use ts_rs::TS;
+
+        let declaration = super::EStop::decl();
+
+        assert_that!(declaration, eq("type EStop = \"AUTOACK\" | \"MANUAL\" | \"REMOTE\" | \"NONE\";"));
+    }
+
+    #[cfg(feature = "json")]
+    const STATE: &str = r#"{
+        "headerId": 1, "timestamp": "2017-04-15T11:40:03.120Z", "version": "2.0.0", "manufacturer": "Fubar Co.",
+        "serialNumber": "1234", "orderId": "o1", "orderUpdateId": 0, "lastNodeId": "n1", "lastNodeSequenceId": 0,
+        "driving": true, "operatingMode": "AUTOMATIC",
+        "nodeStates": [{ "nodeId": "n2", "sequenceId": 2, "released": true }],
+        "edgeStates": [{ "edgeId": "e1", "sequenceId": 1, "released": true }],
+        "loads": [], "actionStates": [],
+        "batteryState": { "batteryCharge": 80.0, "charging": false },
+        "errors": [], "information": [],
+        "safetyState": { "eStop": "NONE", "fieldViolation": false }
+    }"#;
+
+    #[cfg(feature = "json")]
+    #[rstest::rstest]
+    fn test_LazyState_defers_node_and_edge_states() {
+        use googletest::prelude::*;
+        use crate::checked::Raw;
+
+        let state: super::LazyState = serde_json::from_str(STATE).unwrap();
+
+        assert_that!(state.battery_state.battery_charge.raw(), eq(80.0));
+        assert_that!(state.node_states.get(), eq(r#"[{ "nodeId": "n2", "sequenceId": 2, "released": true }]"#));
+        assert_that!(state.node_states(), ok(elements_are![
+            matches_pattern!(super::NodeState { node_id: eq("n2"), sequence_id: eq(&2) })
+        ]));
+        assert_that!(state.edge_states(), ok(elements_are![
+            matches_pattern!(super::EdgeState { edge_id: eq("e1"), sequence_id: eq(&1) })
+        ]));
+    }
+
+    #[cfg(feature = "json")]
+    #[rstest::rstest]
+    fn test_LazyState_into_state() {
+        use googletest::prelude::*;
+
+        let state: super::LazyState = serde_json::from_str(STATE).unwrap();
+
+        assert_that!(state.into_state(), ok(matches_pattern!(super::State {
+            order_id: eq("o1"),
+            node_states: len(eq(1)),
+            edge_states: len(eq(1)),
+        })));
+    }
+
+    #[cfg(feature = "json")]
+    #[rstest::rstest]
+    fn test_LazyState_rejects_malformed_node_states_on_decode() {
+        use googletest::prelude::*;
+
+        let json = STATE.replace(r#""sequenceId": 2"#, r#""sequenceId": "two""#);
+        let state: super::LazyState = serde_json::from_str(&json).unwrap();
+
+        assert_that!(state.node_states(), err(anything()));
+    }
+}
@@ -0,0 +1,702 @@
+//!
+//! [proptest](https://docs.rs/proptest) strategies generating spec-valid messages for property-based testing.
+//!
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prelude::*;
+use proptest::strategy::LazyJust;
+
+use crate::action::{Action, ActionParameter, ActionParameterValue, BlockingType};
+use crate::checked::Raw;
+use crate::common::{AgvPosition, BoundingBoxReference, ControlPoint, HeaderId, LoadDimensions, NodePosition, Timestamp, Trajectory, Velocity};
+use crate::factsheet::{ActionScope, AgvAction, AgvClass, AgvGeometry, AgvKinematic, Envelopes2d, Envelopes3d, Factsheet, LoadSet, LoadSpecification, LocalizationType, MaxArrayLens, MaxStringLens, NavigationType, OptionalParameter, PhysicalParameters, PolygonPoint, Position, ProtocolFeatures, ProtocolLimits, Support, Timing, TypeSpecification, ValueDataType, WheelDefinition, WheelType};
+use crate::finite::{Double, Float, RawDouble};
+use crate::order::{Edge, Node, Order, OrientationType};
+use crate::state::{ActionState, ActionStatus, BatteryState, EStop, EdgeState, Error, ErrorLevel, ErrorReference, InfoLevel, InfoReference, Information, Load, NodeState, OperatingMode, SafetyState, State};
+
+/// Maximum number of nodes of a generated order graph.
+const MAX_NODES: usize = 8;
+
+/// Maximum number of elements of generated lists, e.g. actions of a node.
+const MAX_ELEMENTS: usize = 4;
+
+/// Header of a message: header_id, timestamp, version, manufacturer and serial number.
+type Header = (HeaderId, Timestamp, String, String, String);
+
+/// Strategy generating a header_id.
+pub fn header_id() -> impl Strategy<Value = HeaderId> {
+    any::<u32>().prop_map(HeaderId::from)
+}
+
+/// Strategy generating a UTC timestamp with millisecond precision between 1970 and 2100.
+pub fn timestamp() -> impl Strategy<Value = Timestamp> {
+    (0i64..4_102_444_800_000).prop_map(|millis| {
+        crate::timestamp::from_unix_millis(millis).expect("timestamp within range")
+    })
+}
+
+/// Strategy generating an identifier like a node_id or an action_id.
+pub fn id() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_]{1,16}"
+}
+
+/// Strategy generating an identifier, e.g. an [`OrderId`](crate::id::OrderId), like [`id()`].
+fn typed_id<T: From<String> + core::fmt::Debug>() -> impl Strategy<Value = T> {
+    id().prop_map(T::from)
+}
+
+fn identifier<T: From<String>>(id: String) -> T {
+    T::from(id)
+}
+
+fn header() -> impl Strategy<Value = Header> {
+    (header_id(), timestamp(), "[0-9]\\.[0-9]\\.[0-9]", "[a-zA-Z ]{1,16}", "[a-zA-Z0-9]{1,16}")
+}
+
+fn description() -> impl Strategy<Value = Option<String>> {
+    option::of("[a-zA-Z0-9 ]{0,32}")
+}
+
+fn finite<T: Raw + core::fmt::Debug>() -> impl Strategy<Value = T> {
+    checked(-1000.0f32..1000.0f32)
+}
+
+fn non_negative<T: Raw + core::fmt::Debug>() -> impl Strategy<Value = T> {
+    checked(0.0f32..1000.0f32)
+}
+
+fn angle<T: Raw + core::fmt::Debug>() -> impl Strategy<Value = T> {
+    checked(-core::f32::consts::PI..=core::f32::consts::PI)
+}
+
+/// Maps the values of `strategy`, which have to be within range, to the type of a checked field, e.g. an angle.
+fn checked<T: Raw + core::fmt::Debug>(strategy: impl Strategy<Value = f32>) -> impl Strategy<Value = T> {
+    strategy.prop_map(|value| T::try_from_raw(value).expect("value within range"))
+}
+
+/// Strategy generating a [`BlockingType`].
+pub fn blocking_type() -> impl Strategy<Value = BlockingType> {
+    prop_oneof![
+        LazyJust::new(|| BlockingType::None),
+        LazyJust::new(|| BlockingType::Soft),
+        LazyJust::new(|| BlockingType::Hard),
+    ]
+}
+
+/// Strategy generating an [`ActionParameterValue`] with finite numbers only.
+pub fn action_parameter_value() -> impl Strategy<Value = ActionParameterValue> {
+    prop_oneof![
+        LazyJust::new(|| ActionParameterValue::Null),
+        any::<bool>().prop_map(ActionParameterValue::Boolean),
+        any::<i64>().prop_map(ActionParameterValue::Integer),
+        (-1.0e6f64..1.0e6f64).prop_map(|value| ActionParameterValue::Float(Double::try_from_raw(value).expect("finite value"))),
+        "[a-zA-Z0-9 ]{0,16}".prop_map(ActionParameterValue::String),
+    ]
+}
+
+/// Strategy generating an [`ActionParameter`].
+pub fn action_parameter() -> impl Strategy<Value = ActionParameter> {
+    (id(), action_parameter_value())
+        .prop_map(|(key, value)| ActionParameter { key, value })
+}
+
+/// Strategy generating an [`Action`].
+pub fn action() -> impl Strategy<Value = Action> {
+    (id(), typed_id(), description(), blocking_type(), vec(action_parameter(), 0..MAX_ELEMENTS))
+        .prop_map(|(action_type, action_id, action_description, blocking_type, action_parameters)| Action {
+            action_type,
+            action_id,
+            action_description,
+            blocking_type,
+            action_parameters,
+        })
+}
+
+/// Strategy generating a [`NodePosition`].
+pub fn node_position() -> impl Strategy<Value = NodePosition> {
+    (finite(), finite(), option::of(angle()), option::of(non_negative()), option::of(checked(0.0f32..core::f32::consts::PI)), typed_id(), description())
+        .prop_map(|(x, y, theta, allowed_deviation_xy, allowed_deviation_theta, map_id, map_description)| NodePosition {
+            x,
+            y,
+            theta,
+            allowed_deviation_xy,
+            allowed_deviation_theta,
+            map_id,
+            map_description,
+        })
+}
+
+/// Strategy generating an [`AgvPosition`].
+pub fn agv_position() -> impl Strategy<Value = AgvPosition> {
+    (finite(), finite(), angle(), typed_id(), description(), any::<bool>(), option::of(checked(0.0f32..=1.0f32)), option::of(non_negative()))
+        .prop_map(|(x, y, theta, map_id, map_description, position_initialized, localization_score, deviation_range)| AgvPosition {
+            x,
+            y,
+            theta,
+            map_id,
+            map_description,
+            position_initialized,
+            localization_score,
+            deviation_range,
+        })
+}
+
+/// Strategy generating a [`Velocity`].
+pub fn velocity() -> impl Strategy<Value = Velocity> {
+    (option::of(finite()), option::of(finite()), option::of(finite()))
+        .prop_map(|(vx, vy, omega)| Velocity { vx, vy, omega })
+}
+
+/// Strategy generating a [`ControlPoint`].
+pub fn control_point() -> impl Strategy<Value = ControlPoint> {
+    (finite(), finite(), option::of(checked(0.1f32..10.0f32)), option::of(angle()))
+        .prop_map(|(x, y, weight, orientation)| ControlPoint { x, y, weight, orientation })
+}
+
+/// Strategy generating a [`Trajectory`] whose knot vector has the size of the number of control points + degree + 1.
+pub fn trajectory() -> impl Strategy<Value = Trajectory> {
+    (1i64..=3)
+        .prop_flat_map(|degree| {
+            let min_control_points = degree as usize + 1;
+            (Just(degree), vec(control_point(), min_control_points..min_control_points + MAX_ELEMENTS))
+        })
+        .prop_map(|(degree, control_points)| {
+            let knot_vector = clamped_uniform_knot_vector(degree as usize, control_points.len());
+            Trajectory { degree, knot_vector, control_points }
+        })
+}
+
+/// Knot vector with `degree + 1` zeros at the start, `degree + 1` ones at the end and equally spaced knots in between.
+fn clamped_uniform_knot_vector(degree: usize, control_points: usize) -> Vec<Float> {
+    let inner = control_points - degree - 1;
+    let mut knots = Vec::with_capacity(control_points + degree + 1);
+    knots.extend(core::iter::repeat(0.0).take(degree + 1));
+    knots.extend((1..=inner).map(|index| index as f32 / (inner + 1) as f32));
+    knots.extend(core::iter::repeat(1.0).take(degree + 1));
+    knots.into_iter().map(|knot| Float::try_from_raw(knot).expect("finite knot")).collect()
+}
+
+/// Strategy generating an [`OrientationType`].
+pub fn orientation_type() -> impl Strategy<Value = OrientationType> {
+    prop_oneof![
+        LazyJust::new(|| OrientationType::Global),
+        LazyJust::new(|| OrientationType::Tangential),
+    ]
+}
+
+fn node(node_id: String, sequence_id: u64, released: bool) -> impl Strategy<Value = Node> {
+    (description(), option::of(node_position()), vec(action(), 0..MAX_ELEMENTS))
+        .prop_map(move |(node_description, node_position, actions)| Node {
+            node_id: identifier(node_id.clone()),
+            sequence_id,
+            node_description,
+            released,
+            node_position,
+            actions,
+        })
+}
+
+fn edge(edge_id: String, sequence_id: u64, released: bool, start_node_id: String, end_node_id: String) -> impl Strategy<Value = Edge> {
+    (
+        description(),
+        option::of(non_negative()),
+        option::of(non_negative()),
+        option::of(non_negative()),
+        option::of(angle()),
+        option::of(orientation_type()),
+        option::of("[a-z]{1,8}"),
+        option::of(any::<bool>()),
+        option::of(non_negative()),
+        option::of(non_negative()),
+        option::of(trajectory()),
+        vec(action(), 0..MAX_ELEMENTS),
+    )
+        .prop_map(move |(edge_description, max_speed, max_height, min_height, orientation, orientation_type, direction, rotation_allowed, max_rotation_speed, length, trajectory, actions)| Edge {
+            edge_id: identifier(edge_id.clone()),
+            sequence_id,
+            edge_description,
+            released,
+            start_node_id: identifier(start_node_id.clone()),
+            end_node_id: identifier(end_node_id.clone()),
+            max_speed,
+            max_height,
+            min_height,
+            orientation,
+            orientation_type,
+            direction,
+            rotation_allowed,
+            max_rotation_speed,
+            length,
+            trajectory,
+            actions,
+        })
+}
+
+/// Strategy generating the nodes and edges of an order graph.
+///
+/// Nodes and edges alternate, starting and ending with a node. Nodes have even and edges odd sequence ids, each edge
+/// connects its neighbouring nodes and the released base is followed by the unreleased horizon.
+fn graph() -> impl Strategy<Value = (Vec<Node>, Vec<Edge>)> {
+    (1..=MAX_NODES)
+        .prop_flat_map(|count| (Just(count), 1..=count, vec(id(), count)))
+        .prop_flat_map(|(count, base, ids)| {
+            let node_ids: Vec<String> = ids.iter()
+                .enumerate()
+                .map(|(index, id)| format!("{id}-{index}"))
+                .collect();
+            let nodes = node_ids.iter()
+                .enumerate()
+                .map(|(index, node_id)| node(node_id.clone(), 2 * index as u64, index < base))
+                .collect::<Vec<_>>();
+            let edges = (0..count - 1)
+                .map(|index| edge(
+                    format!("{}-{}", node_ids[index], node_ids[index + 1]),
+                    2 * index as u64 + 1,
+                    index + 1 < base,
+                    node_ids[index].clone(),
+                    node_ids[index + 1].clone(),
+                ))
+                .collect::<Vec<_>>();
+            (nodes, edges)
+        })
+}
+
+/// Strategy generating an [`Order`].
+///
+/// The generated order graph alternates between nodes and edges with consecutive sequence ids, every edge connects
+/// its neighbouring nodes, the base is not empty and trajectories have a valid knot vector length.
+pub fn order() -> impl Strategy<Value = Order> {
+    (header(), typed_id(), 0u64..1000, option::of(typed_id()), graph())
+        .prop_map(|((header_id, timestamp, version, manufacturer, serial_number), order_id, order_update_id, zone_set_id, (nodes, edges))| Order {
+            header_id,
+            timestamp,
+            version,
+            manufacturer,
+            serial_number,
+            order_id,
+            order_update_id,
+            zone_set_id,
+            nodes,
+            edges,
+        })
+}
+
+/// Strategy generating an [`OperatingMode`].
+pub fn operating_mode() -> impl Strategy<Value = OperatingMode> {
+    prop_oneof![
+        LazyJust::new(|| OperatingMode::Automatic),
+        LazyJust::new(|| OperatingMode::Semiautomatic),
+        LazyJust::new(|| OperatingMode::Manual),
+        LazyJust::new(|| OperatingMode::Service),
+        LazyJust::new(|| OperatingMode::Teachin),
+    ]
+}
+
+/// Strategy generating an [`ActionStatus`].
+pub fn action_status() -> impl Strategy<Value = ActionStatus> {
+    prop_oneof![
+        LazyJust::new(|| ActionStatus::Waiting),
+        LazyJust::new(|| ActionStatus::Initializing),
+        LazyJust::new(|| ActionStatus::Paused),
+        LazyJust::new(|| ActionStatus::Running),
+        LazyJust::new(|| ActionStatus::Finished),
+        LazyJust::new(|| ActionStatus::Failed),
+    ]
+}
+
+/// Strategy generating an [`ActionState`].
+pub fn action_state() -> impl Strategy<Value = ActionState> {
+    (typed_id(), option::of(id()), description(), action_status(), description())
+        .prop_map(|(action_id, action_type, action_description, action_status, result_description)| ActionState {
+            action_id,
+            action_type,
+            action_description,
+            action_status,
+            result_description,
+        })
+}
+
+/// Strategy generating a [`Load`].
+pub fn load() -> impl Strategy<Value = Load> {
+    (
+        option::of(id()),
+        option::of(id()),
+        option::of(id()),
+        option::of((finite(), finite(), finite(), option::of(angle()))),
+        option::of((checked(0.1f32..10.0), checked(0.1f32..10.0), option::of(checked(0.1f32..10.0)))),
+        option::of(non_negative()),
+    )
+        .prop_map(|(load_id, load_type, load_position, bounding_box_reference, load_dimensions, weight)| Load {
+            load_id,
+            load_type,
+            load_position,
+            bounding_box_reference: bounding_box_reference.map(|(x, y, z, theta)| BoundingBoxReference { x, y, z, theta }),
+            load_dimensions: load_dimensions.map(|(length, width, height)| LoadDimensions { length, width, height }),
+            weight,
+        })
+}
+
+/// Strategy generating a [`BatteryState`].
+pub fn battery_state() -> impl Strategy<Value = BatteryState> {
+    (checked(0.0f32..=100.0), option::of(checked(0.0f32..100.0)), option::of(0u32..=100), any::<bool>(), option::of(non_negative()))
+        .prop_map(|(battery_charge, battery_voltage, battery_health, charging, reach)| BatteryState {
+            battery_charge,
+            battery_voltage,
+            battery_health,
+            charging,
+            reach,
+        })
+}
+
+/// Strategy generating an [`Error`].
+pub fn error() -> impl Strategy<Value = Error> {
+    let level = prop_oneof![LazyJust::new(|| ErrorLevel::Warning), LazyJust::new(|| ErrorLevel::Fatal)];
+    let reference = (id(), id()).prop_map(|(reference_key, reference_value)| ErrorReference { reference_key, reference_value });
+    (id(), vec(reference, 0..MAX_ELEMENTS), description(), level)
+        .prop_map(|(error_type, error_references, error_description, error_level)| Error {
+            error_type,
+            error_references,
+            error_description,
+            error_level,
+        })
+}
+
+/// Strategy generating an [`Information`].
+pub fn information() -> impl Strategy<Value = Information> {
+    let level = prop_oneof![LazyJust::new(|| InfoLevel::Info), LazyJust::new(|| InfoLevel::Debug)];
+    let reference = (id(), id()).prop_map(|(reference_key, reference_value)| InfoReference { reference_key, reference_value });
+    (id(), vec(reference, 0..MAX_ELEMENTS), description(), level)
+        .prop_map(|(info_type, info_references, info_description, info_level)| Information {
+            info_type,
+            info_references,
+            info_description,
+            info_level,
+        })
+}
+
+/// Strategy generating a [`SafetyState`].
+pub fn safety_state() -> impl Strategy<Value = SafetyState> {
+    let e_stop = prop_oneof![LazyJust::new(|| EStop::Autoack), LazyJust::new(|| EStop::Manual), LazyJust::new(|| EStop::Remote), LazyJust::new(|| EStop::None)];
+    (e_stop, any::<bool>())
+        .prop_map(|(e_stop, field_violation)| SafetyState { e_stop, field_violation })
+}
+
+/// Strategy generating the node and edge states of an order, derived from a generated order graph.
+fn graph_states() -> impl Strategy<Value = (Vec<NodeState>, Vec<EdgeState>)> {
+    graph().prop_map(|(nodes, edges)| {
+        let node_states = nodes.into_iter()
+            .map(|node| NodeState {
+                node_id: node.node_id,
+                sequence_id: node.sequence_id,
+                node_description: node.node_description,
+                node_position: node.node_position,
+                released: node.released,
+            })
+            .collect();
+        let edge_states = edges.into_iter()
+            .map(|edge| EdgeState {
+                edge_id: edge.edge_id,
+                sequence_id: edge.sequence_id,
+                edge_description: edge.edge_description,
+                released: edge.released,
+                trajectory: edge.trajectory,
+            })
+            .collect();
+        (node_states, edge_states)
+    })
+}
+
+/// Strategy generating a [`State`].
+///
+/// The node and edge states alternate with consecutive sequence ids like the ones of a generated [`order()`].
+pub fn state() -> impl Strategy<Value = State> {
+    (
+        (header(), typed_id(), 0u64..1000, option::of(typed_id()), typed_id(), 0u64..1000),
+        (any::<bool>(), option::of(any::<bool>()), option::of(any::<bool>()), option::of(non_negative()), operating_mode()),
+        (graph_states(), option::of(agv_position()), option::of(velocity()), vec(load(), 0..MAX_ELEMENTS), vec(action_state(), 0..MAX_ELEMENTS)),
+        (battery_state(), vec(error(), 0..MAX_ELEMENTS), vec(information(), 0..MAX_ELEMENTS), safety_state()),
+    )
+        .prop_map(|(
+            ((header_id, timestamp, version, manufacturer, serial_number), order_id, order_update_id, zone_set_id, last_node_id, last_node_sequence_id),
+            (driving, paused, new_base_request, distance_since_last_node, operating_mode),
+            ((node_states, edge_states), agv_position, velocity, loads, action_states),
+            (battery_state, errors, information, safety_state),
+        )| State {
+            header_id,
+            timestamp,
+            version,
+            manufacturer,
+            serial_number,
+            order_id,
+            order_update_id,
+            zone_set_id,
+            last_node_id,
+            last_node_sequence_id: 2 * last_node_sequence_id,
+            driving,
+            paused,
+            new_base_request,
+            distance_since_last_node,
+            operating_mode,
+            node_states,
+            edge_states,
+            agv_position,
+            velocity,
+            loads,
+            action_states,
+            battery_state,
+            errors,
+            information,
+            safety_state,
+        })
+}
+
+/// Strategy generating a [`TypeSpecification`].
+pub fn type_specification() -> impl Strategy<Value = TypeSpecification> {
+    let kinematic = prop_oneof![LazyJust::new(|| AgvKinematic::Diff), LazyJust::new(|| AgvKinematic::Omni), LazyJust::new(|| AgvKinematic::ThreeWheel)];
+    let class = prop_oneof![LazyJust::new(|| AgvClass::Forklift), LazyJust::new(|| AgvClass::Conveyor), LazyJust::new(|| AgvClass::Tugger), LazyJust::new(|| AgvClass::Carrier)];
+    let localization = prop_oneof![
+        LazyJust::new(|| LocalizationType::Natural),
+        LazyJust::new(|| LocalizationType::Reflector),
+        LazyJust::new(|| LocalizationType::Rfid),
+        LazyJust::new(|| LocalizationType::Dmc),
+        LazyJust::new(|| LocalizationType::Spot),
+        LazyJust::new(|| LocalizationType::Grid),
+    ];
+    let navigation = prop_oneof![
+        LazyJust::new(|| NavigationType::PhysicalLindeGuided),
+        LazyJust::new(|| NavigationType::VirtualLineGuided),
+        LazyJust::new(|| NavigationType::Autonomous),
+    ];
+    ("[a-zA-Z0-9 ]{1,16}", description(), kinematic, class, non_negative(), vec(localization, 1..MAX_ELEMENTS), vec(navigation, 1..MAX_ELEMENTS))
+        .prop_map(|(series_name, series_description, agv_kinematic, agv_class, max_load_mass, localization_types, navigation_types)| TypeSpecification {
+            series_name,
+            series_description,
+            agv_kinematic,
+            agv_class,
+            max_load_mass,
+            localization_types,
+            navigation_types,
+        })
+}
+
+/// Strategy generating [`PhysicalParameters`] where every minimum does not exceed its maximum.
+pub fn physical_parameters() -> impl Strategy<Value = PhysicalParameters> {
+    (checked(0.0f32..1.0), checked(1.0f32..10.0), non_negative(), non_negative(), option::of(checked(0.0f32..1.0)), checked(1.0f32..5.0), checked(0.1f32..5.0), checked(0.1f32..5.0))
+        .prop_map(|(speed_min, speed_max, acceleration_max, deceleration_max, height_min, height_max, width, length)| PhysicalParameters {
+            speed_min,
+            speed_max,
+            acceleration_max,
+            deceleration_max,
+            height_min,
+            height_max,
+            width,
+            length,
+        })
+}
+
+/// Strategy generating [`ProtocolLimits`].
+pub fn protocol_limits() -> impl Strategy<Value = ProtocolLimits> {
+    let string_lens = (option::of(any::<u32>()), option::of(any::<u16>()), option::of(any::<u16>()), option::of(any::<u16>()), option::of(any::<bool>()), option::of(any::<u16>()), option::of(any::<u16>()))
+        .prop_map(|(msg_len, topic_serial_len, topic_elem_len, id_len, id_numerical_only, enum_len, load_id_len)| MaxStringLens {
+            msg_len: msg_len.map(u64::from),
+            topic_serial_len: topic_serial_len.map(u64::from),
+            topic_elem_len: topic_elem_len.map(u64::from),
+            id_len: id_len.map(u64::from),
+            id_numerical_only,
+            enum_len: enum_len.map(u64::from),
+            load_id_len: load_id_len.map(u64::from),
+        });
+    let array_lens = vec(any::<u16>().prop_map(u32::from), 16)
+        .prop_map(|lens| MaxArrayLens {
+            order_nodes: lens[0],
+            order_edges: lens[1],
+            node_actions: lens[2],
+            edge_actions: lens[3],
+            actions_actions_parameters: lens[4],
+            instant_actions: lens[5],
+            trajectory_knot_vector: lens[6],
+            trajectory_control_points: lens[7],
+            state_node_states: lens[8],
+            state_edge_states: lens[9],
+            state_loads: lens[10],
+            state_action_states: lens[11],
+            state_errors: lens[12],
+            state_information: lens[13],
+            error_error_references: lens[14],
+            information_info_references: lens[15],
+        });
+    let timing = (non_negative(), non_negative(), option::of(non_negative()), option::of(non_negative()))
+        .prop_map(|(min_order_interval, min_state_interval, default_state_interval, visualization_interval)| Timing {
+            min_order_interval,
+            min_state_interval,
+            default_state_interval,
+            visualization_interval,
+        });
+    (string_lens, array_lens, timing)
+        .prop_map(|(max_string_lens, max_array_lens, timing)| ProtocolLimits { max_string_lens, max_array_lens, timing })
+}
+
+/// Strategy generating [`ProtocolFeatures`].
+pub fn protocol_features() -> impl Strategy<Value = ProtocolFeatures> {
+    let support = prop_oneof![LazyJust::new(|| Support::Supported), LazyJust::new(|| Support::Required)];
+    let optional_parameter = ("[a-zA-Z.]{1,32}", support, description())
+        .prop_map(|(parameter, support, description)| OptionalParameter { parameter, support, description });
+    let scope = prop_oneof![LazyJust::new(|| ActionScope::Instant), LazyJust::new(|| ActionScope::Node), LazyJust::new(|| ActionScope::Edge)];
+    let value_data_type = prop_oneof![
+        LazyJust::new(|| ValueDataType::Bool),
+        LazyJust::new(|| ValueDataType::Number),
+        LazyJust::new(|| ValueDataType::Integer),
+        LazyJust::new(|| ValueDataType::Float),
+        LazyJust::new(|| ValueDataType::String),
+        LazyJust::new(|| ValueDataType::Object),
+        LazyJust::new(|| ValueDataType::Array),
+    ];
+    let parameter = (id(), value_data_type, description(), option::of(any::<bool>()))
+        .prop_map(|(key, value_data_type, description, is_optional)| crate::factsheet::ActionParameter {
+            key,
+            value_data_type,
+            description,
+            is_optional,
+        });
+    let agv_action = (id(), description(), vec(scope, 1..=3), vec(parameter, 0..MAX_ELEMENTS), description())
+        .prop_map(|(action_type, action_description, action_scopes, action_parameters, result_description)| AgvAction {
+            action_type,
+            action_description,
+            action_scopes,
+            action_parameters,
+            result_description,
+        });
+    (vec(optional_parameter, 0..MAX_ELEMENTS), vec(agv_action, 0..MAX_ELEMENTS))
+        .prop_map(|(optional_parameters, agv_actions)| ProtocolFeatures { optional_parameters, agv_actions })
+}
+
+/// Strategy generating an [`AgvGeometry`].
+pub fn agv_geometry() -> impl Strategy<Value = AgvGeometry> {
+    let wheel_type = prop_oneof![LazyJust::new(|| WheelType::Drive), LazyJust::new(|| WheelType::Caster), LazyJust::new(|| WheelType::Fixed), LazyJust::new(|| WheelType::Mecanum)];
+    let position = (finite(), finite(), option::of(angle()))
+        .prop_map(|(x, y, theta)| Position { x, y, theta });
+    let wheel = (wheel_type, any::<bool>(), any::<bool>(), position, checked(0.01f32..2.0), checked(0.01f32..1.0), option::of(non_negative()), description())
+        .prop_map(|(wheel_type, is_active_driven, is_active_steered, position, diameter, width, center_displacement, constraints)| WheelDefinition {
+            wheel_type,
+            is_active_driven,
+            is_active_steered,
+            position,
+            diameter,
+            width,
+            center_displacement,
+            constraints,
+        });
+    let envelope2d = (id(), vec((finite(), finite()).prop_map(|(x, y)| PolygonPoint { x, y }), 3..8), description())
+        .prop_map(|(set, polygon_points, description)| Envelopes2d { set, polygon_points, description });
+    let envelope3d = (id(), "[A-Z]{3}", option::of("[a-z]{1,16}".prop_map(|path| format!("ftp://example.com/{path}"))))
+        .prop_map(|(set, format, url)| Envelopes3d { set, format, data: None, url, description: None });
+    (vec(wheel, 0..MAX_ELEMENTS), vec(envelope2d, 0..MAX_ELEMENTS), vec(envelope3d, 0..MAX_ELEMENTS))
+        .prop_map(|(wheel_definitions, envelopes2d, envelopes3d)| AgvGeometry { wheel_definitions, envelopes2d, envelopes3d })
+}
+
+/// Strategy generating a [`LoadSpecification`].
+pub fn load_specification() -> impl Strategy<Value = LoadSpecification> {
+    let load_set = (id(), id(), vec(id(), 0..MAX_ELEMENTS), option::of(non_negative()), option::of((checked(0.0f32..1.0), checked(1.0f32..2.0))), option::of((checked(0.1f32..1.0), checked(0.1f32..1.0))))
+        .prop_map(|(set_name, load_type, load_positions, max_weigth, loadhandling_height, handling_times)| LoadSet {
+            set_name,
+            load_type,
+            load_positions,
+            bounding_box_reference: None,
+            load_dimensions: None,
+            max_weigth,
+            min_loadhandling_height: loadhandling_height.map(|(min, _)| min),
+            max_loadhandling_height: loadhandling_height.map(|(_, max)| max),
+            min_loadhandling_depth: None,
+            max_loadhandling_depth: None,
+            min_loadhandling_tilt: None,
+            max_loadhandling_tilt: None,
+            agv_speed_limit: None,
+            agv_acceleration_limit: None,
+            agv_deceleration_limit: None,
+            pick_time: handling_times.map(|(pick, _)| pick),
+            drop_time: handling_times.map(|(_, drop)| drop),
+            description: None,
+        });
+    (vec(id(), 0..MAX_ELEMENTS), vec(load_set, 0..MAX_ELEMENTS))
+        .prop_map(|(load_positions, load_sets)| LoadSpecification { load_positions, load_sets })
+}
+
+/// Strategy generating a [`Factsheet`].
+pub fn factsheet() -> impl Strategy<Value = Factsheet> {
+    (
+        header(),
+        option::of(type_specification()),
+        option::of(physical_parameters()),
+        option::of(protocol_limits()),
+        option::of(protocol_features()),
+        option::of(agv_geometry()),
+        option::of(load_specification()),
+    )
+        .prop_map(|((header_id, timestamp, version, manufacturer, serial_number), type_specification, physical_parameters, protocol_limits, protocol_features, agv_geometry, load_specification)| Factsheet {
+            header_id,
+            timestamp,
+            version,
+            manufacturer,
+            serial_number,
+            type_specification,
+            physical_parameters,
+            protocol_limits,
+            protocol_features,
+            agv_geometry,
+            load_specification,
+            localization_parameters: None,
+        })
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use googletest::prelude::*;
+    use proptest::prelude::*;
+
+    use super::{factsheet, order, state, trajectory};
+
+    proptest! {
+
+        #[test]
+        fn test_strategy_order_alternates_nodes_and_edges(order in order()) {
+            assert_that!(order.nodes, not(empty()));
+            assert_that!(order.edges.len(), eq(order.nodes.len() - 1));
+            assert_that!(order.nodes[0].released, eq(true));
+
+            for (index, node) in order.nodes.iter().enumerate() {
+                assert_that!(node.sequence_id, eq(2 * index as u64));
+            }
+
+            for (index, edge) in order.edges.iter().enumerate() {
+                assert_that!(edge.sequence_id, eq(2 * index as u64 + 1));
+                assert_that!(edge.start_node_id, eq(&order.nodes[index].node_id));
+                assert_that!(edge.end_node_id, eq(&order.nodes[index + 1].node_id));
+                assert_that!(edge.released, eq(order.nodes[index + 1].released));
+            }
+        }
+
+        #[test]
+        fn test_strategy_trajectory_has_valid_knot_vector(trajectory in trajectory()) {
+            assert_that!(trajectory.knot_vector.len(), eq(trajectory.control_points.len() + trajectory.degree as usize + 1));
+            assert_that!(trajectory.knot_vector.windows(2).all(|knots| knots[0] <= knots[1]), eq(true));
+        }
+
+        #[test]
+        fn test_strategy_state_alternates_node_and_edge_states(state in state()) {
+            assert_that!(state.edge_states.len(), eq(state.node_states.len() - 1));
+            assert_that!(state.last_node_sequence_id % 2, eq(0));
+        }
+
+        #[test]
+        fn test_strategy_factsheet_respects_physical_limits(factsheet in factsheet()) {
+            if let Some(parameters) = factsheet.physical_parameters {
+                assert_that!(parameters.speed_min, le(parameters.speed_max));
+            }
+        }
+    }
+}
@@ -0,0 +1,562 @@
+//!
+//! The [`Timestamp`] type and its ISO8601 representation.
+//!
+//! The type backing a timestamp is selected via features. If more than one is enabled, the first one of the following
+//! list is used:
+//!
+//! | Feature          | Timestamp                  |
+//! | ---------------- | -------------------------- |
+//! | chrono           | `chrono::DateTime<Utc>`    |
+//! | time             | `time::OffsetDateTime`     |
+//! | timestamp-millis | [`EpochMillis`]            |
+//! | timestamp-string | `String`                   |
+//!
+//! Regardless of the backend, a timestamp is serialized as ISO8601 string in UTC with a `Z` suffix,
+//! e.g. `2017-04-15T11:40:03.120Z`.
+//!
+use alloc::format;
+use alloc::string::String;
+
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub type Timestamp = time::OffsetDateTime;
+
+#[cfg(all(feature = "timestamp-millis", not(any(feature = "chrono", feature = "time"))))]
+pub type Timestamp = EpochMillis;
+
+#[cfg(all(feature = "timestamp-string", not(any(feature = "chrono", feature = "time", feature = "timestamp-millis"))))]
+pub type Timestamp = String;
+
+/// Fallback without a backend, which only exists so that the `compile_error!` in the crate root is the only error.
+#[cfg(not(any(feature = "chrono", feature = "time", feature = "timestamp-millis", feature = "timestamp-string")))]
+pub type Timestamp = EpochMillis;
+
+/// Milliseconds since the unix epoch (1970-01-01T00:00:00Z).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EpochMillis(pub u64);
+
+impl core::fmt::Display for EpochMillis {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str(&Parts::from_epoch_millis(self.0).format(TimestampPrecision::Milliseconds))
+    }
+}
+
+impl core::str::FromStr for EpochMillis {
+    type Err = InvalidTimestamp;
+
+    /// Parses an ISO8601 timestamp like `2017-04-15T11:40:03.12Z`. Digits beyond millisecond precision are truncated.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let parts = Parts::parse(value).ok_or(InvalidTimestamp)?;
+        let millis = parts.to_unix().checked_mul(1000).ok_or(InvalidTimestamp)? + i64::from(parts.nanosecond / 1_000_000);
+        u64::try_from(millis).map(EpochMillis).map_err(|_| InvalidTimestamp)
+    }
+}
+
+/// Error returned when a string is not a valid ISO8601 timestamp.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct InvalidTimestamp;
+
+impl core::fmt::Display for InvalidTimestamp {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("invalid ISO8601 timestamp")
+    }
+}
+
+/// Precision of the fractional seconds of a formatted [`Timestamp`]. The timestamp is always formatted in UTC with a `Z` suffix.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum TimestampPrecision {
+    /// Whole seconds, e.g. 2017-04-15T11:40:03Z
+    Seconds,
+    /// Milliseconds, e.g. 2017-04-15T11:40:03.120Z
+    #[default]
+    Milliseconds,
+    /// Microseconds, e.g. 2017-04-15T11:40:03.120000Z
+    Microseconds,
+    /// Nanoseconds, e.g. 2017-04-15T11:40:03.120000000Z
+    Nanoseconds
+}
+
+impl TimestampPrecision {
+
+    /// Formats the given timestamp as ISO8601 with this precision.
+    ///
+    /// A timestamp backed by a `String` (feature `timestamp-string`) which is not a valid ISO8601 timestamp is returned
+    /// unchanged.
+    pub fn format(self, timestamp: &Timestamp) -> String {
+        format(timestamp, self)
+    }
+}
+
+/// Date and time components of a timestamp in UTC.
+struct Parts {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+}
+
+impl Parts {
+
+    #[cfg(all(feature = "timestamp-string", not(any(feature = "chrono", feature = "time", feature = "timestamp-millis"))))]
+    fn from_unix_millis(millis: i64) -> Self {
+        Parts::from_unix(millis.div_euclid(1000), (millis.rem_euclid(1000) * 1_000_000) as u32)
+    }
+
+    /// Splits `millis` into seconds before converting them into an `i64`, since milliseconds above `i64::MAX` would wrap.
+    fn from_epoch_millis(millis: u64) -> Self {
+        Parts::from_unix(i64::try_from(millis / 1000).unwrap_or(i64::MAX), (millis % 1000) as u32 * 1_000_000)
+    }
+
+    fn from_unix(seconds: i64, nanosecond: u32) -> Self {
+        let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+        let seconds_of_day = seconds.rem_euclid(86_400) as u32;
+        Parts {
+            year,
+            month,
+            day,
+            hour: seconds_of_day / 3600,
+            minute: seconds_of_day / 60 % 60,
+            second: seconds_of_day % 60,
+            nanosecond,
+        }
+    }
+
+    fn to_unix(&self) -> i64 {
+        days_from_civil(self.year, self.month, self.day) * 86_400
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second)
+    }
+
+    fn format(&self, precision: TimestampPrecision) -> String {
+        let fraction = match precision {
+            TimestampPrecision::Seconds => String::new(),
+            TimestampPrecision::Milliseconds => format!(".{:03}", self.nanosecond / 1_000_000),
+            TimestampPrecision::Microseconds => format!(".{:06}", self.nanosecond / 1_000),
+            TimestampPrecision::Nanoseconds => format!(".{:09}", self.nanosecond),
+        };
+        format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z", self.year, self.month, self.day, self.hour, self.minute, self.second, fraction)
+    }
+
+    /// Parses an ISO8601 timestamp like `2017-04-15T11:40:03.12Z` or `2017-04-15T13:40:03+02:00` and normalizes it to UTC.
+    fn parse(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+        let number = |range: core::ops::Range<usize>| -> Option<u32> {
+            let digits = bytes.get(range)?;
+            digits.iter().try_fold(0u32, |number, digit| digit.is_ascii_digit().then(|| number * 10 + u32::from(digit - b'0')))
+        };
+        if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || !matches!(bytes[10], b'T' | b't' | b' ') || bytes[13] != b':' || bytes[16] != b':' {
+            return None;
+        }
+        let mut parts = Parts {
+            year: i64::from(number(0..4)?),
+            month: number(5..7)?,
+            day: number(8..10)?,
+            hour: number(11..13)?,
+            minute: number(14..16)?,
+            second: number(17..19)?,
+            nanosecond: 0,
+        };
+        let mut index = 19;
+        if bytes[index] == b'.' {
+            let start = index + 1;
+            index = start;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                if index - start < 9 {
+                    parts.nanosecond += u32::from(bytes[index] - b'0') * 10u32.pow(8 - (index - start) as u32);
+                }
+                index += 1;
+            }
+            if index == start {
+                return None;
+            }
+        }
+        let offset = match bytes.get(index..)? {
+            [b'Z' | b'z'] => 0,
+            [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+                let offset = i64::from(number(index + 1..index + 3)? * 3600 + number(index + 4..index + 6)? * 60);
+                if *sign == b'+' { offset } else { -offset }
+            }
+            _ => return None,
+        };
+        if !(1..=12).contains(&parts.month) || parts.day == 0 || parts.day > days_in_month(parts.year, parts.month) || parts.hour > 23 || parts.minute > 59 || parts.second > 60 {
+            return None;
+        }
+        Some(Parts::from_unix(parts.to_unix() - offset, parts.nanosecond))
+    }
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Converts days since the unix epoch into a (year, month, day) triple of the proleptic gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Converts a date of the proleptic gregorian calendar into days since the unix epoch.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(feature = "chrono")]
+fn format(timestamp: &Timestamp, precision: TimestampPrecision) -> String {
+    use chrono::{Datelike, Timelike};
+    let timestamp = timestamp.naive_utc();
+    Parts {
+        year: i64::from(timestamp.year()),
+        month: timestamp.month(),
+        day: timestamp.day(),
+        hour: timestamp.hour(),
+        minute: timestamp.minute(),
+        second: timestamp.second(),
+        nanosecond: timestamp.nanosecond().min(999_999_999),
+    }.format(precision)
+}
+
+/// Parses an ISO8601 timestamp like `2017-04-15T11:40:03.12Z` into a [`Timestamp`].
+#[cfg(feature = "chrono")]
+pub fn parse(value: &str) -> Result<Timestamp, InvalidTimestamp> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&chrono::Utc))
+        .map_err(|_| InvalidTimestamp)
+}
+
+/// Creates a [`Timestamp`] from milliseconds since the unix epoch. Returns `None` if the timestamp is out of range.
+#[cfg(feature = "chrono")]
+pub fn from_unix_millis(millis: i64) -> Option<Timestamp> {
+    chrono::DateTime::from_timestamp_millis(millis)
+}
+
+/// Returns the milliseconds since the unix epoch of a [`Timestamp`]. Returns `None` if the timestamp is out of range.
+#[cfg(feature = "chrono")]
+pub fn to_unix_millis(timestamp: &Timestamp) -> Option<i64> {
+    Some(timestamp.timestamp_millis())
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+fn format(timestamp: &Timestamp, precision: TimestampPrecision) -> String {
+    let timestamp = timestamp.to_offset(time::UtcOffset::UTC);
+    Parts {
+        year: i64::from(timestamp.year()),
+        month: u32::from(u8::from(timestamp.month())),
+        day: u32::from(timestamp.day()),
+        hour: u32::from(timestamp.hour()),
+        minute: u32::from(timestamp.minute()),
+        second: u32::from(timestamp.second()),
+        nanosecond: timestamp.nanosecond(),
+    }.format(precision)
+}
+
+/// Parses an ISO8601 timestamp like `2017-04-15T11:40:03.12Z` into a [`Timestamp`].
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub fn parse(value: &str) -> Result<Timestamp, InvalidTimestamp> {
+    time::OffsetDateTime::parse(value, &time::format_description::well_known::Rfc3339)
+        .map(|timestamp| timestamp.to_offset(time::UtcOffset::UTC))
+        .map_err(|_| InvalidTimestamp)
+}
+
+/// Creates a [`Timestamp`] from milliseconds since the unix epoch. Returns `None` if the timestamp is out of range.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub fn from_unix_millis(millis: i64) -> Option<Timestamp> {
+    time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(millis) * 1_000_000).ok()
+}
+
+/// Returns the milliseconds since the unix epoch of a [`Timestamp`]. Returns `None` if the timestamp is out of range.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub fn to_unix_millis(timestamp: &Timestamp) -> Option<i64> {
+    i64::try_from(timestamp.unix_timestamp_nanos().div_euclid(1_000_000)).ok()
+}
+
+#[cfg(all(feature = "timestamp-millis", not(any(feature = "chrono", feature = "time"))))]
+fn format(timestamp: &Timestamp, precision: TimestampPrecision) -> String {
+    Parts::from_epoch_millis(timestamp.0).format(precision)
+}
+
+/// Parses an ISO8601 timestamp like `2017-04-15T11:40:03.12Z` into a [`Timestamp`].
+#[cfg(all(feature = "timestamp-millis", not(any(feature = "chrono", feature = "time"))))]
+pub fn parse(value: &str) -> Result<Timestamp, InvalidTimestamp> {
+    value.parse()
+}
+
+/// Creates a [`Timestamp`] from milliseconds since the unix epoch. Returns `None` if the timestamp is out of range.
+#[cfg(all(feature = "timestamp-millis", not(any(feature = "chrono", feature = "time"))))]
+pub fn from_unix_millis(millis: i64) -> Option<Timestamp> {
+    u64::try_from(millis).ok().map(EpochMillis)
+}
+
+/// Returns the milliseconds since the unix epoch of a [`Timestamp`]. Returns `None` if the timestamp is out of range.
+#[cfg(all(feature = "timestamp-millis", not(any(feature = "chrono", feature = "time"))))]
+pub fn to_unix_millis(timestamp: &Timestamp) -> Option<i64> {
+    i64::try_from(timestamp.0).ok()
+}
+
+#[cfg(all(feature = "timestamp-string", not(any(feature = "chrono", feature = "time", feature = "timestamp-millis"))))]
+fn format(timestamp: &Timestamp, precision: TimestampPrecision) -> String {
+    Parts::parse(timestamp).map_or_else(|| timestamp.clone(), |parts| parts.format(precision))
+}
+
+/// Parses an ISO8601 timestamp like `2017-04-15T11:40:03.12Z` into a [`Timestamp`], which is normalized to UTC with
+/// millisecond precision. Digits beyond millisecond precision are truncated.
+#[cfg(all(feature = "timestamp-string", not(any(feature = "chrono", feature = "time", feature = "timestamp-millis"))))]
+pub fn parse(value: &str) -> Result<Timestamp, InvalidTimestamp> {
+    Parts::parse(value).map(|parts| parts.format(TimestampPrecision::Milliseconds)).ok_or(InvalidTimestamp)
+}
+
+/// Creates a [`Timestamp`] from milliseconds since the unix epoch. Returns `None` if the timestamp is out of range.
+#[cfg(all(feature = "timestamp-string", not(any(feature = "chrono", feature = "time", feature = "timestamp-millis"))))]
+pub fn from_unix_millis(millis: i64) -> Option<Timestamp> {
+    Some(Parts::from_unix_millis(millis).format(TimestampPrecision::Milliseconds))
+}
+
+/// Returns the milliseconds since the unix epoch of a [`Timestamp`]. Returns `None` if the timestamp is not a valid
+/// ISO8601 timestamp or out of range.
+#[cfg(all(feature = "timestamp-string", not(any(feature = "chrono", feature = "time", feature = "timestamp-millis"))))]
+pub fn to_unix_millis(timestamp: &Timestamp) -> Option<i64> {
+    let parts = Parts::parse(timestamp)?;
+    Some(parts.to_unix().checked_mul(1000)? + i64::from(parts.nanosecond / 1_000_000))
+}
+
+#[cfg(not(any(feature = "chrono", feature = "time", feature = "timestamp-millis", feature = "timestamp-string")))]
+fn format(_timestamp: &Timestamp, _precision: TimestampPrecision) -> String {
+    unreachable!("no timestamp backend selected")
+}
+
+#[cfg(not(any(feature = "chrono", feature = "time", feature = "timestamp-millis", feature = "timestamp-string")))]
+pub fn parse(_value: &str) -> Result<Timestamp, InvalidTimestamp> {
+    unreachable!("no timestamp backend selected")
+}
+
+#[cfg(not(any(feature = "chrono", feature = "time", feature = "timestamp-millis", feature = "timestamp-string")))]
+pub fn from_unix_millis(_millis: i64) -> Option<Timestamp> {
+    unreachable!("no timestamp backend selected")
+}
+
+#[cfg(not(any(feature = "chrono", feature = "time", feature = "timestamp-millis", feature = "timestamp-string")))]
+pub fn to_unix_millis(_timestamp: &Timestamp) -> Option<i64> {
+    unreachable!("no timestamp backend selected")
+}
+
+/// Serializes `timestamp` with millisecond precision, which is the precision of all messages.
+#[cfg(feature = "serde")]
+pub(crate) fn serialize<S>(timestamp: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&format(timestamp, TimestampPrecision::Milliseconds))
+}
+
+/// Modules to (de-)serialize a [`Timestamp`] with a chosen precision via `#[serde(with = "...")]`, e.g. for a field
+/// of an own message type. The messages of this crate always serialize their timestamps with millisecond precision.
+///
+/// ```
+/// # use vda5050_types::v2_0::common::Timestamp;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Measurement {
+///     #[serde(with = "vda5050_types::v2_0::common::timestamp_precision::micros")]
+///     timestamp: Timestamp,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod precision {
+
+    macro_rules! precision {
+        ($(#[$doc:meta])* $name:ident, $precision:ident) => {
+            $(#[$doc])*
+            pub mod $name {
+                use crate::timestamp::{Timestamp, TimestampPrecision};
+
+                /// Serializes `timestamp` as ISO8601 string with the precision of this module.
+                pub fn serialize<S>(timestamp: &Timestamp, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    serializer.serialize_str(&TimestampPrecision::$precision.format(timestamp))
+                }
+
+                /// Deserializes a timestamp from an ISO8601 string of any precision.
+                pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    crate::timestamp::deserialize(deserializer)
+                }
+            }
+        };
+    }
+
+    precision! {
+        /// (De-)serialization of timestamps with whole seconds, e.g. `2017-04-15T11:40:03Z`.
+        seconds, Seconds
+    }
+
+    precision! {
+        /// (De-)serialization of timestamps with milliseconds, e.g. `2017-04-15T11:40:03.120Z`.
+        millis, Milliseconds
+    }
+
+    precision! {
+        /// (De-)serialization of timestamps with microseconds, e.g. `2017-04-15T11:40:03.120000Z`.
+        micros, Microseconds
+    }
+
+    precision! {
+        /// (De-)serialization of timestamps with nanoseconds, e.g. `2017-04-15T11:40:03.120000000Z`.
+        nanos, Nanoseconds
+    }
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct Iso8601;
+
+    impl <'de> serde::de::Visitor<'de> for Iso8601 {
+        type Value = Timestamp;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("an ISO8601 timestamp")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            parse(value).map_err(|_| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+        }
+    }
+
+    deserializer.deserialize_str(Iso8601)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use googletest::prelude::*;
+    use rstest::rstest;
+    use super::{EpochMillis, Parts, TimestampPrecision};
+
+    #[rstest]
+    #[case(TimestampPrecision::Seconds, "2017-04-15T11:40:03Z")]
+    #[case(TimestampPrecision::Milliseconds, "2017-04-15T11:40:03.120Z")]
+    #[case(TimestampPrecision::Microseconds, "2017-04-15T11:40:03.120456Z")]
+    #[case(TimestampPrecision::Nanoseconds, "2017-04-15T11:40:03.120456789Z")]
+    fn test_TimestampPrecision_format(#[case] precision: TimestampPrecision, #[case] expected: &str) {
+        let parts = Parts::from_unix(1_492_256_403, 120_456_789);
+
+        assert_that!(parts.format(precision), eq(expected));
+    }
+
+    #[rstest]
+    #[case("2017-04-15T11:40:03.12Z", 1_492_256_403, 120_000_000)]
+    #[case("2017-04-15T13:40:03.120456789+02:00", 1_492_256_403, 120_456_789)]
+    #[case("2017-04-15T11:40:03Z", 1_492_256_403, 0)]
+    #[case("1970-01-01T00:00:00Z", 0, 0)]
+    #[case("2024-02-29T23:59:59.999-01:00", 1_709_254_799, 999_000_000)]
+    fn test_Parts_parse(#[case] value: &str, #[case] seconds: i64, #[case] nanosecond: u32) {
+        let parts = Parts::parse(value);
+
+        assert_that!(parts.as_ref().map(Parts::to_unix), some(eq(seconds)));
+        assert_that!(parts.map(|parts| parts.nanosecond), some(eq(nanosecond)));
+    }
+
+    #[rstest]
+    #[case("2017-04-15")]
+    #[case("2017-04-15T11:40:03")]
+    #[case("2017-13-15T11:40:03Z")]
+    #[case("2023-02-29T11:40:03Z")]
+    #[case("2017-04-15T11:40:03.Z")]
+    #[case("2017-04-15T11:40:03+0200")]
+    fn test_Parts_parse_rejects_invalid_timestamps(#[case] value: &str) {
+        assert_that!(Parts::parse(value).is_none(), eq(true));
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1_492_256_403_120)]
+    fn test_to_unix_millis(#[case] millis: i64) {
+        let timestamp = super::from_unix_millis(millis).unwrap();
+
+        assert_that!(super::to_unix_millis(&timestamp), some(eq(millis)));
+    }
+
+    #[rstest]
+    #[cfg(feature = "serde")]
+    fn test_precision_serializes_with_chosen_precision() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Measurement {
+            #[serde(with = "super::precision::micros")]
+            timestamp: super::Timestamp,
+        }
+
+        let measurement = Measurement { timestamp: super::from_unix_millis(1_492_256_403_120).unwrap() };
+        let json = serde_json::to_string(&measurement).unwrap();
+
+        assert_that!(json, eq(r#"{"timestamp":"2017-04-15T11:40:03.120000Z"}"#));
+        assert_that!(serde_json::from_str::<Measurement>(&json).map(|measurement| measurement.timestamp), ok(eq(&measurement.timestamp)));
+    }
+
+    #[rstest]
+    #[case("2017-04-15T13:40:03.12+02:00", TimestampPrecision::Milliseconds, "2017-04-15T11:40:03.120Z")]
+    #[case("2017-04-15T11:40:03.120456789Z", TimestampPrecision::Seconds, "2017-04-15T11:40:03Z")]
+    #[case("2017-04-15T11:40:03Z", TimestampPrecision::Microseconds, "2017-04-15T11:40:03.000000Z")]
+    fn test_parse_and_format(#[case] value: &str, #[case] precision: TimestampPrecision, #[case] expected: &str) {
+        let timestamp = super::parse(value);
+
+        assert_that!(timestamp.as_ref().map(|timestamp| precision.format(timestamp)), ok(eq(expected)));
+    }
+
+    #[rstest]
+    #[case("2017-04-15")]
+    #[case("not a timestamp")]
+    fn test_parse_rejects_invalid_timestamps(#[case] value: &str) {
+        assert_that!(super::parse(value).is_err(), eq(true));
+    }
+
+    #[rstest]
+    fn test_EpochMillis_display_beyond_i64() {
+        let timestamp = EpochMillis(u64::MAX);
+
+        assert_that!(std::format!("{}", timestamp), ends_with("T14:25:51.615Z"));
+    }
+
+    #[rstest]
+    fn test_EpochMillis_display() {
+        assert_that!(std::format!("{}", EpochMillis(1_492_256_403_120)), eq("2017-04-15T11:40:03.120Z"));
+    }
+
+    #[rstest]
+    fn test_EpochMillis_from_str() {
+        assert_that!("2017-04-15T13:40:03.120456+02:00".parse::<EpochMillis>(), ok(eq(EpochMillis(1_492_256_403_120))));
+        assert_that!("1969-12-31T23:59:59Z".parse::<EpochMillis>(), err(eq(super::InvalidTimestamp)));
+    }
+}
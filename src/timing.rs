@@ -0,0 +1,63 @@
+//!
+//! Intervals of the timing of an AGV as durations instead of seconds.
+//!
+use core::time::Duration;
+
+use crate::checked::Raw;
+use crate::factsheet::Timing;
+
+/// Returns the interval of `seconds` rounded to microseconds, so that e.g. `0.1` is exactly 100 ms despite its single
+/// precision representation, or `None` if `seconds` is negative.
+fn duration<T: Raw>(seconds: T) -> Option<Duration> {
+    Duration::try_from_secs_f64(libm::round(f64::from(seconds.raw()) * 1e6) / 1e6).ok()
+}
+
+impl Timing {
+
+    /// Returns the minimum interval between order messages to the AGV, or `None` if the interval is negative.
+    pub fn min_order_interval_duration(&self) -> Option<Duration> {
+        duration(self.min_order_interval)
+    }
+
+    /// Returns the minimum interval between state messages, or `None` if the interval is negative.
+    pub fn min_state_interval_duration(&self) -> Option<Duration> {
+        duration(self.min_state_interval)
+    }
+
+    /// Returns the default interval between state messages, or `None` if the interval is not defined or negative.
+    pub fn default_state_interval_duration(&self) -> Option<Duration> {
+        self.default_state_interval.and_then(duration)
+    }
+
+    /// Returns the default interval between messages on the visualization topic, or `None` if the interval is not
+    /// defined or negative.
+    pub fn visualization_interval_duration(&self) -> Option<Duration> {
+        self.visualization_interval.and_then(duration)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use core::time::Duration;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::factsheet::Timing;
+
+    #[rstest]
+    fn test_Timing_durations() {
+        let timing = Timing {
+            min_order_interval: checked(0.5),
+            min_state_interval: checked(-1.0),
+            default_state_interval: Some(checked(30.0)),
+            visualization_interval: None,
+        };
+
+        assert_that!(timing.min_order_interval_duration(), some(eq(Duration::from_millis(500))));
+        assert_that!(timing.min_state_interval_duration(), none());
+        assert_that!(timing.default_state_interval_duration(), some(eq(Duration::from_secs(30))));
+        assert_that!(timing.visualization_interval_duration(), none());
+    }
+}
@@ -0,0 +1,817 @@
+//!
+//! Geometry of NURBS trajectories.
+//!
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f32::consts::{FRAC_PI_2, TAU};
+
+use crate::angle::normalize;
+use crate::checked::Raw;
+use crate::common::{ControlPoint, Trajectory};
+use crate::order::OrientationType;
+
+/// Maximum number of times a piece of a trajectory is halved by [`Trajectory::sample`].
+const MAX_SAMPLE_DEPTH: usize = 12;
+
+/// Maximum distance in meters between the end of a trajectory and the start of the trajectory appended by
+/// [`Trajectory::concat`].
+const MAX_JOIN_DISTANCE: f32 = 1e-4;
+
+/// Number of parameters per knot span at which [`Trajectory::simplify`] compares the simplified trajectory.
+const SIMPLIFY_SAMPLES: usize = 16;
+
+/// Tolerance in radians of the sweep of an arc, which absorbs rounding errors of e.g. full or quarter turns.
+const MAX_ANGLE_ERROR: f32 = 1e-5;
+
+impl Trajectory {
+
+    /// Returns the parameter range `(start, end)` of the trajectory or `None` if the trajectory is malformed, e.g. the
+    /// size of the knot vector does not match the number of control points and the degree.
+    pub fn domain(&self) -> Option<(f32, f32)> {
+        let degree = usize::try_from(self.degree).ok().filter(|degree| *degree >= 1)?;
+        let count = self.control_points.len();
+        if count <= degree || self.knot_vector.len() != count + degree + 1 {
+            return None;
+        }
+        let start = self.knot_vector[degree].raw();
+        let end = self.knot_vector[count].raw();
+        (start < end).then_some((start, end))
+    }
+
+    /// Returns the point `(x, y)` of the trajectory at the parameter `u` or `None` if the trajectory is malformed or
+    /// `u` lies outside of its [`domain`](Trajectory::domain).
+    pub fn point_at(&self, u: f32) -> Option<(f32, f32)> {
+        let (knots, points) = self.homogeneous_at(u)?;
+        let [x, y, weight] = de_boor(self.degree as usize, &knots, &points, u)?;
+        (weight != 0.0).then(|| (x / weight, y / weight))
+    }
+
+    /// Returns the unit vector `(x, y)` pointing along the trajectory at the parameter `u`, i.e. in the direction of
+    /// travel, or `None` if the trajectory is malformed, `u` lies outside of its [`domain`](Trajectory::domain) or the
+    /// trajectory has no direction at `u`, e.g. because control points coincide.
+    pub fn tangent_at(&self, u: f32) -> Option<(f32, f32)> {
+        let (knots, points) = self.homogeneous_at(u)?;
+        let degree = self.degree as usize;
+        let [x, y, weight] = de_boor(degree, &knots, &points, u)?;
+
+        // The derivative of a B-spline is a B-spline of one degree less, see The NURBS Book, equation 3.6.
+        let derivative_points = points.windows(2).enumerate()
+            .map(|(index, pair)| {
+                let span = knots[index + degree + 1] - knots[index + 1];
+                let factor = if span > 0.0 { degree as f32 / span } else { 0.0 };
+                [0, 1, 2].map(|coordinate| factor * (pair[1][coordinate] - pair[0][coordinate]))
+            })
+            .collect::<Vec<_>>();
+        let [dx, dy, dweight] = de_boor(degree - 1, &knots[1..knots.len() - 1], &derivative_points, u)?;
+        if weight == 0.0 {
+            return None;
+        }
+
+        // Quotient rule of the rational curve.
+        let (x, y) = (x / weight, y / weight);
+        let (dx, dy) = ((dx - dweight * x) / weight, (dy - dweight * y) / weight);
+        let length = libm::hypotf(dx, dy);
+        (length > 0.0 && length.is_finite()).then(|| (dx / length, dy / length))
+    }
+
+    /// Returns the orientation of the AGV at the parameter `u` in radians within `[-π, π]` following the orientation
+    /// rules of an edge, or `None` if the trajectory is malformed, `u` lies outside of its [`domain`](Trajectory::domain)
+    /// or the orientation is undefined at `u`, e.g. because the trajectory has no [tangent](Trajectory::tangent_at).
+    ///
+    /// If every control point defines an orientation, the orientations of the control points are interpolated along
+    /// the trajectory and take precedence over the edge. Otherwise, an `orientation` of the edge of the type `GLOBAL`
+    /// is the orientation on the map regardless of the trajectory, whereas an orientation of the type `TANGENTIAL` is
+    /// relative to the direction of travel, where `0` is forwards and `π` backwards. Without orientation, the AGV
+    /// drives forwards.
+    pub fn orientation_at(&self, u: f32, orientation_type: OrientationType, orientation: Option<f32>) -> Option<f32> {
+        if self.control_points.iter().all(|point| point.orientation.is_some()) {
+            return self.control_point_orientation_at(u);
+        }
+        match (orientation_type, orientation) {
+            (OrientationType::Global, Some(orientation)) => {
+                let (start, end) = self.domain()?;
+                (start..=end).contains(&u).then(|| normalize(orientation))
+            }
+            (_, orientation) => {
+                let (x, y) = self.tangent_at(u)?;
+                Some(normalize(libm::atan2f(y, x) + orientation.unwrap_or(0.0)))
+            }
+        }
+    }
+
+    /// Returns the orientation at the parameter `u` interpolated between the orientations of the control points, which
+    /// are blended as unit vectors like the positions of the control points to take the shorter way around, or `None`
+    /// if a control point has no orientation or the blended orientations cancel out.
+    fn control_point_orientation_at(&self, u: f32) -> Option<f32> {
+        let (knots, points) = self.homogeneous_at(u)?;
+        let directions = points.iter().zip(&self.control_points)
+            .map(|([_, _, weight], point)| {
+                let orientation = point.orientation?.raw();
+                Some([libm::cosf(orientation) * weight, libm::sinf(orientation) * weight, *weight])
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let [x, y, weight] = de_boor(self.degree as usize, &knots, &directions, u)?;
+        let (x, y) = (x / weight, y / weight);
+        (libm::hypotf(x, y) > 0.0).then(|| libm::atan2f(y, x))
+    }
+
+    /// Returns the raw knot vector and the control points in homogeneous coordinates, i.e. multiplied by their
+    /// weights, or `None` if the trajectory is malformed.
+    fn homogeneous(&self) -> Option<(Vec<f32>, Vec<[f32; 3]>)> {
+        self.domain()?;
+        let knots = self.knot_vector.iter().map(|knot| knot.raw()).collect();
+        let points = self.control_points.iter()
+            .map(|point| {
+                let weight = point.weight.map_or(1.0, Raw::raw);
+                [point.x.raw() * weight, point.y.raw() * weight, weight]
+            })
+            .collect();
+        Some((knots, points))
+    }
+
+    /// Returns the raw knot vector and the control points in homogeneous coordinates, or `None` if the trajectory is
+    /// malformed or `u` lies outside of its domain.
+    fn homogeneous_at(&self, u: f32) -> Option<(Vec<f32>, Vec<[f32; 3]>)> {
+        let (start, end) = self.domain()?;
+        (start..=end).contains(&u).then(|| self.homogeneous())?
+    }
+
+    /// Returns a polyline approximating the trajectory, whose chords deviate from the trajectory by at most
+    /// `tolerance` in meters, or `None` if the trajectory is malformed.
+    ///
+    /// Each knot span is halved until the midpoint of every chord is within `tolerance`, but at most 12 times, which
+    /// bounds the number of points for tiny or non-positive tolerances. The polyline starts and ends at the end points
+    /// of the trajectory.
+    pub fn sample(&self, tolerance: f32) -> Option<Vec<(f32, f32)>> {
+        let (start, end) = self.domain()?;
+        let mut breaks = self.knot_vector.iter()
+            .map(|knot| knot.raw())
+            .filter(|knot| (start..=end).contains(knot))
+            .collect::<Vec<_>>();
+        breaks.dedup();
+        let pieces = self.degree as usize;
+        let mut points = vec![self.point_at(start)?];
+        for span in breaks.windows(2) {
+            for piece in 0..pieces {
+                let from = span[0] + (span[1] - span[0]) * piece as f32 / pieces as f32;
+                let to = if piece + 1 == pieces { span[1] } else { span[0] + (span[1] - span[0]) * (piece + 1) as f32 / pieces as f32 };
+                let from = (from, *points.last()?);
+                let to = (to, self.point_at(to)?);
+                self.refine(from, to, tolerance, 0, &mut points)?;
+            }
+        }
+        Some(points)
+    }
+
+    /// Returns the length in meters of the polyline approximating the trajectory within `tolerance`, see
+    /// [`Trajectory::sample`], or `None` if the trajectory is malformed.
+    pub fn length(&self, tolerance: f32) -> Option<f32> {
+        Some(self.sample(tolerance)?.windows(2)
+            .map(|points| libm::hypotf(points[1].0 - points[0].0, points[1].1 - points[0].1))
+            .sum())
+    }
+
+    /// Appends the points approximating the trajectory from `from` to `to` to `points`, excluding the point `from`.
+    fn refine(&self, (u0, p0): (f32, (f32, f32)), (u1, p1): (f32, (f32, f32)), tolerance: f32, depth: usize, points: &mut Vec<(f32, f32)>) -> Option<()> {
+        let u = (u0 + u1) / 2.0;
+        let p = self.point_at(u)?;
+        if depth < MAX_SAMPLE_DEPTH && distance_to_chord(p, p0, p1) > tolerance {
+            self.refine((u0, p0), (u, p), tolerance, depth + 1, points)?;
+            self.refine((u, p), (u1, p1), tolerance, depth + 1, points)
+        }
+        else {
+            points.push(p1);
+            Some(())
+        }
+    }
+
+    /// Returns the straight line from `from` to `to` as trajectory of the degree `1`, or `None` if a coordinate is not
+    /// finite.
+    pub fn line(from: (f32, f32), to: (f32, f32)) -> Option<Trajectory> {
+        from_homogeneous(1, vec![0.0, 0.0, 1.0, 1.0], vec![[from.0, from.1, 1.0], [to.0, to.1, 1.0]])
+    }
+
+    /// Returns the circular arc around `center` with `radius` in meters from the angle `from` to the angle `to` in
+    /// radians as trajectory of the degree `2`, or `None` if the radius is not positive, the angles are equal or more
+    /// than a full turn apart, or a value is not finite.
+    ///
+    /// The arc runs counterclockwise if `to` is greater than `from` and clockwise otherwise. It consists of up to four
+    /// rational Bézier pieces spanning at most a quarter turn each, which represent the circle exactly.
+    pub fn arc(center: (f32, f32), radius: f32, from: f32, to: f32) -> Option<Trajectory> {
+        let sweep = to - from;
+        if !radius.is_finite() || radius <= 0.0 || !sweep.is_finite() || sweep == 0.0 || libm::fabsf(sweep) > TAU + MAX_ANGLE_ERROR {
+            return None;
+        }
+        let pieces = (libm::ceilf(libm::fabsf(sweep) / FRAC_PI_2 - MAX_ANGLE_ERROR) as usize).clamp(1, 4);
+        let step = sweep / pieces as f32;
+        let weight = libm::cosf(step / 2.0);
+        let point = |angle: f32, distance: f32, weight: f32| {
+            [(center.0 + distance * libm::cosf(angle)) * weight, (center.1 + distance * libm::sinf(angle)) * weight, weight]
+        };
+
+        let mut knots = vec![0.0; 3];
+        let mut points = vec![point(from, radius, 1.0)];
+        for piece in 0..pieces {
+            let start = from + step * piece as f32;
+            let end = if piece + 1 == pieces { to } else { start + step };
+            points.push(point(start + step / 2.0, radius / weight, weight));
+            points.push(point(end, radius, 1.0));
+            let knot = (piece + 1) as f32 / pieces as f32;
+            knots.extend(core::iter::repeat(knot).take(if piece + 1 == pieces { 3 } else { 2 }));
+        }
+        from_homogeneous(2, knots, points)
+    }
+
+    /// Returns the trajectory following this trajectory and then `next`, or `None` if either trajectory is malformed
+    /// or not clamped, i.e. does not start and end at its first and last control point, or if `next` does not start
+    /// within 0.1 mm of the end of this trajectory.
+    ///
+    /// The trajectory of the lower degree is elevated to the degree of the other, which keeps its shape but replaces
+    /// its control points, whose orientations are dropped. The knots of `next` are shifted to continue the knot vector
+    /// of this trajectory, and its weights are scaled to match at the shared control point.
+    pub fn concat(&self, next: &Trajectory) -> Option<Trajectory> {
+        let degree = self.degree.max(next.degree);
+        let (first, second) = (self.elevated(degree)?, next.elevated(degree)?);
+        let (end, start) = (first.control_points.last()?, second.control_points.first()?);
+        if libm::hypotf(end.x.raw() - start.x.raw(), end.y.raw() - start.y.raw()) > MAX_JOIN_DISTANCE {
+            return None;
+        }
+
+        let shift = first.knot_vector.last()?.raw() - second.knot_vector.first()?.raw();
+        let scale = end.weight.map_or(1.0, Raw::raw) / start.weight.map_or(1.0, Raw::raw);
+        let knot_vector = first.knot_vector[..first.knot_vector.len() - 1].iter().copied()
+            .map(Some)
+            .chain(second.knot_vector[degree as usize + 1..].iter().map(|knot| Raw::try_from_raw(knot.raw() + shift).ok()))
+            .collect::<Option<Vec<_>>>()?;
+        let control_points = first.control_points.iter().cloned()
+            .map(Some)
+            .chain(second.control_points[1..].iter().map(|point| {
+                let weight = point.weight.map_or(1.0, Raw::raw) * scale;
+                Some(ControlPoint {
+                    weight: if weight == 1.0 { None } else { Some(Raw::try_from_raw(weight).ok()?) },
+                    ..point.clone()
+                })
+            }))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Trajectory { degree, knot_vector, control_points })
+    }
+
+    /// Returns the trajectory of the same shape without the knots and thereby control points whose removal moves no
+    /// point of the trajectory by more than `tolerance` in meters, or `None` if the trajectory is malformed.
+    ///
+    /// Knots are removed one at a time, see The NURBS Book, section 5.4, as long as the points of the simplified
+    /// trajectory at 16 parameters per knot span stay within `tolerance` of the points of this trajectory at the same
+    /// parameters. The control points of the simplified trajectory have no orientation.
+    pub fn simplify(&self, tolerance: f32) -> Option<Trajectory> {
+        let (start, end) = self.domain()?;
+        let (mut knots, mut points) = self.homogeneous()?;
+        let degree = self.degree as usize;
+        let mut breaks = knots.iter().copied().filter(|knot| (start..=end).contains(knot)).collect::<Vec<_>>();
+        breaks.dedup();
+        let expected = breaks.windows(2)
+            .flat_map(|span| (0..SIMPLIFY_SAMPLES).map(move |index| span[0] + (span[1] - span[0]) * index as f32 / SIMPLIFY_SAMPLES as f32))
+            .chain([end])
+            .map(|u| Some((u, self.point_at(u)?)))
+            .collect::<Option<Vec<_>>>()?;
+
+        let within = |knots: &[f32], points: &[[f32; 3]]| {
+            points.iter().all(|[_, _, weight]| *weight > 0.0) && expected.iter().all(|(u, (x, y))| {
+                de_boor(degree, knots, points, *u).is_some_and(|[px, py, weight]| libm::hypotf(px / weight - x, py / weight - y) <= tolerance)
+            })
+        };
+
+        let mut index = degree + 1;
+        while index < points.len() {
+            let removable = start < knots[index] && knots[index] < end && knots[index] < knots[index + 1];
+            match removable.then(|| remove_knot(degree, &knots, &points, index)).flatten().filter(|(knots, points)| within(knots, points)) {
+                Some(simplified) => {
+                    (knots, points) = simplified;
+                    index = degree + 1;
+                }
+                None => index += 1,
+            }
+        }
+        from_homogeneous(degree, knots, points)
+    }
+
+    /// Rescales the knot vector to the range `[0, 1]` defined by the specification without changing the shape of the
+    /// trajectory, or leaves it unchanged if all knots are equal.
+    pub fn normalize_knots(&mut self) {
+        let (Some(first), Some(last)) = (self.knot_vector.first(), self.knot_vector.last()) else {
+            return;
+        };
+        let (first, last) = (first.raw(), last.raw());
+        if first < last {
+            for knot in self.knot_vector.iter_mut() {
+                *knot = Raw::try_from_raw(((knot.raw() - first) / (last - first)).clamp(0.0, 1.0)).unwrap_or(*knot);
+            }
+        }
+    }
+
+    /// Returns `true` if the first and the last `degree + 1` knots are equal, so that the trajectory starts and ends at
+    /// its first and last control point.
+    fn is_clamped(&self) -> bool {
+        let (Some(_), Ok(multiplicity)) = (self.domain(), usize::try_from(self.degree + 1)) else {
+            return false;
+        };
+        let knots = self.knot_vector.iter().map(|knot| knot.raw()).collect::<Vec<_>>();
+        let equal = |knots: &[f32]| knots.windows(2).all(|pair| pair[0] == pair[1]);
+        equal(&knots[..multiplicity]) && equal(&knots[knots.len() - multiplicity..])
+    }
+
+    /// Returns the clamped trajectory of the same shape with the degree `degree`, or `None` if the trajectory is not
+    /// clamped, has a greater degree or is not continuous.
+    fn elevated(&self, degree: i64) -> Option<Trajectory> {
+        if !self.is_clamped() || self.degree > degree {
+            return None;
+        }
+        let mut trajectory = self.clone();
+        while trajectory.degree < degree {
+            trajectory = trajectory.elevated_once()?;
+        }
+        Some(trajectory)
+    }
+
+    /// Returns the trajectory of the same shape with a degree greater by one by splitting the clamped trajectory into
+    /// Bézier pieces and elevating each, see The NURBS Book, section 5.5.
+    fn elevated_once(&self) -> Option<Trajectory> {
+        let (mut knots, mut points) = self.homogeneous()?;
+        let degree = self.degree as usize;
+        let (start, end) = (knots[0], knots[knots.len() - 1]);
+        let mut breaks = knots.iter().copied().filter(|knot| start < *knot && *knot < end).collect::<Vec<_>>();
+        breaks.dedup();
+        for knot in &breaks {
+            let multiplicity = knots.iter().filter(|other| *other == knot).count();
+            if multiplicity > degree {
+                return None;
+            }
+            for _ in multiplicity..degree {
+                insert_knot(degree, &mut knots, &mut points, *knot)?;
+            }
+        }
+
+        let mut elevated = vec![points[0]];
+        for piece in points.windows(degree + 1).step_by(degree) {
+            elevated.extend((1..=degree + 1).map(|index| {
+                let alpha = index as f32 / (degree + 1) as f32;
+                let next = piece.get(index).copied().unwrap_or(piece[degree]);
+                [0, 1, 2].map(|coordinate| alpha * piece[index - 1][coordinate] + (1.0 - alpha) * next[coordinate])
+            }));
+        }
+        let mut knots = vec![start; degree + 2];
+        for knot in breaks {
+            knots.extend(core::iter::repeat(knot).take(degree + 1));
+        }
+        knots.extend(core::iter::repeat(end).take(degree + 2));
+        from_homogeneous(degree + 1, knots, elevated)
+    }
+
+    /// Returns the maximum curvature in 1/m of the trajectory, approximated by the circles through three consecutive
+    /// points of `samples` points evenly spaced across the domain, or `None` if the trajectory is malformed.
+    pub(crate) fn max_curvature(&self, samples: usize) -> Option<f32> {
+        let (start, end) = self.domain()?;
+        let points = (0..samples.max(3))
+            .map(|index| self.point_at(start + (end - start) * index as f32 / (samples.max(3) - 1) as f32))
+            .collect::<Option<Vec<_>>>()?;
+        let squared_distance = |(ax, ay): (f32, f32), (bx, by): (f32, f32)| (bx - ax) * (bx - ax) + (by - ay) * (by - ay);
+        Some(points.windows(3)
+            .filter_map(|points| {
+                let (a, b, c) = (points[0], points[1], points[2]);
+                let cross = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+                let lengths = libm::sqrtf(squared_distance(a, b) * squared_distance(b, c) * squared_distance(a, c));
+                (lengths > 0.0).then(|| 2.0 * cross.abs() / lengths)
+            })
+            .fold(0.0, f32::max))
+    }
+}
+
+/// Returns the trajectory of `degree` with `knots` and the control points `points` in homogeneous coordinates, or
+/// `None` if a value is not finite.
+fn from_homogeneous(degree: usize, knots: Vec<f32>, points: Vec<[f32; 3]>) -> Option<Trajectory> {
+    Some(Trajectory {
+        degree: degree as i64,
+        knot_vector: knots.into_iter().map(|knot| Raw::try_from_raw(knot).ok()).collect::<Option<_>>()?,
+        control_points: points.into_iter()
+            .map(|[x, y, weight]| Some(ControlPoint {
+                x: Raw::try_from_raw(x / weight).ok()?,
+                y: Raw::try_from_raw(y / weight).ok()?,
+                weight: if weight == 1.0 { None } else { Some(Raw::try_from_raw(weight).ok()?) },
+                orientation: None,
+            }))
+            .collect::<Option<_>>()?,
+    })
+}
+
+/// Inserts the knot `u` within the domain into the B-spline of `degree` with `knots` and `points` without changing its
+/// shape via Boehm's algorithm, or returns `None` if `u` lies in no knot span.
+fn insert_knot(degree: usize, knots: &mut Vec<f32>, points: &mut Vec<[f32; 3]>, u: f32) -> Option<()> {
+    let span = (degree..points.len()).find(|index| knots[*index] <= u && u < knots[*index + 1])?;
+    let inserted = (span + 1 - degree..=span)
+        .map(|index| {
+            let alpha = (u - knots[index]) / (knots[index + degree] - knots[index]);
+            [0, 1, 2].map(|coordinate| (1.0 - alpha) * points[index - 1][coordinate] + alpha * points[index][coordinate])
+        })
+        .collect::<Vec<_>>();
+    points.splice(span + 1 - degree..span, inserted);
+    knots.insert(span + 1, u);
+    Some(())
+}
+
+/// Returns the knots and points of the B-spline of `degree` with `knots` and `points` without the knot at `index`,
+/// which is the last of its multiplicity, or `None` if the multiplicity exceeds the degree.
+///
+/// The control points next to the knot are solved from both sides, see The NURBS Book, algorithm A5.8, where the two
+/// solutions of the middle control point are averaged, so that the shape changes as little as possible if the knot is
+/// not exactly removable.
+fn remove_knot(degree: usize, knots: &[f32], points: &[[f32; 3]], index: usize) -> Option<(Vec<f32>, Vec<[f32; 3]>)> {
+    let u = knots[index];
+    let multiplicity = knots.iter().filter(|knot| **knot == u).count();
+    if multiplicity > degree {
+        return None;
+    }
+    let (first, last) = (index - degree, index - multiplicity);
+    let combine = |a: [f32; 3], b: [f32; 3], alpha: f32, divisor: f32| [0, 1, 2].map(|coordinate| (a[coordinate] - alpha * b[coordinate]) / divisor);
+
+    let mut left = vec![points[first - 1]];
+    let mut right = vec![points[last + 1]];
+    let (mut i, mut j) = (first, last);
+    while i < j {
+        let alpha = (u - knots[i]) / (knots[i + degree + 1] - knots[i]);
+        left.push(combine(points[i], *left.last()?, 1.0 - alpha, alpha));
+        let alpha = (u - knots[j]) / (knots[j + degree + 1] - knots[j]);
+        right.push(combine(points[j], *right.last()?, alpha, 1.0 - alpha));
+        (i, j) = (i + 1, j - 1);
+    }
+    if i == j + 1 {
+        // Both sides solved the middle control point.
+        let (a, b) = (left.pop()?, right.pop()?);
+        left.push([0, 1, 2].map(|coordinate| (a[coordinate] + b[coordinate]) / 2.0));
+    }
+
+    let points = points[..first].iter()
+        .chain(&left[1..])
+        .chain(right[1..].iter().rev())
+        .chain(&points[last + 1..])
+        .copied()
+        .collect();
+    let knots = knots[..index].iter().chain(&knots[index + 1..]).copied().collect();
+    Some((knots, points))
+}
+
+/// Returns the point of the B-spline of `degree` with `knots` and `points` at the parameter `u` via de Boor's
+/// algorithm, or `None` if `u` lies in no knot span.
+fn de_boor(degree: usize, knots: &[f32], points: &[[f32; 3]], u: f32) -> Option<[f32; 3]> {
+    // Index of the knot span containing u, the last non-empty span for the end of the domain.
+    let span = (degree..points.len())
+        .rev()
+        .find(|index| knots[*index] <= u && knots[*index] < knots[*index + 1])?;
+
+    let mut points = points[span - degree..=span].to_vec();
+    for level in 1..=degree {
+        for index in (level..=degree).rev() {
+            let lower = knots[index + span - degree];
+            let upper = knots[index + 1 + span - level];
+            let alpha = if upper > lower { (u - lower) / (upper - lower) } else { 0.0 };
+            let previous = points[index - 1];
+            for (coordinate, previous) in points[index].iter_mut().zip(previous) {
+                *coordinate = (1.0 - alpha) * previous + alpha * *coordinate;
+            }
+        }
+    }
+    Some(points[degree])
+}
+
+/// Returns the distance of `point` to the chord from `start` to `end`.
+fn distance_to_chord(point: (f32, f32), start: (f32, f32), end: (f32, f32)) -> f32 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let squared_length = dx * dx + dy * dy;
+    let t = if squared_length > 0.0 { (((point.0 - start.0) * dx + (point.1 - start.1) * dy) / squared_length).clamp(0.0, 1.0) } else { 0.0 };
+    libm::hypotf(point.0 - start.0 - t * dx, point.1 - start.1 - t * dy)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+pub(crate) mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::{checked, Raw};
+    use crate::common::{ControlPoint, Trajectory};
+    use crate::order::OrientationType;
+
+    pub(crate) fn trajectory(degree: i64, knot_vector: Vec<f32>, control_points: &[(f32, f32, Option<f32>)]) -> Trajectory {
+        Trajectory {
+            degree,
+            knot_vector: knot_vector.into_iter().map(checked).collect(),
+            control_points: control_points.iter()
+                .map(|(x, y, weight)| ControlPoint { x: checked(*x), y: checked(*y), weight: weight.map(checked), orientation: None })
+                .collect(),
+        }
+    }
+
+    /// Quarter circle of radius 1 around the origin from (1, 0) to (0, 1).
+    pub(crate) fn quarter_circle() -> Trajectory {
+        trajectory(2, vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0], &[
+            (1.0, 0.0, None),
+            (1.0, 1.0, Some(core::f32::consts::FRAC_1_SQRT_2)),
+            (0.0, 1.0, None),
+        ])
+    }
+
+    #[rstest]
+    #[case(0.0, (0.0, 0.0))]
+    #[case(0.25, (1.0, 0.5))]
+    #[case(0.5, (2.0, 1.0))]
+    #[case(1.0, (4.0, 2.0))]
+    fn test_Trajectory_point_at_on_line(#[case] u: f32, #[case] expected: (f32, f32)) {
+        let line = trajectory(1, vec![0.0, 0.0, 1.0, 1.0], &[(0.0, 0.0, None), (4.0, 2.0, None)]);
+
+        assert_that!(line.point_at(u), some(eq(expected)));
+    }
+
+    #[rstest]
+    fn test_Trajectory_default_is_line() {
+        let line = Trajectory {
+            knot_vector: vec![checked(0.0), checked(0.0), checked(1.0), checked(1.0)],
+            control_points: vec![ControlPoint::default(), ControlPoint { x: checked(4.0), ..ControlPoint::default() }],
+            ..Trajectory::default()
+        };
+
+        assert_that!(line.point_at(0.5), some(eq((2.0, 0.0))));
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(0.3)]
+    #[case(0.5)]
+    #[case(0.9)]
+    #[case(1.0)]
+    fn test_Trajectory_point_at_on_quarter_circle(#[case] u: f32) {
+        let (x, y) = quarter_circle().point_at(u).unwrap();
+
+        assert_that!(x * x + y * y, near(1.0, 1e-5));
+    }
+
+    #[rstest]
+    #[case::line(trajectory(1, vec![0.0, 0.0, 1.0, 1.0], &[(0.0, 0.0, None), (4.0, 2.0, None)]), 0.0)]
+    #[case::quarter_circle(quarter_circle(), 1.0)]
+    fn test_Trajectory_max_curvature(#[case] trajectory: Trajectory, #[case] expected: f32) {
+        assert_that!(trajectory.max_curvature(64), some(near(expected, 1e-3)));
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(0.3)]
+    #[case(0.5)]
+    #[case(1.0)]
+    fn test_Trajectory_tangent_at_on_quarter_circle(#[case] u: f32) {
+        let circle = quarter_circle();
+        let (x, y) = circle.point_at(u).unwrap();
+
+        // The tangent of a counterclockwise circle around the origin is perpendicular to the radius.
+        let (dx, dy) = circle.tangent_at(u).unwrap();
+        assert_that!(dx, near(-y, 1e-5));
+        assert_that!(dy, near(x, 1e-5));
+    }
+
+    #[rstest]
+    fn test_Trajectory_tangent_at_on_line() {
+        let line = trajectory(1, vec![0.0, 0.0, 1.0, 1.0], &[(0.0, 0.0, None), (4.0, 3.0, None)]);
+
+        assert_that!(line.tangent_at(0.5), some(eq((0.8, 0.6))));
+        assert_that!(line.tangent_at(1.5), none());
+    }
+
+    #[rstest]
+    #[case::tangential_forwards(OrientationType::Tangential, None, FRAC_PI_2)]
+    #[case::tangential_backwards(OrientationType::Tangential, Some(PI), -FRAC_PI_2)]
+    #[case::global(OrientationType::Global, Some(0.5), 0.5)]
+    #[case::global_without_orientation(OrientationType::Global, None, FRAC_PI_2)]
+    fn test_Trajectory_orientation_at(#[case] orientation_type: OrientationType, #[case] orientation: Option<f32>, #[case] expected: f32) {
+        let orientation = quarter_circle().orientation_at(0.0, orientation_type, orientation);
+
+        assert_that!(orientation, some(near(expected, 1e-5)));
+    }
+
+    #[rstest]
+    #[case::start(0.0, &[0.0, FRAC_PI_2], 0.0)]
+    #[case::interpolated(0.5, &[0.0, FRAC_PI_2], FRAC_PI_4)]
+    #[case::across_pi(0.5, &[PI - 0.25, -PI + 0.25], PI)]
+    fn test_Trajectory_orientation_at_interpolates_control_points(#[case] u: f32, #[case] orientations: &[f32], #[case] expected: f32) {
+        let mut line = trajectory(1, vec![0.0, 0.0, 1.0, 1.0], &[(0.0, 0.0, None), (4.0, 3.0, None)]);
+        for (point, orientation) in line.control_points.iter_mut().zip(orientations) {
+            point.orientation = Some(checked(*orientation));
+        }
+
+        let orientation = line.orientation_at(u, OrientationType::Global, Some(1.0));
+
+        assert_that!(orientation.map(|orientation| libm::sinf(orientation - expected)), some(near(0.0, 1e-5)));
+        assert_that!(orientation.map(|orientation| libm::cosf(orientation - expected)), some(near(1.0, 1e-5)));
+    }
+
+    #[rstest]
+    fn test_Trajectory_orientation_at_without_orientation_of_every_control_point() {
+        let mut line = trajectory(1, vec![0.0, 0.0, 1.0, 1.0], &[(0.0, 0.0, None), (0.0, 3.0, None)]);
+        line.control_points[0].orientation = Some(checked(1.0));
+
+        assert_that!(line.orientation_at(0.5, OrientationType::Tangential, None), some(near(FRAC_PI_2, 1e-5)));
+    }
+
+    #[rstest]
+    fn test_Trajectory_orientation_at_without_tangent() {
+        let point = trajectory(1, vec![0.0, 0.0, 1.0, 1.0], &[(1.0, 1.0, None), (1.0, 1.0, None)]);
+
+        assert_that!(point.orientation_at(0.5, OrientationType::Global, Some(0.5)), some(near(0.5, 1e-5)));
+        assert_that!(point.orientation_at(1.5, OrientationType::Global, Some(0.5)), none());
+        assert_that!(point.orientation_at(0.5, OrientationType::Tangential, Some(0.5)), none());
+        assert_that!(point.orientation_at(0.5, OrientationType::Global, None), none());
+    }
+
+    #[rstest]
+    fn test_Trajectory_line() {
+        let line = Trajectory::line((1.0, 2.0), (4.0, 6.0)).unwrap();
+
+        assert_that!(line.domain(), some(eq((0.0, 1.0))));
+        assert_that!(line.point_at(0.5), some(eq((2.5, 4.0))));
+        assert_that!(line.length(0.01), some(eq(5.0)));
+    }
+
+    #[rstest]
+    #[case::quarter_turn(0.0, FRAC_PI_2, 3)]
+    #[case::clockwise_half_turn(PI, 0.0, 5)]
+    #[case::three_quarter_turn(-FRAC_PI_2, PI, 7)]
+    #[case::full_turn(0.0, TAU, 9)]
+    fn test_Trajectory_arc_lies_on_circle(#[case] from: f32, #[case] to: f32, #[case] control_points: usize) {
+        let arc = Trajectory::arc((1.0, -1.0), 2.0, from, to).unwrap();
+
+        assert_that!(arc.control_points, len(eq(control_points)));
+        for index in 0..=20 {
+            let (x, y) = arc.point_at(index as f32 / 20.0).unwrap();
+            assert_that!(libm::hypotf(x - 1.0, y + 1.0), near(2.0, 1e-5));
+        }
+        let (x, y) = arc.point_at(1.0).unwrap();
+        assert_that!((x, y), (near(1.0 + 2.0 * libm::cosf(to), 1e-5), near(-1.0 + 2.0 * libm::sinf(to), 1e-5)));
+    }
+
+    #[rstest]
+    #[case::counterclockwise(FRAC_PI_2, (0.0, 1.0))]
+    #[case::clockwise(-FRAC_PI_2, (0.0, -1.0))]
+    fn test_Trajectory_arc_direction(#[case] to: f32, #[case] expected: (f32, f32)) {
+        let arc = Trajectory::arc((0.0, 0.0), 1.0, 0.0, to).unwrap();
+
+        assert_that!(arc.tangent_at(0.0), some((near(expected.0, 1e-5), near(expected.1, 1e-5))));
+    }
+
+    #[rstest]
+    #[case::zero_radius(0.0, 0.0, PI)]
+    #[case::equal_angles(1.0, PI, PI)]
+    #[case::more_than_full_turn(1.0, 0.0, 7.0)]
+    #[case::not_finite(f32::NAN, 0.0, PI)]
+    fn test_Trajectory_arc_returns_none(#[case] radius: f32, #[case] from: f32, #[case] to: f32) {
+        assert_that!(Trajectory::arc((0.0, 0.0), radius, from, to), none());
+    }
+
+    #[rstest]
+    fn test_Trajectory_concat_line_and_arc() {
+        let line = Trajectory::line((0.0, 0.0), (1.0, 0.0)).unwrap();
+        let arc = Trajectory::arc((1.0, 1.0), 1.0, -FRAC_PI_2, 0.0).unwrap();
+
+        let trajectory = line.concat(&arc).unwrap();
+
+        assert_that!(trajectory.degree, eq(2));
+        assert_that!(trajectory.domain(), some(eq((0.0, 2.0))));
+        assert_that!(trajectory.point_at(0.5), some((near(0.5, 1e-6), near(0.0, 1e-6))));
+        assert_that!(trajectory.point_at(1.0), some((near(1.0, 1e-6), near(0.0, 1e-6))));
+        assert_that!(trajectory.point_at(2.0), some((near(2.0, 1e-6), near(1.0, 1e-6))));
+        assert_that!(trajectory.length(1e-4), some(near(1.0 + FRAC_PI_2, 1e-3)));
+    }
+
+    #[rstest]
+    fn test_Trajectory_concat_arcs_scales_weights() {
+        let first = Trajectory::arc((0.0, 0.0), 1.0, 0.0, FRAC_PI_2).unwrap();
+        let second = Trajectory { control_points: first.control_points.iter()
+            .map(|point| ControlPoint { x: checked(-point.y.raw()), y: checked(point.x.raw()), weight: Some(checked(2.0 * point.weight.map_or(1.0, Raw::raw))), orientation: None })
+            .collect(), ..first.clone() };
+
+        let trajectory = first.concat(&second).unwrap();
+
+        for index in 0..=20 {
+            let (x, y) = trajectory.point_at(index as f32 / 10.0).unwrap();
+            assert_that!(libm::hypotf(x, y), near(1.0, 1e-5));
+        }
+        assert_that!(trajectory.point_at(2.0), some((near(-1.0, 1e-6), near(0.0, 1e-6))));
+    }
+
+    #[rstest]
+    #[case::gap(Trajectory::line((1.0, 0.1), (2.0, 0.0)).unwrap())]
+    #[case::not_clamped(trajectory(1, vec![0.0, 1.0, 2.0, 3.0], &[(1.0, 0.0, None), (2.0, 0.0, None)]))]
+    fn test_Trajectory_concat_returns_none(#[case] next: Trajectory) {
+        assert_that!(Trajectory::line((0.0, 0.0), (1.0, 0.0)).unwrap().concat(&next), none());
+    }
+
+    #[rstest]
+    fn test_Trajectory_simplify_removes_inserted_knots() {
+        let circle = quarter_circle();
+        let (mut knots, mut points) = circle.homogeneous().unwrap();
+        super::insert_knot(2, &mut knots, &mut points, 0.3).unwrap();
+        super::insert_knot(2, &mut knots, &mut points, 0.6).unwrap();
+        super::insert_knot(2, &mut knots, &mut points, 0.6).unwrap();
+        let refined = super::from_homogeneous(2, knots, points).unwrap();
+
+        let simplified = refined.simplify(1e-5).unwrap();
+
+        assert_that!(refined.control_points, len(eq(6)));
+        assert_that!(simplified.control_points, len(eq(3)));
+        for index in 0..=10 {
+            let (x, y) = simplified.point_at(index as f32 / 10.0).unwrap();
+            assert_that!(libm::hypotf(x, y), near(1.0, 1e-5));
+        }
+    }
+
+    #[rstest]
+    #[case::collinear(&[(0.0, 0.0, None), (1.0, 0.0, None), (2.0, 0.0, None)], 0.01, 2)]
+    #[case::corner(&[(0.0, 0.0, None), (1.0, 1.0, None), (2.0, 0.0, None)], 0.1, 3)]
+    #[case::corner_within_tolerance(&[(0.0, 0.0, None), (1.0, 1.0, None), (2.0, 0.0, None)], 1.0, 2)]
+    fn test_Trajectory_simplify_polyline(#[case] control_points: &[(f32, f32, Option<f32>)], #[case] tolerance: f32, #[case] expected: usize) {
+        let polyline = trajectory(1, vec![0.0, 0.0, 0.5, 1.0, 1.0], control_points);
+
+        let simplified = polyline.simplify(tolerance).unwrap();
+
+        assert_that!(simplified.control_points, len(eq(expected)));
+        assert_that!(simplified.knot_vector, len(eq(expected + 2)));
+        assert_that!(simplified.point_at(1.0), some(eq((2.0, 0.0))));
+    }
+
+    #[rstest]
+    fn test_Trajectory_simplify_keeps_joined_arcs() {
+        let arc = Trajectory::arc((0.0, 0.0), 1.0, 0.0, PI).unwrap();
+
+        assert_that!(arc.simplify(0.01).unwrap().control_points, len(eq(5)));
+    }
+
+    #[rstest]
+    fn test_Trajectory_normalize_knots() {
+        let mut circle = trajectory(2, vec![2.0, 2.0, 2.0, 6.0, 6.0, 6.0], &[
+            (1.0, 0.0, None),
+            (1.0, 1.0, Some(core::f32::consts::FRAC_1_SQRT_2)),
+            (0.0, 1.0, None),
+        ]);
+        let midpoint = circle.point_at(4.0);
+
+        circle.normalize_knots();
+
+        assert_that!(circle.knot_vector.iter().map(|knot| knot.raw()).collect::<Vec<_>>(), eq(&vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0]));
+        assert_that!(circle.point_at(0.5), eq(midpoint));
+    }
+
+    #[rstest]
+    fn test_Trajectory_normalize_knots_keeps_equal_knots() {
+        let mut trajectory = trajectory(1, vec![2.0, 2.0, 2.0, 2.0], &[(0.0, 0.0, None), (1.0, 0.0, None)]);
+
+        trajectory.normalize_knots();
+
+        assert_that!(trajectory.knot_vector.iter().map(|knot| knot.raw()).collect::<Vec<_>>(), eq(&vec![2.0; 4]));
+    }
+
+    #[rstest]
+    fn test_Trajectory_sample_line() {
+        let line = trajectory(1, vec![0.0, 0.0, 1.0, 1.0], &[(0.0, 0.0, None), (4.0, 2.0, None)]);
+
+        assert_that!(line.sample(0.01), some(eq(&vec![(0.0, 0.0), (4.0, 2.0)])));
+    }
+
+    #[rstest]
+    #[case(0.1)]
+    #[case(0.01)]
+    #[case(0.001)]
+    fn test_Trajectory_sample_bounds_chord_error(#[case] tolerance: f32) {
+        let points = quarter_circle().sample(tolerance).unwrap();
+
+        // The chord error of a chord of a circle of radius 1 is 1 - cos(α / 2) for the angle α it spans.
+        let max_chord_error = points.windows(2)
+            .map(|points| 1.0 - libm::cosf(libm::asinf(libm::hypotf(points[1].0 - points[0].0, points[1].1 - points[0].1) / 2.0)))
+            .fold(0.0, f32::max);
+        assert_that!(points.first(), some(eq(&(1.0, 0.0))));
+        assert_that!(points.last().map(|(x, y)| (libm::roundf(*x * 1e5), libm::roundf(*y * 1e5))), some(eq((0.0, 1e5))));
+        assert_that!(max_chord_error, le(tolerance * 1.01));
+        assert_that!(quarter_circle().length(tolerance), some(near(core::f32::consts::FRAC_PI_2, 4.0 * tolerance)));
+    }
+
+    #[rstest]
+    fn test_Trajectory_sample_depth_is_bounded() {
+        let points = quarter_circle().sample(0.0).unwrap();
+
+        assert_that!(points.len(), le(2 * (1 << super::MAX_SAMPLE_DEPTH) + 1));
+    }
+
+    #[rstest]
+    #[case::outside_domain(quarter_circle(), 1.5)]
+    #[case::too_short_knot_vector(trajectory(2, vec![0.0, 0.0, 1.0, 1.0], &[(0.0, 0.0, None), (1.0, 0.0, None), (1.0, 1.0, None)]), 0.5)]
+    #[case::degree_zero(trajectory(0, vec![0.0, 1.0], &[(0.0, 0.0, None)]), 0.5)]
+    fn test_Trajectory_point_at_returns_none(#[case] trajectory: Trajectory, #[case] u: f32) {
+        assert_that!(trajectory.point_at(u), none());
+    }
+}
@@ -0,0 +1,489 @@
+//!
+//! State machines of statuses reported by the AGV and trackers detecting illegal transitions between messages.
+//!
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::action::Action;
+use crate::common::{HeaderId, Timestamp};
+use crate::connection::{Connection, ConnectionState};
+use crate::id::{ActionId, OrderId};
+use crate::instant_actions::InstantActions;
+use crate::order::Order;
+use crate::state::{ActionStatus, State};
+use crate::validation::{RULE_DUPLICATE_ID, RULE_ILLEGAL_TRANSITION, Validator, Violation};
+
+impl ActionStatus {
+
+    /// Returns `true` if an action may change from this status to `next`, which is the case for
+    /// - `WAITING` to `INITIALIZING`, `RUNNING`, `FINISHED` or `FAILED`, as not every action requires preparation,
+    /// - `INITIALIZING` to `RUNNING`, `FINISHED` or `FAILED`,
+    /// - `RUNNING` to `PAUSED`, `FINISHED` or `FAILED`,
+    /// - `PAUSED` to `RUNNING` or `FAILED`.
+    ///
+    /// `FINISHED` and `FAILED` are final. Remaining in the same status is always allowed.
+    pub fn can_transition_to(&self, next: &ActionStatus) -> bool {
+        use ActionStatus::*;
+        *self == *next || matches!((self, next),
+            (Waiting, Initializing | Running | Finished | Failed)
+            | (Initializing, Running | Finished | Failed)
+            | (Running, Paused | Finished | Failed)
+            | (Paused, Running | Failed))
+    }
+
+    /// Returns the name of the status as used in messages, e.g. `RUNNING`.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            ActionStatus::Waiting => "WAITING",
+            ActionStatus::Initializing => "INITIALIZING",
+            ActionStatus::Paused => "PAUSED",
+            ActionStatus::Running => "RUNNING",
+            ActionStatus::Finished => "FINISHED",
+            ActionStatus::Failed => "FAILED",
+        }
+    }
+}
+
+/// Tracks the statuses of actions across consecutive states of an AGV and detects illegal transitions.
+///
+/// The statuses are forgotten when the `orderId` of an observed state changes, as action states are only kept until
+/// a new order is received.
+#[derive(Clone, Default)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct ActionStatusTracker {
+    order_id: OrderId,
+    statuses: BTreeMap<ActionId, ActionStatus>,
+}
+
+impl ActionStatusTracker {
+
+    /// Creates a tracker which has not observed any state yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the last observed status of the action `action_id`.
+    pub fn status(&self, action_id: &str) -> Option<ActionStatus> {
+        self.statuses.get(action_id).copied()
+    }
+
+    /// Records the action states of `state` and returns a violation of [`RULE_ILLEGAL_TRANSITION`] for each action
+    /// whose status changed illegally since the previously observed state, see [`ActionStatus::can_transition_to`].
+    pub fn observe(&mut self, state: &State) -> Vec<Violation> {
+        if self.order_id != state.order_id {
+            self.order_id.clone_from(&state.order_id);
+            self.statuses.clear();
+        }
+        let mut validator = Validator::new();
+        validator.elements("actionStates", &state.action_states, |validator, action| {
+            if let Some(previous) = self.statuses.get(&action.action_id).filter(|previous| !previous.can_transition_to(&action.action_status)) {
+                validator.field("actionStatus", |validator| {
+                    validator.violation(RULE_ILLEGAL_TRANSITION, format!("action `{}` changed from {} to {}", action.action_id, previous.name(), action.action_status.name()));
+                });
+            }
+        });
+        self.statuses = state.action_states.iter().map(|action| (action.action_id.clone(), action.action_status)).collect();
+        validator.into_violations()
+    }
+}
+
+/// Message which issued an action id, see [`ActionIdTracker::issuer`].
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum ActionIssuer {
+    /// A node or edge action of the order with the given `orderId`.
+    Order(OrderId),
+    /// An instant action of the instant actions message with the given `headerId`.
+    InstantActions(HeaderId),
+}
+
+impl core::fmt::Display for ActionIssuer {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ActionIssuer::Order(order_id) => write!(formatter, "order `{order_id}`"),
+            ActionIssuer::InstantActions(header_id) => write!(formatter, "instant actions {header_id}"),
+        }
+    }
+}
+
+/// Tracks the action ids issued to an AGV by orders and instant actions and detects ids which are issued twice, as
+/// the action states reported by the AGV would be ambiguous.
+///
+/// Order updates may repeat the actions of their order. The issued ids are forgotten when an order with another
+/// `orderId` is issued, as action states are only kept until a new order is received. A fleet uses one tracker per AGV.
+#[derive(Clone, Default)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct ActionIdTracker {
+    order_id: OrderId,
+    issuers: BTreeMap<ActionId, ActionIssuer>,
+}
+
+impl ActionIdTracker {
+
+    /// Creates a tracker which has not observed any message yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the message which issued the action `action_id` first.
+    pub fn issuer(&self, action_id: &str) -> Option<&ActionIssuer> {
+        self.issuers.get(action_id)
+    }
+
+    /// Records the action ids of the nodes and edges of `order` and returns a violation of [`RULE_DUPLICATE_ID`] for
+    /// each action whose id is used twice within `order` or was already issued by instant actions.
+    pub fn observe_order(&mut self, order: &Order) -> Vec<Violation> {
+        if self.order_id != order.order_id {
+            self.order_id.clone_from(&order.order_id);
+            self.issuers.clear();
+        }
+        let issuer = ActionIssuer::Order(order.order_id.clone());
+        let mut issued = BTreeSet::new();
+        let mut validator = Validator::new();
+        validator.elements("nodes", &order.nodes, |validator, node| {
+            self.issue(validator, &node.actions, &issuer, &mut issued);
+        });
+        validator.elements("edges", &order.edges, |validator, edge| {
+            self.issue(validator, &edge.actions, &issuer, &mut issued);
+        });
+        validator.into_violations()
+    }
+
+    /// Records the action ids of `instant_actions` and returns a violation of [`RULE_DUPLICATE_ID`] for each action
+    /// whose id is used twice within `instant_actions` or was already issued by an order or other instant actions.
+    pub fn observe_instant_actions(&mut self, instant_actions: &InstantActions) -> Vec<Violation> {
+        let issuer = ActionIssuer::InstantActions(instant_actions.header_id);
+        let mut issued = BTreeSet::new();
+        let mut validator = Validator::new();
+        self.issue(&mut validator, &instant_actions.instant_actions, &issuer, &mut issued);
+        validator.into_violations()
+    }
+
+    fn issue(&mut self, validator: &mut Validator, actions: &[Action], issuer: &ActionIssuer, issued: &mut BTreeSet<ActionId>) {
+        let field = match issuer {
+            ActionIssuer::Order(_) => "actions",
+            ActionIssuer::InstantActions(_) => "instantActions",
+        };
+        validator.elements(field, actions, |validator, action| {
+            let previous = self.issuers.get(&action.action_id).filter(|previous| !matches!((previous, issuer), (ActionIssuer::Order(_), ActionIssuer::Order(_))));
+            if !issued.insert(action.action_id.clone()) {
+                validator.field("actionId", |validator| {
+                    validator.violation(RULE_DUPLICATE_ID, format!("action id `{}` is used more than once by {issuer}", action.action_id));
+                });
+            }
+            else if let Some(previous) = previous {
+                validator.field("actionId", |validator| {
+                    validator.violation(RULE_DUPLICATE_ID, format!("action id `{}` was already issued by {previous}", action.action_id));
+                });
+            }
+            else {
+                self.issuers.insert(action.action_id.clone(), issuer.clone());
+            }
+        });
+    }
+}
+
+impl ConnectionState {
+
+    /// Returns `true` if the connection may change from this state to `next`, which is the case for
+    /// - `ONLINE` to `OFFLINE` or `CONNECTIONBROKEN`,
+    /// - `OFFLINE` or `CONNECTIONBROKEN` to `ONLINE`.
+    ///
+    /// Remaining in the same state is always allowed, e.g. due to a retained message being delivered again.
+    pub fn can_transition_to(&self, next: &ConnectionState) -> bool {
+        use ConnectionState::*;
+        *self == *next || matches!((self, next), (Online, Offline | ConnectionBroken) | (Offline | ConnectionBroken, Online))
+    }
+
+    /// Returns the name of the state as used in messages, e.g. `ONLINE`.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            ConnectionState::Online => "ONLINE",
+            ConnectionState::Offline => "OFFLINE",
+            ConnectionState::ConnectionBroken => "CONNECTIONBROKEN",
+        }
+    }
+}
+
+/// Tracks the connection state of an AGV and the time of its last message to judge whether it is alive.
+#[derive(Clone, Default)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct ConnectionTracker {
+    connection_state: Option<ConnectionState>,
+    last_seen: Option<i64>,
+}
+
+impl ConnectionTracker {
+
+    /// Creates a tracker which has not observed any message yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the last observed connection state.
+    pub fn connection_state(&self) -> Option<ConnectionState> {
+        self.connection_state
+    }
+
+    /// Records the connection state of `connection` and returns a violation of [`RULE_ILLEGAL_TRANSITION`] if it
+    /// changed illegally since the previously observed connection message, see [`ConnectionState::can_transition_to`].
+    pub fn observe(&mut self, connection: &Connection) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        if let Some(previous) = self.connection_state.filter(|previous| !previous.can_transition_to(&connection.connection_state)) {
+            validator.field("connectionState", |validator| {
+                validator.violation(RULE_ILLEGAL_TRANSITION, format!("connection changed from {} to {}", previous.name(), connection.connection_state.name()));
+            });
+        }
+        self.connection_state = Some(connection.connection_state);
+        self.touch(&connection.timestamp);
+        validator.into_violations()
+    }
+
+    /// Records the timestamp of any other message of the AGV, e.g. a state, as a sign of life.
+    pub fn touch(&mut self, timestamp: &Timestamp) {
+        if let Some(millis) = crate::timestamp::to_unix_millis(timestamp) {
+            self.last_seen = Some(self.last_seen.map_or(millis, |last_seen| last_seen.max(millis)));
+        }
+    }
+
+    /// Returns `true` if the last connection state is `ONLINE` and the last message of the AGV is not older than
+    /// `timeout` at `now`.
+    pub fn is_online(&self, now: &Timestamp, timeout: Duration) -> bool {
+        let timeout = i64::try_from(timeout.as_millis()).unwrap_or(i64::MAX);
+        self.connection_state == Some(ConnectionState::Online)
+            && self.last_seen.zip(crate::timestamp::to_unix_millis(now)).is_some_and(|(last_seen, now)| now.saturating_sub(last_seen) <= timeout)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::time::Duration;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::action::{Action, BlockingType};
+    use crate::checked::checked;
+    use crate::connection::{Connection, ConnectionState};
+    use crate::instant_actions::InstantActions;
+    use crate::order::{Node, Order};
+    use crate::state::{ActionState, ActionStatus, BatteryState, EStop, OperatingMode, SafetyState, State};
+    use crate::timestamp::from_unix_millis;
+    use crate::validation::{RULE_DUPLICATE_ID, RULE_ILLEGAL_TRANSITION};
+    use super::{ActionIdTracker, ActionIssuer, ActionStatusTracker, ConnectionTracker};
+
+    fn state(order_id: &str, action_states: &[(&str, ActionStatus)]) -> State {
+        State {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            order_id: order_id.into(),
+            order_update_id: 0,
+            zone_set_id: None,
+            last_node_id: "".into(),
+            last_node_sequence_id: 0,
+            driving: false,
+            paused: None,
+            new_base_request: None,
+            distance_since_last_node: None,
+            operating_mode: OperatingMode::Automatic,
+            node_states: vec![],
+            edge_states: vec![],
+            agv_position: None,
+            velocity: None,
+            loads: vec![],
+            action_states: action_states.iter().map(|(action_id, action_status)| ActionState {
+                action_id: (*action_id).into(),
+                action_type: None,
+                action_description: None,
+                action_status: *action_status,
+                result_description: None,
+            }).collect(),
+            battery_state: BatteryState { battery_charge: checked(80.0), battery_voltage: None, battery_health: None, charging: false, reach: None },
+            errors: vec![],
+            information: vec![],
+            safety_state: SafetyState { e_stop: EStop::None, field_violation: false },
+        }
+    }
+
+    #[rstest]
+    #[case(ActionStatus::Waiting, ActionStatus::Initializing, true)]
+    #[case(ActionStatus::Waiting, ActionStatus::Failed, true)]
+    #[case(ActionStatus::Waiting, ActionStatus::Paused, false)]
+    #[case(ActionStatus::Initializing, ActionStatus::Waiting, false)]
+    #[case(ActionStatus::Running, ActionStatus::Paused, true)]
+    #[case(ActionStatus::Paused, ActionStatus::Running, true)]
+    #[case(ActionStatus::Paused, ActionStatus::Finished, false)]
+    #[case(ActionStatus::Finished, ActionStatus::Finished, true)]
+    #[case(ActionStatus::Finished, ActionStatus::Running, false)]
+    #[case(ActionStatus::Failed, ActionStatus::Waiting, false)]
+    fn test_ActionStatus_can_transition_to(#[case] current: ActionStatus, #[case] next: ActionStatus, #[case] expected: bool) {
+        assert_that!(current.can_transition_to(&next), eq(expected));
+    }
+
+    #[rstest]
+    fn test_ActionStatusTracker_reports_illegal_transitions() {
+        let mut tracker = ActionStatusTracker::new();
+
+        let first = tracker.observe(&state("o1", &[("a1", ActionStatus::Running), ("a2", ActionStatus::Finished)]));
+        let second = tracker.observe(&state("o1", &[("a1", ActionStatus::Paused), ("a2", ActionStatus::Running), ("a3", ActionStatus::Running)]));
+
+        assert_that!(first, empty());
+        assert_that!(second.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![
+            ("/actionStates/1/actionStatus", RULE_ILLEGAL_TRANSITION),
+        ]));
+        assert_that!(tracker.status("a2"), some(eq(ActionStatus::Running)));
+    }
+
+    #[rstest]
+    fn test_ActionStatusTracker_forgets_statuses_of_previous_order() {
+        let mut tracker = ActionStatusTracker::new();
+
+        tracker.observe(&state("o1", &[("a1", ActionStatus::Finished)]));
+        let violations = tracker.observe(&state("o2", &[("a1", ActionStatus::Waiting)]));
+
+        assert_that!(violations, empty());
+    }
+
+    fn action(action_id: &str) -> Action {
+        Action {
+            action_type: "beep".into(),
+            action_id: action_id.into(),
+            action_description: None,
+            blocking_type: BlockingType::None,
+            action_parameters: vec![],
+        }
+    }
+
+    fn order(order_id: &str, order_update_id: u64, action_ids: &[&str]) -> Order {
+        Order {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            order_id: order_id.into(),
+            order_update_id,
+            zone_set_id: None,
+            nodes: vec![Node {
+                node_id: "n1".into(),
+                sequence_id: 0,
+                node_description: None,
+                released: true,
+                node_position: None,
+                actions: action_ids.iter().map(|action_id| action(action_id)).collect(),
+            }],
+            edges: vec![],
+        }
+    }
+
+    fn instant_actions(header_id: u64, action_ids: &[&str]) -> InstantActions {
+        InstantActions {
+            header_id,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            instant_actions: action_ids.iter().map(|action_id| action(action_id)).collect(),
+        }
+    }
+
+    #[rstest]
+    fn test_ActionIdTracker_reports_instant_actions_reusing_order_action_ids() {
+        let mut tracker = ActionIdTracker::new();
+
+        let first = tracker.observe_order(&order("o1", 0, &["a1", "a2"]));
+        let second = tracker.observe_instant_actions(&instant_actions(1, &["i1", "a2"]));
+
+        assert_that!(first, empty());
+        assert_that!(second.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![
+            ("/instantActions/1/actionId", RULE_DUPLICATE_ID),
+        ]));
+        assert_that!(tracker.issuer("a2"), some(eq(&ActionIssuer::Order("o1".into()))));
+        assert_that!(tracker.issuer("i1"), some(eq(&ActionIssuer::InstantActions(1))));
+    }
+
+    #[rstest]
+    #[case::order_update(order("o1", 1, &["a1", "i1"]), vec!["/nodes/0/actions/1/actionId"])]
+    #[case::duplicate_within_order(order("o1", 1, &["a1", "a1"]), vec!["/nodes/0/actions/1/actionId"])]
+    #[case::new_order(order("o2", 0, &["a1", "i1"]), vec![])]
+    fn test_ActionIdTracker_observe_order(#[case] next: Order, #[case] expected: Vec<&str>) {
+        let mut tracker = ActionIdTracker::new();
+        tracker.observe_order(&order("o1", 0, &["a1"]));
+        tracker.observe_instant_actions(&instant_actions(1, &["i1"]));
+
+        let violations = tracker.observe_order(&next);
+
+        assert_that!(violations.iter().map(|violation| violation.path.as_str()).collect::<Vec<_>>(), eq(&expected));
+    }
+
+    #[rstest]
+    fn test_ActionIdTracker_reports_instant_actions_reusing_instant_action_ids() {
+        let mut tracker = ActionIdTracker::new();
+
+        tracker.observe_instant_actions(&instant_actions(1, &["i1"]));
+        let violations = tracker.observe_instant_actions(&instant_actions(2, &["i2", "i2", "i1"]));
+
+        assert_that!(violations.iter().map(|violation| violation.path.as_str()).collect::<Vec<_>>(), eq(&vec![
+            "/instantActions/1/actionId",
+            "/instantActions/2/actionId",
+        ]));
+    }
+
+    fn connection(millis: i64, connection_state: ConnectionState) -> Connection {
+        Connection {
+            header_id: 1,
+            timestamp: from_unix_millis(millis).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            connection_state,
+        }
+    }
+
+    #[rstest]
+    #[case(ConnectionState::Online, ConnectionState::Offline, true)]
+    #[case(ConnectionState::Online, ConnectionState::ConnectionBroken, true)]
+    #[case(ConnectionState::Offline, ConnectionState::Online, true)]
+    #[case(ConnectionState::ConnectionBroken, ConnectionState::Online, true)]
+    #[case(ConnectionState::Offline, ConnectionState::ConnectionBroken, false)]
+    #[case(ConnectionState::ConnectionBroken, ConnectionState::Offline, false)]
+    #[case(ConnectionState::Offline, ConnectionState::Offline, true)]
+    fn test_ConnectionState_can_transition_to(#[case] current: ConnectionState, #[case] next: ConnectionState, #[case] expected: bool) {
+        assert_that!(current.can_transition_to(&next), eq(expected));
+    }
+
+    #[rstest]
+    fn test_ConnectionTracker_reports_illegal_transitions() {
+        let mut tracker = ConnectionTracker::new();
+
+        let first = tracker.observe(&connection(0, ConnectionState::Offline));
+        let second = tracker.observe(&connection(1000, ConnectionState::ConnectionBroken));
+
+        assert_that!(first, empty());
+        assert_that!(second.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![
+            ("/connectionState", RULE_ILLEGAL_TRANSITION),
+        ]));
+        assert_that!(tracker.connection_state(), some(eq(ConnectionState::ConnectionBroken)));
+    }
+
+    #[rstest]
+    #[case::recent(ConnectionState::Online, 9_000, 2_000, true)]
+    #[case::at_timeout(ConnectionState::Online, 5_000, 5_000, true)]
+    #[case::timed_out(ConnectionState::Online, 1_000, 2_000, false)]
+    #[case::offline(ConnectionState::Offline, 9_000, 5_000, false)]
+    fn test_ConnectionTracker_is_online(#[case] connection_state: ConnectionState, #[case] touched: i64, #[case] timeout: u64, #[case] expected: bool) {
+        let mut tracker = ConnectionTracker::new();
+
+        tracker.observe(&connection(0, connection_state));
+        tracker.touch(&from_unix_millis(touched).unwrap());
+
+        assert_that!(tracker.is_online(&from_unix_millis(10_000).unwrap(), Duration::from_millis(timeout)), eq(expected));
+    }
+}
@@ -0,0 +1,229 @@
+//!
+//! Enforcement of the maximum message length by shortening free-text fields before publishing.
+//!
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::factsheet::MaxStringLens;
+use crate::instant_actions::InstantActions;
+use crate::order::Order;
+use crate::state::State;
+use crate::validation::{RULE_TOO_LONG, Violation};
+
+/// How to handle a message exceeding the maximum message length `msgLen`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum Overflow {
+    /// Shortens free-text fields, longest first, until the message fits.
+    Truncate,
+    /// Leaves the message unchanged and returns a violation.
+    Reject,
+}
+
+/// Returns the free-text fields of a message, e.g. descriptions, which can be shortened without changing its meaning.
+trait FreeText {
+    fn free_texts(&mut self) -> Vec<&mut String>;
+
+    fn serialized_len(&self) -> usize;
+}
+
+impl FreeText for Order {
+
+    fn free_texts(&mut self) -> Vec<&mut String> {
+        let mut texts = Vec::new();
+        for node in &mut self.nodes {
+            texts.extend(node.node_description.as_mut());
+            texts.extend(node.node_position.as_mut().and_then(|position| position.map_description.as_mut()));
+            texts.extend(node.actions.iter_mut().filter_map(|action| action.action_description.as_mut()));
+        }
+        for edge in &mut self.edges {
+            texts.extend(edge.edge_description.as_mut());
+            texts.extend(edge.actions.iter_mut().filter_map(|action| action.action_description.as_mut()));
+        }
+        texts
+    }
+
+    fn serialized_len(&self) -> usize {
+        crate::json::serialized_len(self)
+    }
+}
+
+impl FreeText for InstantActions {
+
+    fn free_texts(&mut self) -> Vec<&mut String> {
+        self.instant_actions.iter_mut().filter_map(|action| action.action_description.as_mut()).collect()
+    }
+
+    fn serialized_len(&self) -> usize {
+        crate::json::serialized_len(self)
+    }
+}
+
+impl FreeText for State {
+
+    fn free_texts(&mut self) -> Vec<&mut String> {
+        let mut texts = Vec::new();
+        texts.extend(self.node_states.iter_mut().filter_map(|node| node.node_description.as_mut()));
+        texts.extend(self.edge_states.iter_mut().filter_map(|edge| edge.edge_description.as_mut()));
+        for action in &mut self.action_states {
+            texts.extend(action.action_description.as_mut());
+            texts.extend(action.result_description.as_mut());
+        }
+        texts.extend(self.errors.iter_mut().filter_map(|error| error.error_description.as_mut()));
+        texts.extend(self.information.iter_mut().filter_map(|information| information.info_description.as_mut()));
+        texts.extend(self.agv_position.as_mut().and_then(|position| position.map_description.as_mut()));
+        texts
+    }
+
+    fn serialized_len(&self) -> usize {
+        crate::json::serialized_len(self)
+    }
+}
+
+fn enforce<T: FreeText>(message: &mut T, lens: &MaxStringLens, overflow: Overflow) -> Result<(), Violation> {
+    let Some(max) = lens.msg_len.filter(|max| *max != 0).map(|max| usize::try_from(max).unwrap_or(usize::MAX)) else {
+        return Ok(());
+    };
+    loop {
+        let len = message.serialized_len();
+        if len <= max {
+            return Ok(());
+        }
+        let too_long = || Violation {
+            path: String::new(),
+            rule: RULE_TOO_LONG,
+            message: format!("message of {len} bytes exceeds the maximum of {max}"),
+        };
+        if overflow == Overflow::Reject {
+            return Err(too_long());
+        }
+        let mut texts = message.free_texts();
+        let Some(text) = texts.iter_mut().filter(|text| !text.is_empty()).max_by_key(|text| text.len()) else {
+            return Err(too_long());
+        };
+        // Removing a byte shortens the serialized message by at least one byte, escaped characters by more.
+        let mut end = text.len().saturating_sub(len - max);
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+    }
+}
+
+macro_rules! enforce_limits {
+    ($($message:ty),*) => {
+        $(
+            impl $message {
+
+                /// Ensures the message does not exceed the maximum message length `msgLen` when serialized as JSON.
+                ///
+                /// If the message is too long, free-text fields like descriptions are shortened, longest first, or the
+                /// message is rejected depending on `overflow`. Returns a violation if the message is rejected or still
+                /// exceeds the limit with all free-text fields emptied. A maximum length which is absent or zero means
+                /// no limit.
+                pub fn enforce_limits(&mut self, lens: &MaxStringLens, overflow: Overflow) -> Result<(), Violation> {
+                    enforce(self, lens, overflow)
+                }
+            }
+        )*
+    };
+}
+
+enforce_limits!(InstantActions, Order, State);
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::action::{Action, BlockingType};
+    use crate::factsheet::MaxStringLens;
+    use crate::instant_actions::InstantActions;
+    use crate::timestamp::from_unix_millis;
+    use crate::validation::RULE_TOO_LONG;
+    use super::Overflow;
+
+    fn action(action_id: &str, action_description: Option<&str>) -> Action {
+        Action {
+            action_type: "pick".into(),
+            action_id: action_id.into(),
+            action_description: action_description.map(String::from),
+            blocking_type: BlockingType::Hard,
+            action_parameters: vec![],
+        }
+    }
+
+    fn instant_actions() -> InstantActions {
+        InstantActions {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            instant_actions: vec![
+                action("a1", Some("short")),
+                action("a2", Some("a rather long description ääää which does not fit")),
+                action("a3", None),
+            ],
+        }
+    }
+
+    fn lens(msg_len: Option<u64>) -> MaxStringLens {
+        MaxStringLens {
+            msg_len,
+            topic_serial_len: None,
+            topic_elem_len: None,
+            id_len: None,
+            id_numerical_only: None,
+            enum_len: None,
+            load_id_len: None,
+        }
+    }
+
+    #[rstest]
+    fn test_InstantActions_enforce_limits_truncates_longest_description() {
+        let mut instant_actions = instant_actions();
+        let max = instant_actions.serialized_len_estimate() - 30;
+
+        let result = instant_actions.enforce_limits(&lens(Some(max as u64)), Overflow::Truncate);
+
+        assert_that!(result, ok(anything()));
+        assert_that!(instant_actions.serialized_len_estimate(), le(max));
+        assert_that!(instant_actions.instant_actions[0].action_description, some(eq("short")));
+        assert_that!(instant_actions.instant_actions[1].action_description.as_deref().unwrap().len(), lt(51));
+    }
+
+    #[rstest]
+    fn test_InstantActions_enforce_limits_rejects() {
+        let mut instant_actions = instant_actions();
+        let max = instant_actions.serialized_len_estimate() - 1;
+
+        let result = instant_actions.enforce_limits(&lens(Some(max as u64)), Overflow::Reject);
+
+        assert_that!(result, err(matches_pattern!(crate::validation::Violation { path: eq(""), rule: eq(&RULE_TOO_LONG) })));
+        assert_that!(instant_actions.instant_actions[1].action_description.as_deref(), some(eq("a rather long description ääää which does not fit")));
+    }
+
+    #[rstest]
+    fn test_InstantActions_enforce_limits_fails_without_free_text() {
+        let mut instant_actions = instant_actions();
+
+        let result = instant_actions.enforce_limits(&lens(Some(64)), Overflow::Truncate);
+
+        assert_that!(result, err(anything()));
+        assert_that!(instant_actions.instant_actions[1].action_description, some(eq("")));
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some(0))]
+    fn test_InstantActions_enforce_limits_without_limit(#[case] msg_len: Option<u64>) {
+        let mut instant_actions = instant_actions();
+
+        assert_that!(instant_actions.enforce_limits(&lens(msg_len), Overflow::Reject), ok(anything()));
+    }
+}
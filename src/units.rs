@@ -0,0 +1,213 @@
+//!
+//! Newtypes of physical quantities in the SI units required by the specification.
+//!
+//! If the feature `units` is enabled, the following fields of the factsheet use these newtypes instead of `f32`, so
+//! that a length in millimeters cannot be passed where meters are expected without an explicit conversion:
+//!
+//! | Field                                                                                                          | Type                       |
+//! | -------------------------------------------------------------------------------------------------------------- | -------------------------- |
+//! | `PhysicalParameters::height_min`, `height_max`, `width`, `length`, `LoadSet::min_loadhandling_height`, `max_loadhandling_height`, `min_loadhandling_depth`, `max_loadhandling_depth` | [`Meters`] |
+//! | `PhysicalParameters::speed_min`, `speed_max`, `LoadSet::agv_speed_limit`                                       | [`MetersPerSecond`]        |
+//! | `PhysicalParameters::acceleration_max`, `deceleration_max`, `LoadSet::agv_acceleration_limit`, `agv_deceleration_limit` | [`MetersPerSecondSquared`] |
+//! | `TypeSpecification::max_load_mass`, `LoadSet::max_weigth`                                                      | [`Kilograms`]              |
+//!
+//! The aliases [`Length`], [`Speed`], [`Acceleration`] and [`Mass`] resolve to the type used by the fields.
+//!
+use crate::checked::{OutOfRange, Raw};
+
+/// Type of lengths, which is [`Meters`] if the feature `units` is enabled and [`Float`](crate::finite::Float)
+/// otherwise.
+#[cfg(feature = "units")]
+pub type Length = Meters;
+
+/// Type of lengths, which is [`Meters`] if the feature `units` is enabled and [`Float`](crate::finite::Float)
+/// otherwise.
+#[cfg(not(feature = "units"))]
+pub type Length = crate::finite::Float;
+
+/// Type of speeds, which is [`MetersPerSecond`] if the feature `units` is enabled and
+/// [`Float`](crate::finite::Float) otherwise.
+#[cfg(feature = "units")]
+pub type Speed = MetersPerSecond;
+
+/// Type of speeds, which is [`MetersPerSecond`] if the feature `units` is enabled and
+/// [`Float`](crate::finite::Float) otherwise.
+#[cfg(not(feature = "units"))]
+pub type Speed = crate::finite::Float;
+
+/// Type of accelerations, which is [`MetersPerSecondSquared`] if the feature `units` is enabled and
+/// [`Float`](crate::finite::Float) otherwise.
+#[cfg(feature = "units")]
+pub type Acceleration = MetersPerSecondSquared;
+
+/// Type of accelerations, which is [`MetersPerSecondSquared`] if the feature `units` is enabled and
+/// [`Float`](crate::finite::Float) otherwise.
+#[cfg(not(feature = "units"))]
+pub type Acceleration = crate::finite::Float;
+
+/// Type of masses, which is [`Kilograms`] if the feature `units` is enabled and [`Float`](crate::finite::Float)
+/// otherwise.
+#[cfg(feature = "units")]
+pub type Mass = Kilograms;
+
+/// Type of masses, which is [`Kilograms`] if the feature `units` is enabled and [`Float`](crate::finite::Float)
+/// otherwise.
+#[cfg(not(feature = "units"))]
+pub type Mass = crate::finite::Float;
+
+macro_rules! unit {
+    ($(#[$doc:meta])* $name:ident, $unit:literal) => {
+
+        $(#[$doc])*
+        #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+        #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+        #[cfg_attr(feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(transparent)
+        )]
+        pub struct $name(pub f32);
+
+        impl $name {
+
+            /// Returns the value in
+            #[doc = concat!($unit, ".")]
+            pub fn get(self) -> f32 {
+                self.0
+            }
+        }
+
+        impl From<$name> for f32 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, formatter)?;
+                formatter.write_str(concat!(" ", $unit))
+            }
+        }
+
+        impl Raw for $name {
+
+            fn try_from_raw(value: f32) -> Result<Self, OutOfRange> {
+                if value.is_finite() {
+                    Ok(Self(value))
+                }
+                else {
+                    Err(OutOfRange { value, min: f32::MIN, max: f32::MAX })
+                }
+            }
+
+            fn raw(self) -> f32 {
+                self.0
+            }
+        }
+
+        #[cfg(feature = "strict-floats")]
+        impl crate::float::Finite for $name {
+            fn is_finite(&self) -> bool {
+                self.0.is_finite()
+            }
+        }
+    };
+}
+
+unit! {
+    /// Length in meters, e.g. the width of an AGV.
+    Meters, "m"
+}
+
+unit! {
+    /// Speed in meters per second, e.g. the maximum speed of an AGV.
+    MetersPerSecond, "m/s"
+}
+
+unit! {
+    /// Acceleration in meters per square second, e.g. the maximum deceleration of an AGV.
+    MetersPerSecondSquared, "m/s²"
+}
+
+unit! {
+    /// Mass in kilograms, e.g. the maximum load mass of an AGV.
+    Kilograms, "kg"
+}
+
+impl Meters {
+
+    /// Returns the length of `millimeters`.
+    pub fn from_millimeters(millimeters: f32) -> Self {
+        Meters(millimeters / 1000.0)
+    }
+
+    /// Returns the length of `centimeters`.
+    pub fn from_centimeters(centimeters: f32) -> Self {
+        Meters(centimeters / 100.0)
+    }
+
+    /// Returns the length in millimeters.
+    pub fn to_millimeters(self) -> f32 {
+        self.0 * 1000.0
+    }
+}
+
+impl MetersPerSecond {
+
+    /// Returns the speed of `kilometers_per_hour`.
+    pub fn from_kilometers_per_hour(kilometers_per_hour: f32) -> Self {
+        MetersPerSecond(kilometers_per_hour / 3.6)
+    }
+
+    /// Returns the speed in kilometers per hour.
+    pub fn to_kilometers_per_hour(self) -> f32 {
+        self.0 * 3.6
+    }
+}
+
+impl Kilograms {
+
+    /// Returns the mass of `tonnes`.
+    pub fn from_tonnes(tonnes: f32) -> Self {
+        Kilograms(tonnes * 1000.0)
+    }
+
+    /// Returns the mass in tonnes.
+    pub fn to_tonnes(self) -> f32 {
+        self.0 / 1000.0
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::string::ToString;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::Raw;
+    use super::{Kilograms, Meters, MetersPerSecond, MetersPerSecondSquared};
+
+    #[rstest]
+    fn test_units_convert_from_other_units() {
+        assert_that!(Meters::from_millimeters(1200.0), eq(Meters(1.2)));
+        assert_that!(Meters::from_centimeters(50.0), eq(Meters(0.5)));
+        assert_that!(MetersPerSecond::from_kilometers_per_hour(7.2), eq(MetersPerSecond(2.0)));
+        assert_that!(Kilograms::from_tonnes(1.5), eq(Kilograms(1500.0)));
+        assert_that!(Kilograms(1500.0).to_tonnes(), eq(1.5));
+    }
+
+    #[rstest]
+    fn test_units_display_their_unit() {
+        assert_that!(Meters(1.5).to_string(), eq("1.5 m"));
+        assert_that!(MetersPerSecondSquared(0.5).to_string(), eq("0.5 m/s²"));
+    }
+
+    #[rstest]
+    fn test_units_reject_non_finite_raw_values() {
+        assert_that!(Meters::try_from_raw(f32::NAN).is_err(), eq(true));
+        assert_that!(Meters::try_from_raw(2.0).map(Raw::raw), ok(eq(2.0)));
+    }
+}
@@ -0,0 +1,167 @@
+//!
+//! Stitching of order updates to the order they update, following the order update rules of the specification.
+//!
+use alloc::vec::Vec;
+
+use crate::order::Order;
+
+impl Order {
+
+    /// Returns the order resulting from stitching `update` to this order.
+    ///
+    /// The first node of the update has to be the last base node of this order, i.e. its last released node, with the
+    /// same node id and sequence id. The resulting order
+    /// - keeps the base of this order, whereas its horizon is discarded,
+    /// - appends the actions of the first node of the update to the last base node, except for actions whose ids are
+    ///   already contained,
+    /// - appends the remaining nodes and edges of the update, i.e. the new base and horizon,
+    /// - takes the header, order update id and zone set of the update.
+    pub fn apply_update(&self, update: Order) -> Result<Order, UpdateError> {
+        if update.order_id != self.order_id {
+            return Err(UpdateError::DifferentOrder);
+        }
+        if update.order_update_id <= self.order_update_id {
+            return Err(UpdateError::Outdated { order_update_id: update.order_update_id, current_order_update_id: self.order_update_id });
+        }
+        let Some(last_base_node) = self.nodes.iter().filter(|node| node.released).max_by_key(|node| node.sequence_id) else {
+            return Err(UpdateError::NoBase);
+        };
+        let stitching_sequence_id = last_base_node.sequence_id;
+        match update.nodes.iter().min_by_key(|node| node.sequence_id) {
+            Some(first) if first.node_id == last_base_node.node_id && first.sequence_id == stitching_sequence_id => {}
+            _ => return Err(UpdateError::NotStitching { sequence_id: stitching_sequence_id }),
+        }
+
+        let mut nodes = self.nodes.iter()
+            .filter(|node| node.released && node.sequence_id <= stitching_sequence_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut edges = self.edges.iter()
+            .filter(|edge| edge.released && edge.sequence_id < stitching_sequence_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        for node in update.nodes {
+            if node.sequence_id > stitching_sequence_id {
+                nodes.push(node);
+            }
+            else if let Some(stitching) = nodes.iter_mut().find(|stitching| stitching.sequence_id == stitching_sequence_id) {
+                for action in node.actions {
+                    if !stitching.actions.iter().any(|other| other.action_id == action.action_id) {
+                        stitching.actions.push(action);
+                    }
+                }
+            }
+        }
+        edges.extend(update.edges.into_iter().filter(|edge| edge.sequence_id > stitching_sequence_id));
+        nodes.sort_by_key(|node| node.sequence_id);
+        edges.sort_by_key(|edge| edge.sequence_id);
+
+        Ok(Order {
+            header_id: update.header_id,
+            timestamp: update.timestamp,
+            version: update.version,
+            manufacturer: update.manufacturer,
+            serial_number: update.serial_number,
+            order_id: update.order_id,
+            order_update_id: update.order_update_id,
+            zone_set_id: update.zone_set_id,
+            nodes,
+            edges,
+        })
+    }
+}
+
+/// Error returned when an order update cannot be stitched to an order, see [`Order::apply_update`].
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub enum UpdateError {
+    /// The update has a different order id, i.e. it is a new order instead of an update.
+    DifferentOrder,
+    /// The order update id of the update is not greater than the one of the order.
+    Outdated {
+        /// Order update id of the update.
+        order_update_id: u64,
+        /// Order update id of the updated order.
+        current_order_update_id: u64,
+    },
+    /// The order has no released node the update could be stitched to.
+    NoBase,
+    /// The first node of the update is not the last base node of the order.
+    NotStitching {
+        /// Sequence id of the last base node of the order.
+        sequence_id: u64,
+    },
+}
+
+impl core::fmt::Display for UpdateError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UpdateError::DifferentOrder => write!(formatter, "update belongs to a different order"),
+            UpdateError::Outdated { order_update_id, current_order_update_id } => write!(formatter, "order update {order_update_id} is not newer than the current order update {current_order_update_id}"),
+            UpdateError::NoBase => write!(formatter, "order does not contain any released node"),
+            UpdateError::NotStitching { sequence_id } => write!(formatter, "first node of the update does not match the last base node {sequence_id}"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::action::Action;
+    use crate::order::Order;
+    use crate::timestamp::from_unix_millis;
+    use super::UpdateError;
+
+    fn order() -> Order {
+        Order::builder("o1", from_unix_millis(0).unwrap())
+            .node("n1")
+            .edge_to("e1", "n2")
+            .node_action(Action::start_pause("a1"))
+            .horizon_node("e2", "n3")
+            .build()
+            .unwrap()
+    }
+
+    fn update(order_update_id: u64, first_node_id: &str, first_sequence_id: u64) -> Order {
+        Order::builder("o1", from_unix_millis(1000).unwrap())
+            .header_id(1)
+            .order_update(order_update_id, first_sequence_id)
+            .node(first_node_id)
+            .node_action(Action::start_pause("a1"))
+            .node_action(Action::stop_pause("a2"))
+            .edge_to("e2", "n3")
+            .horizon_node("e3", "n4")
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_Order_apply_update_stitches_update_to_base() {
+        let order = order().apply_update(update(1, "n2", 2));
+
+        assert_that!(order, ok(matches_pattern!(Order {
+            header_id: eq(&1),
+            order_update_id: eq(&1),
+        })));
+        let order = order.unwrap();
+        let nodes = order.nodes.iter().map(|node| (node.node_id.as_str(), node.sequence_id, node.released)).collect::<Vec<_>>();
+        let edges = order.edges.iter().map(|edge| (edge.edge_id.as_str(), edge.sequence_id, edge.released)).collect::<Vec<_>>();
+        assert_that!(nodes, eq(&vec![("n1", 0, true), ("n2", 2, true), ("n3", 4, true), ("n4", 6, false)]));
+        assert_that!(edges, eq(&vec![("e1", 1, true), ("e2", 3, true), ("e3", 5, false)]));
+        assert_that!(order.nodes[1].actions.iter().map(|action| action.action_id.as_str()).collect::<Vec<_>>(), eq(&vec!["a1", "a2"]));
+    }
+
+    #[rstest]
+    #[case::different_order(Order { order_id: "o2".into(), ..update(1, "n2", 2) }, UpdateError::DifferentOrder)]
+    #[case::outdated(update(0, "n2", 2), UpdateError::Outdated { order_update_id: 0, current_order_update_id: 0 })]
+    #[case::other_node(update(1, "n3", 2), UpdateError::NotStitching { sequence_id: 2 })]
+    #[case::other_sequence_id(update(1, "n2", 4), UpdateError::NotStitching { sequence_id: 2 })]
+    fn test_Order_apply_update_rejects(#[case] update: Order, #[case] expected: UpdateError) {
+        assert_that!(order().apply_update(update), err(eq(&expected)));
+    }
+}
@@ -0,0 +1,1942 @@
+//!
+//! Semantic validation of messages beyond what the type system and deserialization enforce.
+//!
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::action::{Action, ActionParameterValue};
+use crate::checked::Raw;
+use crate::common::{NodePosition, Timestamp, Trajectory};
+use crate::connection::Connection;
+use crate::factsheet::{ActionScope, AgvAction, Envelopes2d, Factsheet, MaxStringLens, PhysicalParameters, PolygonPoint, ProtocolFeatures, ProtocolLimits, ValueDataType, WheelDefinition, WheelType};
+use crate::instant_actions::InstantActions;
+use crate::order::{Edge, Node, Order};
+use crate::state::State;
+use crate::visualization::Visualization;
+
+/// Rule identifier of a required id or name being empty.
+pub const RULE_EMPTY_ID: &str = "empty-id";
+
+/// Rule identifier of a number being outside of its allowed range.
+pub const RULE_OUT_OF_RANGE: &str = "out-of-range";
+
+/// Rule identifier of an edge referring to a node which is not part of the order.
+pub const RULE_UNKNOWN_NODE: &str = "unknown-node";
+
+/// Rule identifier of an id used for different elements.
+pub const RULE_DUPLICATE_ID: &str = "duplicate-id";
+
+/// Rule identifier of nodes and edges which do not form a path along their sequence ids.
+pub const RULE_DISCONNECTED: &str = "disconnected";
+
+/// Rule identifier of nodes or edges whose sequence ids are not strictly increasing in the order of the list.
+pub const RULE_SEQUENCE_ORDER: &str = "sequence-order";
+
+/// Rule identifier of a node with an odd or an edge with an even sequence id.
+pub const RULE_SEQUENCE_PARITY: &str = "sequence-parity";
+
+/// Rule identifier of base nodes and edges whose sequence ids are not consecutive.
+pub const RULE_SEQUENCE_GAP: &str = "sequence-gap";
+
+/// Rule identifier of a released node or edge following a horizon node or edge.
+pub const RULE_RELEASED_AFTER_HORIZON: &str = "released-after-horizon";
+
+/// Rule identifier of an order without released node.
+pub const RULE_NO_BASE_NODE: &str = "no-base-node";
+
+/// Rule identifier of an array with more elements than allowed by the protocol limits of the factsheet.
+pub const RULE_TOO_MANY_ELEMENTS: &str = "too-many-elements";
+
+/// Rule identifier of a string or message longer than allowed by the protocol limits of the factsheet.
+pub const RULE_TOO_LONG: &str = "too-long";
+
+/// Rule identifier of an id containing other than numerical characters although `idNumericalOnly` is set.
+pub const RULE_NOT_NUMERICAL: &str = "not-numerical";
+
+/// Rule identifier of an id containing characters which are not allowed by the [`IdPolicy`].
+pub const RULE_INVALID_CHARACTER: &str = "invalid-character";
+
+/// Rule identifier of an action type not listed in the `agvActions` of the factsheet.
+pub const RULE_UNSUPPORTED_ACTION: &str = "unsupported-action";
+
+/// Rule identifier of an action used in a scope not listed in the `actionScopes` of the factsheet.
+pub const RULE_ACTION_SCOPE: &str = "action-scope";
+
+/// Rule identifier of an action lacking a parameter which is not optional according to the factsheet.
+pub const RULE_MISSING_PARAMETER: &str = "missing-parameter";
+
+/// Rule identifier of an action parameter value not matching the `valueDataType` of the factsheet.
+pub const RULE_PARAMETER_TYPE: &str = "parameter-type";
+
+/// Rule identifier of fields contradicting each other, e.g. a driving AGV with an active emergency stop.
+pub const RULE_INCONSISTENT: &str = "inconsistent";
+
+/// Rule identifier of a polygon with less than three points, duplicate consecutive points or intersecting segments.
+pub const RULE_INVALID_POLYGON: &str = "invalid-polygon";
+
+/// Rule identifier of an optional field which is required in this context, e.g. the orientation of a fixed wheel.
+pub const RULE_MISSING_FIELD: &str = "missing-field";
+
+/// Rule identifier of wheel definitions without an actively driven wheel.
+pub const RULE_NO_DRIVEN_WHEEL: &str = "no-driven-wheel";
+
+/// Rule identifier of a protocol version which is not of the form `[Major].[Minor].[Patch]`.
+pub const RULE_INVALID_VERSION: &str = "invalid-version";
+
+/// Rule identifier of a topic element containing characters reserved by MQTT, i.e. `/`, `+` or `#`.
+pub const RULE_TOPIC_UNSAFE: &str = "topic-unsafe";
+
+/// Rule identifier of a timestamp too far in the future, see [`MAX_TIMESTAMP_AHEAD_MILLIS`].
+pub const RULE_FUTURE_TIMESTAMP: &str = "future-timestamp";
+
+/// Rule identifier of a status changing between consecutive messages in a way not allowed by the specification.
+pub const RULE_ILLEGAL_TRANSITION: &str = "illegal-transition";
+
+/// Rule identifier of a raw message which is not valid JSON or does not conform to the JSON schema of its topic.
+pub const RULE_SCHEMA: &str = "schema";
+
+/// Maximum number of milliseconds the timestamp of a message may be ahead of the current time.
+pub const MAX_TIMESTAMP_AHEAD_MILLIS: i64 = 24 * 60 * 60 * 1000;
+
+/// Rule identifier of a NURBS trajectory which cannot be evaluated, e.g. due to a knot vector of wrong size.
+pub const RULE_MALFORMED_TRAJECTORY: &str = "malformed-trajectory";
+
+/// Rule identifier of an edge the AGV cannot traverse as specified due to its physical parameters, e.g. a `maxSpeed`
+/// above `speedMax`.
+pub const RULE_PHYSICAL_LIMIT: &str = "physical-limit";
+
+/// Number of points a trajectory is sampled at to approximate its curvature.
+const CURVATURE_SAMPLES: usize = 64;
+
+/// A violated rule of a message.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Violation {
+    /// JSON pointer like path of the offending field, e.g. `/nodes/0/actions/1/actionId`.
+    pub path: String,
+    /// Identifier of the violated rule, e.g. [`RULE_EMPTY_ID`].
+    pub rule: &'static str,
+    /// Human readable description of the violation.
+    pub message: String,
+}
+
+impl core::fmt::Display for Violation {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "{}: {} ({})", self.path, self.message, self.rule)
+    }
+}
+
+/// Constraints on the format of ids like `orderId`, `nodeId`, `edgeId` and `actionId`.
+///
+/// A policy mirroring the id constraints of a factsheet can be obtained from its [`MaxStringLens`].
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+pub struct IdPolicy {
+    /// Maximum number of bytes of an id. An absent maximum or a maximum of zero means no limit.
+    pub max_len: Option<u64>,
+    /// Whether ids must contain numerical characters only.
+    pub numerical_only: bool,
+    /// Characters ids may consist of, all characters are allowed if absent.
+    pub allowed_chars: Option<String>,
+}
+
+impl IdPolicy {
+
+    /// Returns all violations of the policy by `id`, whose paths are empty.
+    pub fn check(&self, id: &str) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        validator.policed_id_value(id, self);
+        validator.into_violations()
+    }
+}
+
+impl From<&MaxStringLens> for IdPolicy {
+
+    fn from(lens: &MaxStringLens) -> Self {
+        Self {
+            max_len: lens.id_len,
+            numerical_only: lens.id_numerical_only == Some(true),
+            allowed_chars: None,
+        }
+    }
+}
+
+/// A message which can be checked for semantic rules of the VDA5050 specification.
+pub trait Validate {
+
+    /// Returns all violations of the message, an empty list if the message is valid.
+    fn validate(&self) -> Vec<Violation>;
+}
+
+/// Collects violations while descending into the fields of a message.
+pub(crate) struct Validator {
+    path: String,
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+
+    pub(crate) fn new() -> Self {
+        Self { path: String::new(), violations: Vec::new() }
+    }
+
+    /// Runs `check` for the field `name` of the current value.
+    pub(crate) fn field(&mut self, name: &str, check: impl FnOnce(&mut Self)) {
+        let length = self.path.len();
+        self.path.push('/');
+        self.path.push_str(name);
+        check(self);
+        self.path.truncate(length);
+    }
+
+    /// Runs `check` for each element of `values`, which are the elements of the field `name` of the current value.
+    pub(crate) fn elements<T>(&mut self, name: &str, values: &[T], mut check: impl FnMut(&mut Self, &T)) {
+        self.field(name, |validator| {
+            for (index, value) in values.iter().enumerate() {
+                validator.field(&format!("{index}"), |validator| check(validator, value));
+            }
+        });
+    }
+
+    /// Runs `check` for the element at `index` of the field `name` of the current value.
+    pub(crate) fn element(&mut self, name: &str, index: usize, check: impl FnOnce(&mut Self)) {
+        self.field(name, |validator| validator.field(&format!("{index}"), check));
+    }
+
+    /// Records a violation of `rule` at the current path.
+    pub(crate) fn violation(&mut self, rule: &'static str, message: impl Into<String>) {
+        self.violations.push(Violation { path: self.path.clone(), rule, message: message.into() });
+    }
+
+    /// Records a violation of [`RULE_EMPTY_ID`] if the id `name` of the current value is empty.
+    pub(crate) fn id(&mut self, name: &str, id: &str) {
+        if id.is_empty() {
+            self.field(name, |validator| validator.violation(RULE_EMPTY_ID, "must not be empty"));
+        }
+    }
+
+    /// Records a violation of [`RULE_OUT_OF_RANGE`] if the number `name` of the current value is outside of
+    /// `min..=max`.
+    pub(crate) fn range<T: Copy + PartialOrd + core::fmt::Display>(&mut self, name: &str, value: T, min: T, max: T) {
+        if !(min..=max).contains(&value) {
+            self.field(name, |validator| validator.violation(RULE_OUT_OF_RANGE, format!("{value} is outside of [{min}, {max}]")));
+        }
+    }
+
+    /// Records a violation of [`RULE_TOO_MANY_ELEMENTS`] if the array `name` of the current value has more than `max`
+    /// elements. A maximum of zero means no limit.
+    pub(crate) fn count(&mut self, name: &str, count: usize, max: u32) {
+        if max != 0 && count > max as usize {
+            self.field(name, |validator| validator.violation(RULE_TOO_MANY_ELEMENTS, format!("{count} elements exceed the maximum of {max}")));
+        }
+    }
+
+    /// Records a violation of [`RULE_TOO_LONG`] if the string `name` of the current value has more than `max` bytes.
+    /// An absent maximum or a maximum of zero means no limit.
+    pub(crate) fn length(&mut self, name: &str, value: &str, max: Option<u64>) {
+        if let Some(max) = max.filter(|max| *max != 0 && value.len() as u64 > *max) {
+            self.field(name, |validator| validator.violation(RULE_TOO_LONG, format!("{} bytes exceed the maximum of {max}", value.len())));
+        }
+    }
+
+    /// Records a violation of [`RULE_TOO_LONG`] if the serialized message of `len()` bytes has more than `max` bytes.
+    /// An absent maximum or a maximum of zero means no limit, in which case `len` is not called.
+    #[cfg(feature = "json")]
+    pub(crate) fn message_length(&mut self, len: impl FnOnce() -> usize, max: Option<u64>) {
+        if let Some(max) = max.filter(|max| *max != 0) {
+            let len = len();
+            if len as u64 > max {
+                self.violation(RULE_TOO_LONG, format!("message of {len} bytes exceeds the maximum of {max}"));
+            }
+        }
+    }
+
+    /// Records violations if the id `name` of the current value exceeds `idLen` or is not numerical although
+    /// `idNumericalOnly` is set.
+    pub(crate) fn limited_id(&mut self, name: &str, id: &str, lens: &MaxStringLens) {
+        self.policed_id(name, id, &IdPolicy::from(lens));
+    }
+
+    /// Records violations if the id `name` of the current value does not comply with `policy`.
+    pub(crate) fn policed_id(&mut self, name: &str, id: &str, policy: &IdPolicy) {
+        self.field(name, |validator| validator.policed_id_value(id, policy));
+    }
+
+    fn policed_id_value(&mut self, id: &str, policy: &IdPolicy) {
+        if let Some(max) = policy.max_len.filter(|max| *max != 0 && id.len() as u64 > *max) {
+            self.violation(RULE_TOO_LONG, format!("{} bytes exceed the maximum of {max}", id.len()));
+        }
+        if policy.numerical_only && !id.bytes().all(|byte| byte.is_ascii_digit()) {
+            self.violation(RULE_NOT_NUMERICAL, format!("`{id}` must contain numerical characters only"));
+        }
+        if let Some(allowed_chars) = &policy.allowed_chars {
+            if let Some(character) = id.chars().find(|character| !allowed_chars.contains(*character)) {
+                self.violation(RULE_INVALID_CHARACTER, format!("`{id}` contains the character `{character}` which is not allowed"));
+            }
+        }
+    }
+
+    /// Records a violation of [`RULE_OUT_OF_RANGE`] if the number `name` of the current value is not positive.
+    pub(crate) fn positive<T: Raw>(&mut self, name: &str, value: T) {
+        let value = value.raw();
+        if value.is_nan() || value <= 0.0 {
+            self.field(name, |validator| validator.violation(RULE_OUT_OF_RANGE, format!("{value} must be positive")));
+        }
+    }
+
+    /// Records a violation of [`RULE_OUT_OF_RANGE`] if the number `name` of the current value is negative.
+    pub(crate) fn non_negative<T: Raw>(&mut self, name: &str, value: Option<T>) {
+        if let Some(value) = value.map(Raw::raw).filter(|value| *value < 0.0) {
+            self.field(name, |validator| validator.violation(RULE_OUT_OF_RANGE, format!("{value} must not be negative")));
+        }
+    }
+
+    /// Records a violation of [`RULE_OUT_OF_RANGE`] if the angle `name` of the current value is outside of `[-π, π]`.
+    pub(crate) fn angle<T: Raw>(&mut self, name: &str, angle: Option<T>) {
+        if let Some(angle) = angle.map(Raw::raw) {
+            self.range(name, angle, -core::f32::consts::PI, core::f32::consts::PI);
+        }
+    }
+
+    pub(crate) fn into_violations(self) -> Vec<Violation> {
+        self.violations
+    }
+}
+
+fn validate_node_position(validator: &mut Validator, node_position: &Option<NodePosition>) {
+    if let Some(node_position) = node_position {
+        validator.field("nodePosition", |validator| validator.angle("theta", node_position.theta));
+    }
+}
+
+/// Returns all violations of the header fields shared by all messages, which are
+/// - a `version` not of the form `[Major].[Minor].[Patch]`, optionally followed by semver pre-release and build
+///   identifiers, e.g. `2.0.0` or `2.1.0-rc.1`,
+/// - an empty `manufacturer` or `serial_number`,
+/// - a `manufacturer` or `serial_number` containing `/`, `+` or `#`, which cannot be used as MQTT topic elements,
+/// - a `timestamp` more than [`MAX_TIMESTAMP_AHEAD_MILLIS`] ahead of `now`.
+///
+/// Apart from the timestamp, these checks are part of [`Validate::validate`] of all messages.
+pub fn validate_header(version: &str, manufacturer: &str, serial_number: &str, timestamp: &Timestamp, now: &Timestamp) -> Vec<Violation> {
+    let mut validator = Validator::new();
+    check_header(&mut validator, version, manufacturer, serial_number);
+    let ahead = crate::timestamp::to_unix_millis(timestamp)
+        .zip(crate::timestamp::to_unix_millis(now))
+        .map(|(timestamp, now)| timestamp.saturating_sub(now));
+    if let Some(ahead) = ahead.filter(|ahead| *ahead > MAX_TIMESTAMP_AHEAD_MILLIS) {
+        validator.field("timestamp", |validator| {
+            validator.violation(RULE_FUTURE_TIMESTAMP, format!("timestamp is {ahead} ms ahead of the current time"));
+        });
+    }
+    validator.into_violations()
+}
+
+fn check_header(validator: &mut Validator, version: &str, manufacturer: &str, serial_number: &str) {
+    if !is_semver(version) {
+        validator.field("version", |validator| validator.violation(RULE_INVALID_VERSION, format!("`{version}` is not of the form [Major].[Minor].[Patch]")));
+    }
+    for (name, value) in [("manufacturer", manufacturer), ("serialNumber", serial_number)] {
+        validator.id(name, value);
+        if value.contains(['/', '+', '#']) {
+            validator.field(name, |validator| validator.violation(RULE_TOPIC_UNSAFE, format!("`{value}` must not contain `/`, `+` or `#`")));
+        }
+    }
+}
+
+/// Returns `true` if `version` is a semantic version like `2.0.0`, `2.1.0-rc.1` or `2.0.0+build.5`.
+fn is_semver(version: &str) -> bool {
+    let (version, build) = version.split_once('+').map_or((version, None), |(version, build)| (version, Some(build)));
+    let (core, pre_release) = version.split_once('-').map_or((version, None), |(core, pre_release)| (core, Some(pre_release)));
+    let numeric = |part: &str| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit()) && (part == "0" || !part.starts_with('0'));
+    let identifiers = |identifiers: &str| identifiers.split('.')
+        .all(|identifier| !identifier.is_empty() && identifier.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'-'));
+    let parts = core.split('.').collect::<Vec<_>>();
+    parts.len() == 3
+        && parts.iter().all(|part| numeric(part))
+        && pre_release.map_or(true, identifiers)
+        && build.map_or(true, identifiers)
+}
+
+/// Records violations if the id, type or parameters of the action exceed the protocol limits.
+fn check_action_limits(validator: &mut Validator, action: &Action, limits: &ProtocolLimits) {
+    let strings = &limits.max_string_lens;
+    validator.limited_id("actionId", &action.action_id, strings);
+    validator.length("actionType", &action.action_type, strings.enum_len);
+    validator.count("actionParameters", action.action_parameters.len(), limits.max_array_lens.actions_actions_parameters);
+    validator.elements("actionParameters", &action.action_parameters, |validator, parameter| {
+        validator.length("key", &parameter.key, strings.enum_len);
+    });
+}
+
+/// Records violations if the action is not supported in `scope` according to the factsheet.
+fn check_action_support(validator: &mut Validator, action: &Action, features: &ProtocolFeatures, scope: ActionScope) {
+    let Some(definition) = features.agv_actions.iter().find(|definition| definition.action_type == action.action_type) else {
+        validator.field("actionType", |validator| {
+            validator.violation(RULE_UNSUPPORTED_ACTION, format!("action type `{}` is not supported", action.action_type));
+        });
+        return;
+    };
+    if !definition.action_scopes.contains(&scope) {
+        let scope = match scope {
+            ActionScope::Instant => "INSTANT",
+            ActionScope::Node => "NODE",
+            ActionScope::Edge => "EDGE",
+        };
+        validator.field("actionType", |validator| {
+            validator.violation(RULE_ACTION_SCOPE, format!("action type `{}` is not supported in scope {scope}", action.action_type));
+        });
+    }
+    for parameter in definition.action_parameters.iter().filter(|parameter| parameter.is_optional != Some(true)) {
+        if !action.action_parameters.iter().any(|given| given.key == parameter.key) {
+            validator.field("actionParameters", |validator| {
+                validator.violation(RULE_MISSING_PARAMETER, format!("parameter `{}` is required", parameter.key));
+            });
+        }
+    }
+    check_parameter_types(validator, action, definition, true);
+}
+
+/// Records a violation for each parameter whose value does not match the data type defined by the factsheet.
+fn check_parameter_types(validator: &mut Validator, action: &Action, definition: &AgvAction, coerce_integers: bool) {
+    validator.elements("actionParameters", &action.action_parameters, |validator, parameter| {
+        let Some(value_data_type) = definition.action_parameters.iter()
+            .find(|defined| defined.key == parameter.key)
+            .map(|defined| &defined.value_data_type) else {
+            return;
+        };
+        let matches = match (value_data_type, &parameter.value) {
+            (ValueDataType::Bool, ActionParameterValue::Boolean(_)) => true,
+            (ValueDataType::Number, ActionParameterValue::Integer(_) | ActionParameterValue::Float(_)) => true,
+            (ValueDataType::Integer, ActionParameterValue::Integer(_)) => true,
+            (ValueDataType::Float, ActionParameterValue::Float(_)) => true,
+            (ValueDataType::Float, ActionParameterValue::Integer(_)) => coerce_integers,
+            (ValueDataType::String, ActionParameterValue::String(_)) => true,
+            // Objects and arrays cannot be represented by an ActionParameterValue, thus they are not checked.
+            (ValueDataType::Object | ValueDataType::Array, _) => true,
+            _ => false,
+        };
+        if !matches {
+            let expected = match value_data_type {
+                ValueDataType::Bool => "BOOL",
+                ValueDataType::Number => "NUMBER",
+                ValueDataType::Integer => "INTEGER",
+                ValueDataType::Float => "FLOAT",
+                ValueDataType::String => "STRING",
+                ValueDataType::Object => "OBJECT",
+                ValueDataType::Array => "ARRAY",
+            };
+            validator.field("value", |validator| {
+                validator.violation(RULE_PARAMETER_TYPE, format!("value of parameter `{}` must be of type {expected}", parameter.key));
+            });
+        }
+    });
+}
+
+impl Action {
+
+    /// Returns a violation for each parameter whose value does not match the `valueDataType` of its definition in the
+    /// factsheet. Integers are accepted for parameters of type `FLOAT` if `coerce_integers` is set, e.g. as JSON does
+    /// not distinguish `1` and `1.0`. Parameters not defined by the factsheet and parameters of type `OBJECT` or
+    /// `ARRAY` are not checked.
+    pub fn check_parameter_types(&self, definition: &AgvAction, coerce_integers: bool) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        check_parameter_types(&mut validator, self, definition, coerce_integers);
+        validator.into_violations()
+    }
+}
+
+fn validate_action(validator: &mut Validator, action: &Action) {
+    validator.id("actionId", &action.action_id);
+    validator.id("actionType", &action.action_type);
+}
+
+impl Validate for Connection {
+
+    fn validate(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        check_header(&mut validator, &self.version, &self.manufacturer, &self.serial_number);
+        validator.into_violations()
+    }
+}
+
+impl Validate for Factsheet {
+
+    fn validate(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        check_header(&mut validator, &self.version, &self.manufacturer, &self.serial_number);
+        if let Some(agv_geometry) = &self.agv_geometry {
+            validator.field("agvGeometry", |validator| {
+                validator.elements("wheelDefinitions", &agv_geometry.wheel_definitions, |validator, wheel| wheel.check(validator));
+                if !agv_geometry.wheel_definitions.is_empty() && !agv_geometry.wheel_definitions.iter().any(|wheel| wheel.is_active_driven) {
+                    validator.field("wheelDefinitions", |validator| validator.violation(RULE_NO_DRIVEN_WHEEL, "at least one wheel must be actively driven"));
+                }
+                validator.elements("envelopes2d", &agv_geometry.envelopes2d, |validator, envelope| envelope.check(validator));
+            });
+        }
+        validator.into_violations()
+    }
+}
+
+impl WheelDefinition {
+
+    /// Returns all violations of the wheel, which are
+    /// - a fixed wheel without orientation `position.theta`,
+    /// - a diameter or width which is not positive.
+    ///
+    /// Whether at least one wheel is actively driven is checked by [`Validate::validate`] of the factsheet.
+    pub fn validate(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        self.check(&mut validator);
+        validator.into_violations()
+    }
+
+    fn check(&self, validator: &mut Validator) {
+        if matches!(self.wheel_type, WheelType::Fixed) && self.position.theta.is_none() {
+            validator.field("position", |validator| validator.field("theta", |validator| {
+                validator.violation(RULE_MISSING_FIELD, "orientation is required for fixed wheels");
+            }));
+        }
+        validator.positive("diameter", self.diameter);
+        validator.positive("width", self.width);
+    }
+}
+
+impl Envelopes2d {
+
+    /// Returns all violations of the polygon, which has to be a closed ring without self-intersections, which are
+    /// - less than three distinct points,
+    /// - consecutive points being equal, i.e. segments of zero length,
+    /// - segments intersecting other, non-adjacent segments.
+    ///
+    /// The polygon is closed implicitly, but repeating the first point as last point is accepted as well.
+    pub fn validate(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        self.check(&mut validator);
+        validator.into_violations()
+    }
+
+    fn check(&self, validator: &mut Validator) {
+        let mut points = self.polygon_points.as_slice();
+        if let [first, .., last] = points {
+            if first.x == last.x && first.y == last.y {
+                points = &points[..points.len() - 1];
+            }
+        }
+        if points.len() < 3 {
+            validator.field("polygonPoints", |validator| {
+                validator.violation(RULE_INVALID_POLYGON, format!("{} distinct points given, at least 3 required", points.len()));
+            });
+            return;
+        }
+
+        let count = points.len();
+        let segment = |index: usize| (&points[index], &points[(index + 1) % count]);
+        let mut repeated = false;
+        for index in 0..count {
+            let (start, end) = segment(index);
+            if start.x == end.x && start.y == end.y {
+                repeated = true;
+                validator.element("polygonPoints", (index + 1) % count, |validator| {
+                    validator.violation(RULE_INVALID_POLYGON, format!("point ({}, {}) repeats the preceding point", end.x, end.y));
+                });
+            }
+        }
+        // Segments of zero length make their neighbours touch, thus intersections are only checked without them.
+        if repeated {
+            return;
+        }
+        for first in 0..count {
+            // Adjacent segments share a point, the first and the last segment are adjacent as well.
+            for second in first + 2..count {
+                if first == 0 && second == count - 1 {
+                    continue;
+                }
+                let (a, b) = segment(first);
+                let (c, d) = segment(second);
+                if segments_intersect(a, b, c, d) {
+                    validator.element("polygonPoints", first, |validator| {
+                        validator.violation(RULE_INVALID_POLYGON, format!(
+                            "segment ({}, {}) -> ({}, {}) intersects segment ({}, {}) -> ({}, {}) starting at point {second}",
+                            a.x, a.y, b.x, b.y, c.x, c.y, d.x, d.y));
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if the segments `a -> b` and `c -> d` have at least one point in common.
+fn segments_intersect(a: &PolygonPoint, b: &PolygonPoint, c: &PolygonPoint, d: &PolygonPoint) -> bool {
+    // Sign of the cross product of (q - p) and (r - p), i.e. on which side of p -> q the point r lies.
+    let orientation = |p: &PolygonPoint, q: &PolygonPoint, r: &PolygonPoint| {
+        let (p, q, r) = ((p.x.raw(), p.y.raw()), (q.x.raw(), q.y.raw()), (r.x.raw(), r.y.raw()));
+        let cross = (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0);
+        if cross > 0.0 { 1 } else if cross < 0.0 { -1 } else { 0 }
+    };
+    // Whether r lies within the bounding box of p -> q, which is on the segment if the three points are collinear.
+    let within = |p: &PolygonPoint, q: &PolygonPoint, r: &PolygonPoint| {
+        r.x >= p.x.min(q.x) && r.x <= p.x.max(q.x) && r.y >= p.y.min(q.y) && r.y <= p.y.max(q.y)
+    };
+
+    let (abc, abd, cda, cdb) = (orientation(a, b, c), orientation(a, b, d), orientation(c, d, a), orientation(c, d, b));
+    (abc * abd < 0 && cda * cdb < 0)
+        || (abc == 0 && within(a, b, c))
+        || (abd == 0 && within(a, b, d))
+        || (cda == 0 && within(c, d, a))
+        || (cdb == 0 && within(c, d, b))
+}
+
+impl Validate for InstantActions {
+
+    fn validate(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        check_header(&mut validator, &self.version, &self.manufacturer, &self.serial_number);
+        validator.elements("instantActions", &self.instant_actions, validate_action);
+        validator.into_violations()
+    }
+}
+
+impl InstantActions {
+
+    /// Returns all violations of `policy` by the ids of the actions.
+    pub fn check_ids(&self, policy: &IdPolicy) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        validator.elements("instantActions", &self.instant_actions, |validator, action| validator.policed_id("actionId", &action.action_id, policy));
+        validator.into_violations()
+    }
+
+    /// Returns all violations of the protocol limits of an AGV given by its factsheet, which are
+    /// - more instant actions or action parameters than the maximum array lengths,
+    /// - ids, action types, parameter keys, the serial number and other topic elements longer than the maximum string
+    ///   lengths,
+    /// - action ids containing other than numerical characters if `idNumericalOnly` is set,
+    /// - a serialized message longer than `msgLen` if the `json` feature is enabled.
+    ///
+    /// Limits which are absent or zero are not checked.
+    pub fn check_limits(&self, limits: &ProtocolLimits) -> Vec<Violation> {
+        let strings = &limits.max_string_lens;
+        let mut validator = Validator::new();
+
+        #[cfg(feature = "json")]
+        validator.message_length(|| self.serialized_len_estimate(), strings.msg_len);
+        validator.length("version", &self.version, strings.topic_elem_len);
+        validator.length("manufacturer", &self.manufacturer, strings.topic_elem_len);
+        validator.length("serialNumber", &self.serial_number, strings.topic_serial_len);
+        validator.count("instantActions", self.instant_actions.len(), limits.max_array_lens.instant_actions);
+        validator.elements("instantActions", &self.instant_actions, |validator, action| check_action_limits(validator, action, limits));
+        validator.into_violations()
+    }
+
+    /// Returns all violations of the actions supported by an AGV given by its factsheet, which are
+    /// - action types not listed in `agvActions`,
+    /// - action types which do not list the scope `INSTANT`,
+    /// - actions lacking a parameter which is not optional,
+    /// - parameter values not matching their data type, see [`Action::check_parameter_types`] with integers coerced.
+    pub fn check_actions(&self, features: &ProtocolFeatures) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        validator.elements("instantActions", &self.instant_actions, |validator, action| check_action_support(validator, action, features, ActionScope::Instant));
+        validator.into_violations()
+    }
+
+    /// Appends `action` unless the instant actions would violate `limits` thereby, see [`InstantActions::check_limits`].
+    ///
+    /// Returns the violations of the instant actions including `action` if it is not appended.
+    pub fn push_checked(&mut self, action: Action, limits: &ProtocolLimits) -> Result<(), Vec<Violation>> {
+        self.instant_actions.push(action);
+        let violations = self.check_limits(limits);
+        if violations.is_empty() {
+            Ok(())
+        }
+        else {
+            self.instant_actions.pop();
+            Err(violations)
+        }
+    }
+}
+
+impl Validate for Order {
+
+    fn validate(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        check_header(&mut validator, &self.version, &self.manufacturer, &self.serial_number);
+        validator.id("orderId", &self.order_id);
+        validator.elements("nodes", &self.nodes, |validator, node| {
+            validator.id("nodeId", &node.node_id);
+            validate_node_position(validator, &node.node_position);
+            validator.elements("actions", &node.actions, validate_action);
+        });
+        validator.elements("edges", &self.edges, |validator, edge| {
+            validator.id("edgeId", &edge.edge_id);
+            validator.id("startNodeId", &edge.start_node_id);
+            validator.id("endNodeId", &edge.end_node_id);
+            validator.angle("orientation", edge.orientation);
+            if let Some(trajectory) = &edge.trajectory {
+                validator.field("trajectory", |validator| trajectory.check(validator));
+            }
+            validator.elements("actions", &edge.actions, validate_action);
+        });
+        self.check_structure(&mut validator);
+        self.check_sequence(&mut validator);
+        self.check_base(&mut validator);
+        validator.into_violations()
+    }
+}
+
+impl Order {
+
+    /// Returns all violations of the order graph, which are
+    /// - edges referring to start or end nodes not contained in the order,
+    /// - nodes contained twice with the same sequence id and edge ids used for different start and end nodes,
+    /// - nodes and edges not alternating along their sequence ids, e.g. an edge not starting at the preceding node.
+    ///
+    /// These checks are part of [`Validate::validate`] as well.
+    pub fn validate_structure(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        self.check_structure(&mut validator);
+        validator.into_violations()
+    }
+
+    /// Returns all violations of the sequencing rules, which are
+    /// - sequence ids of nodes and of edges not strictly increasing in the order of their lists,
+    /// - nodes with odd and edges with even sequence ids,
+    /// - gaps between the sequence ids of consecutive base (released) nodes and edges.
+    ///
+    /// These checks are part of [`Validate::validate`] as well.
+    pub fn validate_sequence(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        self.check_sequence(&mut validator);
+        validator.into_violations()
+    }
+
+    /// Returns all violations of the separation into base and horizon, which are
+    /// - released nodes or edges following a horizon node or edge along the sequence ids,
+    /// - the absence of any released node.
+    ///
+    /// These checks are part of [`Validate::validate`] as well.
+    pub fn validate_base(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        self.check_base(&mut validator);
+        validator.into_violations()
+    }
+
+    /// Returns all violations of the protocol limits of an AGV given by its factsheet, which are
+    /// - more nodes, edges, actions, action parameters, knots or control points than the maximum array lengths,
+    /// - ids, enumeration values, the serial number and other topic elements longer than the maximum string lengths,
+    /// - ids containing other than numerical characters if `idNumericalOnly` is set,
+    /// - a serialized message longer than `msgLen` if the `json` feature is enabled.
+    ///
+    /// Limits which are absent or zero are not checked.
+    pub fn check_limits(&self, limits: &ProtocolLimits) -> Vec<Violation> {
+        let arrays = &limits.max_array_lens;
+        let strings = &limits.max_string_lens;
+        let mut validator = Validator::new();
+
+        #[cfg(feature = "json")]
+        validator.message_length(|| self.serialized_len_estimate(), strings.msg_len);
+        validator.length("version", &self.version, strings.topic_elem_len);
+        validator.length("manufacturer", &self.manufacturer, strings.topic_elem_len);
+        validator.length("serialNumber", &self.serial_number, strings.topic_serial_len);
+        validator.limited_id("orderId", &self.order_id, strings);
+        if let Some(zone_set_id) = &self.zone_set_id {
+            validator.limited_id("zoneSetId", zone_set_id, strings);
+        }
+
+        let check_actions = |validator: &mut Validator, actions: &[Action], max: u32| {
+            validator.count("actions", actions.len(), max);
+            validator.elements("actions", actions, |validator, action| check_action_limits(validator, action, limits));
+        };
+
+        validator.count("nodes", self.nodes.len(), arrays.order_nodes);
+        validator.elements("nodes", &self.nodes, |validator, node| {
+            validator.limited_id("nodeId", &node.node_id, strings);
+            if let Some(node_position) = &node.node_position {
+                validator.field("nodePosition", |validator| validator.limited_id("mapId", &node_position.map_id, strings));
+            }
+            check_actions(validator, &node.actions, arrays.node_actions);
+        });
+        validator.count("edges", self.edges.len(), arrays.order_edges);
+        validator.elements("edges", &self.edges, |validator, edge| {
+            validator.limited_id("edgeId", &edge.edge_id, strings);
+            validator.limited_id("startNodeId", &edge.start_node_id, strings);
+            validator.limited_id("endNodeId", &edge.end_node_id, strings);
+            if let Some(direction) = &edge.direction {
+                validator.length("direction", direction, strings.enum_len);
+            }
+            if let Some(trajectory) = &edge.trajectory {
+                validator.field("trajectory", |validator| {
+                    validator.count("knotVector", trajectory.knot_vector.len(), arrays.trajectory_knot_vector);
+                    validator.count("controlPoints", trajectory.control_points.len(), arrays.trajectory_control_points);
+                });
+            }
+            check_actions(validator, &edge.actions, arrays.edge_actions);
+        });
+        validator.into_violations()
+    }
+
+    /// Returns all violations of the actions supported by an AGV given by its factsheet, which are
+    /// - action types not listed in `agvActions`,
+    /// - actions of nodes or edges whose type does not list the scope `NODE` or `EDGE` respectively,
+    /// - actions lacking a parameter which is not optional,
+    /// - parameter values not matching their data type, see [`Action::check_parameter_types`] with integers coerced.
+    pub fn check_actions(&self, features: &ProtocolFeatures) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        validator.elements("nodes", &self.nodes, |validator, node| {
+            validator.elements("actions", &node.actions, |validator, action| check_action_support(validator, action, features, ActionScope::Node));
+        });
+        validator.elements("edges", &self.edges, |validator, edge| {
+            validator.elements("actions", &edge.actions, |validator, action| check_action_support(validator, action, features, ActionScope::Edge));
+        });
+        validator.into_violations()
+    }
+
+    /// Returns all violations of the physical parameters of an AGV given by its factsheet, which are
+    /// - a `maxSpeed` of an edge above `speedMax`,
+    /// - a `maxRotationSpeed` of an edge at which the corners of the AGV, given by `length` and `width`, would move
+    ///   faster than `speedMax`,
+    /// - a trajectory curved so tightly that the centripetal acceleration exceeds `accelerationMax` at the speed allowed
+    ///   on the edge, which is the lower of `maxSpeed` and `speedMax`.
+    ///
+    /// Parameters which are zero are not checked.
+    pub fn check_physical_parameters(&self, parameters: &PhysicalParameters) -> Vec<Violation> {
+        let speed_max = parameters.speed_max.raw();
+        let acceleration_max = parameters.acceleration_max.raw();
+        // Distance of the corners of the AGV from its center of rotation.
+        let radius = libm::sqrtf(parameters.length.raw() * parameters.length.raw() + parameters.width.raw() * parameters.width.raw()) / 2.0;
+        let mut validator = Validator::new();
+        validator.elements("edges", &self.edges, |validator, edge| {
+            let max_speed = edge.max_speed.map(Raw::raw);
+            if let Some(max_speed) = max_speed.filter(|max_speed| speed_max > 0.0 && *max_speed > speed_max) {
+                validator.field("maxSpeed", |validator| {
+                    validator.violation(RULE_PHYSICAL_LIMIT, format!("{max_speed} m/s exceeds speedMax of {speed_max} m/s"));
+                });
+            }
+            if let Some(rotation_speed) = edge.max_rotation_speed.map(Raw::raw).filter(|rotation_speed| speed_max > 0.0 && rotation_speed * radius > speed_max) {
+                validator.field("maxRotationSpeed", |validator| {
+                    validator.violation(RULE_PHYSICAL_LIMIT, format!(
+                        "{rotation_speed} rad/s moves the corners of the AGV at {} m/s, which exceeds speedMax of {speed_max} m/s", rotation_speed * radius));
+                });
+            }
+            let speed = match max_speed {
+                Some(max_speed) if speed_max > 0.0 => max_speed.min(speed_max),
+                Some(max_speed) => max_speed,
+                None => speed_max,
+            };
+            let curvature = edge.trajectory.as_ref().and_then(|trajectory| trajectory.max_curvature(CURVATURE_SAMPLES)).unwrap_or_default();
+            if acceleration_max > 0.0 && speed * speed * curvature > acceleration_max {
+                validator.field("trajectory", |validator| {
+                    validator.violation(RULE_PHYSICAL_LIMIT, format!(
+                        "centripetal acceleration of {} m/s² at {speed} m/s exceeds accelerationMax of {acceleration_max} m/s², the tightest curve allows {} m/s",
+                        speed * speed * curvature, libm::sqrtf(acceleration_max / curvature)));
+                });
+            }
+        });
+        validator.into_violations()
+    }
+
+    /// Returns all violations of `policy` by the `orderId` as well as the ids of nodes, edges and actions.
+    pub fn check_ids(&self, policy: &IdPolicy) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        validator.policed_id("orderId", &self.order_id, policy);
+        validator.elements("nodes", &self.nodes, |validator, node| {
+            validator.policed_id("nodeId", &node.node_id, policy);
+            validator.elements("actions", &node.actions, |validator, action| validator.policed_id("actionId", &action.action_id, policy));
+        });
+        validator.elements("edges", &self.edges, |validator, edge| {
+            validator.policed_id("edgeId", &edge.edge_id, policy);
+            validator.policed_id("startNodeId", &edge.start_node_id, policy);
+            validator.policed_id("endNodeId", &edge.end_node_id, policy);
+            validator.elements("actions", &edge.actions, |validator, action| validator.policed_id("actionId", &action.action_id, policy));
+        });
+        validator.into_violations()
+    }
+
+    fn check_structure(&self, validator: &mut Validator) {
+        for (index, node) in self.nodes.iter().enumerate() {
+            if self.nodes[..index].iter().any(|other| other.node_id == node.node_id && other.sequence_id == node.sequence_id) {
+                validator.element("nodes", index, |validator| validator.field("nodeId", |validator| {
+                    validator.violation(RULE_DUPLICATE_ID, format!("node `{}` is contained twice with sequence id {}", node.node_id, node.sequence_id));
+                }));
+            }
+        }
+        for (index, edge) in self.edges.iter().enumerate() {
+            if self.edges[..index].iter().any(|other| other.edge_id == edge.edge_id && (other.start_node_id != edge.start_node_id || other.end_node_id != edge.end_node_id)) {
+                validator.element("edges", index, |validator| validator.field("edgeId", |validator| {
+                    validator.violation(RULE_DUPLICATE_ID, format!("edge `{}` connects different nodes", edge.edge_id));
+                }));
+            }
+            for (name, node_id) in [("startNodeId", &edge.start_node_id), ("endNodeId", &edge.end_node_id)] {
+                if !self.nodes.iter().any(|node| node.node_id == *node_id) {
+                    validator.element("edges", index, |validator| validator.field(name, |validator| {
+                        validator.violation(RULE_UNKNOWN_NODE, format!("node `{node_id}` is not part of the order"));
+                    }));
+                }
+            }
+        }
+
+        // Walks nodes and edges along their sequence ids, which have to alternate between nodes and edges.
+        let mut elements = self.nodes.iter().enumerate().map(|(index, node)| (node.sequence_id, Element::Node(index, node)))
+            .chain(self.edges.iter().enumerate().map(|(index, edge)| (edge.sequence_id, Element::Edge(index, edge))))
+            .collect::<Vec<_>>();
+        elements.sort_by_key(|(sequence_id, _)| *sequence_id);
+        let elements = elements.into_iter().map(|(_, element)| element).collect::<Vec<_>>();
+
+        for (position, element) in elements.iter().enumerate() {
+            let previous = position.checked_sub(1).map(|previous| &elements[previous]);
+            let next = elements.get(position + 1);
+            match element {
+                Element::Node(index, node) => if let Some(Element::Node(_, previous)) = previous {
+                    validator.element("nodes", *index, |validator| {
+                        validator.violation(RULE_DISCONNECTED, format!("node `{}` follows node `{}` without an edge", node.node_id, previous.node_id));
+                    });
+                }
+                Element::Edge(index, edge) => validator.element("edges", *index, |validator| {
+                    match previous {
+                        Some(Element::Node(_, node)) if node.node_id == edge.start_node_id => {}
+                        Some(Element::Node(_, node)) => validator.field("startNodeId", |validator| {
+                            validator.violation(RULE_DISCONNECTED, format!("edge `{}` does not start at the preceding node `{}`", edge.edge_id, node.node_id));
+                        }),
+                        _ => validator.violation(RULE_DISCONNECTED, format!("edge `{}` is not preceded by a node", edge.edge_id)),
+                    }
+                    match next {
+                        Some(Element::Node(_, node)) if node.node_id == edge.end_node_id => {}
+                        Some(Element::Node(_, node)) => validator.field("endNodeId", |validator| {
+                            validator.violation(RULE_DISCONNECTED, format!("edge `{}` does not end at the succeeding node `{}`", edge.edge_id, node.node_id));
+                        }),
+                        _ => validator.violation(RULE_DISCONNECTED, format!("edge `{}` is not succeeded by a node", edge.edge_id)),
+                    }
+                }),
+            }
+        }
+    }
+
+    fn check_sequence(&self, validator: &mut Validator) {
+        for (name, sequence_ids, parity) in [
+            ("nodes", self.nodes.iter().map(|node| node.sequence_id).collect::<Vec<_>>(), 0),
+            ("edges", self.edges.iter().map(|edge| edge.sequence_id).collect::<Vec<_>>(), 1),
+        ] {
+            for (index, sequence_id) in sequence_ids.iter().enumerate() {
+                if sequence_id % 2 != parity {
+                    validator.element(name, index, |validator| validator.field("sequenceId", |validator| {
+                        validator.violation(RULE_SEQUENCE_PARITY, format!("{sequence_id} must be {}", if parity == 0 { "even" } else { "odd" }));
+                    }));
+                }
+                if let Some(previous) = index.checked_sub(1).map(|previous| sequence_ids[previous]).filter(|previous| previous >= sequence_id) {
+                    validator.element(name, index, |validator| validator.field("sequenceId", |validator| {
+                        validator.violation(RULE_SEQUENCE_ORDER, format!("{sequence_id} must be greater than the preceding {previous}"));
+                    }));
+                }
+            }
+        }
+
+        let mut base = self.nodes.iter().enumerate().filter(|(_, node)| node.released).map(|(index, node)| (node.sequence_id, "nodes", index))
+            .chain(self.edges.iter().enumerate().filter(|(_, edge)| edge.released).map(|(index, edge)| (edge.sequence_id, "edges", index)))
+            .collect::<Vec<_>>();
+        base.sort_by_key(|(sequence_id, _, _)| *sequence_id);
+        for window in base.windows(2) {
+            let (previous, _, _) = window[0];
+            let (sequence_id, name, index) = window[1];
+            if sequence_id > previous + 1 {
+                validator.element(name, index, |validator| validator.field("sequenceId", |validator| {
+                    validator.violation(RULE_SEQUENCE_GAP, format!("{sequence_id} does not follow the preceding base element {previous}"));
+                }));
+            }
+        }
+    }
+
+    fn check_base(&self, validator: &mut Validator) {
+        if !self.nodes.iter().any(|node| node.released) {
+            validator.field("nodes", |validator| validator.violation(RULE_NO_BASE_NODE, "order must contain at least one released node"));
+        }
+
+        let mut elements = self.nodes.iter().enumerate().map(|(index, node)| (node.sequence_id, node.released, "nodes", index))
+            .chain(self.edges.iter().enumerate().map(|(index, edge)| (edge.sequence_id, edge.released, "edges", index)))
+            .collect::<Vec<_>>();
+        elements.sort_by_key(|(sequence_id, _, _, _)| *sequence_id);
+        if let Some(horizon) = elements.iter().position(|(_, released, _, _)| !released) {
+            let (horizon_sequence_id, _, _, _) = elements[horizon];
+            for (sequence_id, _, name, index) in elements[horizon..].iter().filter(|(_, released, _, _)| *released) {
+                validator.element(name, *index, |validator| validator.field("released", |validator| {
+                    validator.violation(RULE_RELEASED_AFTER_HORIZON, format!("released element {sequence_id} follows the horizon starting at {horizon_sequence_id}"));
+                }));
+            }
+        }
+    }
+}
+
+impl Trajectory {
+
+    /// Returns all violations of the NURBS structure, which are
+    /// - a degree less than 1,
+    /// - less than two control points,
+    /// - a knot vector whose size is not the number of control points plus degree plus one,
+    /// - decreasing knots,
+    /// - orientations of control points outside of `[-π, π]`.
+    ///
+    /// These checks are part of [`Validate::validate`] of orders and states containing the trajectory.
+    pub fn validate(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        self.check(&mut validator);
+        validator.into_violations()
+    }
+
+    fn check(&self, validator: &mut Validator) {
+        if self.degree < 1 {
+            validator.field("degree", |validator| validator.violation(RULE_MALFORMED_TRAJECTORY, format!("{} must be at least 1", self.degree)));
+        }
+        if self.control_points.len() < 2 {
+            validator.field("controlPoints", |validator| {
+                validator.violation(RULE_MALFORMED_TRAJECTORY, format!("{} control points given, at least 2 required", self.control_points.len()));
+            });
+        }
+        let expected = usize::try_from(self.degree).ok().map(|degree| self.control_points.len() + degree + 1);
+        if expected.is_some_and(|expected| expected != self.knot_vector.len()) {
+            validator.field("knotVector", |validator| {
+                validator.violation(RULE_MALFORMED_TRAJECTORY, format!("{} knots given, {} required", self.knot_vector.len(), expected.unwrap_or_default()));
+            });
+        }
+        for (index, knots) in self.knot_vector.windows(2).enumerate() {
+            if knots[1] < knots[0] {
+                validator.element("knotVector", index + 1, |validator| {
+                    validator.violation(RULE_MALFORMED_TRAJECTORY, format!("{} must not be less than the preceding {}", knots[1], knots[0]));
+                });
+            }
+        }
+        validator.elements("controlPoints", &self.control_points, |validator, point| validator.angle("orientation", point.orientation));
+    }
+}
+
+/// A node or edge of an order with its index.
+enum Element<'a> {
+    Node(usize, &'a Node),
+    Edge(usize, &'a Edge),
+}
+
+impl Validate for State {
+
+    fn validate(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        check_header(&mut validator, &self.version, &self.manufacturer, &self.serial_number);
+        validator.elements("nodeStates", &self.node_states, |validator, node| {
+            validator.id("nodeId", &node.node_id);
+            validate_node_position(validator, &node.node_position);
+        });
+        for (index, node) in self.node_states.iter().enumerate() {
+            if self.node_states[..index].iter().any(|other| other.sequence_id == node.sequence_id) {
+                validator.element("nodeStates", index, |validator| validator.field("sequenceId", |validator| {
+                    validator.violation(RULE_DUPLICATE_ID, format!("sequence id {} is used by more than one node state", node.sequence_id));
+                }));
+            }
+            if !self.last_node_id.is_empty() && node.sequence_id <= self.last_node_sequence_id {
+                validator.element("nodeStates", index, |validator| validator.field("sequenceId", |validator| {
+                    validator.violation(RULE_INCONSISTENT, format!("node state {} has already been traversed according to lastNodeSequenceId {}", node.sequence_id, self.last_node_sequence_id));
+                }));
+            }
+        }
+        for (index, edge) in self.edge_states.iter().enumerate() {
+            if self.edge_states[..index].iter().any(|other| other.sequence_id == edge.sequence_id) {
+                validator.element("edgeStates", index, |validator| validator.field("sequenceId", |validator| {
+                    validator.violation(RULE_DUPLICATE_ID, format!("sequence id {} is used by more than one edge state", edge.sequence_id));
+                }));
+            }
+        }
+        for (index, action) in self.action_states.iter().enumerate() {
+            if self.action_states[..index].iter().any(|other| other.action_id == action.action_id) {
+                validator.element("actionStates", index, |validator| validator.field("actionId", |validator| {
+                    validator.violation(RULE_DUPLICATE_ID, format!("action `{}` has more than one action state", action.action_id));
+                }));
+            }
+        }
+        if self.driving && self.safety_state.e_stop.is_engaged() {
+            validator.field("driving", |validator| validator.violation(RULE_INCONSISTENT, "AGV must not drive while an emergency stop is active"));
+        }
+        validator.elements("edgeStates", &self.edge_states, |validator, edge| {
+            validator.id("edgeId", &edge.edge_id);
+            if let Some(trajectory) = &edge.trajectory {
+                validator.field("trajectory", |validator| trajectory.check(validator));
+            }
+        });
+        validator.elements("actionStates", &self.action_states, |validator, action| {
+            validator.id("actionId", &action.action_id);
+        });
+        if let Some(agv_position) = &self.agv_position {
+            validator.field("agvPosition", |validator| {
+                validator.angle("theta", Some(agv_position.theta));
+                if let Some(localization_score) = agv_position.localization_score {
+                    validator.range("localizationScore", localization_score.raw(), 0.0, 1.0);
+                }
+                validator.non_negative("deviationRange", agv_position.deviation_range);
+            });
+        }
+        validator.field("batteryState", |validator| {
+            validator.range("batteryCharge", self.battery_state.battery_charge.raw(), 0.0, 100.0);
+            if let Some(battery_health) = self.battery_state.battery_health {
+                validator.range("batteryHealth", battery_health, 0, 100);
+            }
+        });
+        validator.into_violations()
+    }
+}
+
+impl State {
+
+    /// Returns all violations of `policy` by the `orderId` and `lastNodeId`, if not empty, as well as the ids of node,
+    /// edge and action states.
+    pub fn check_ids(&self, policy: &IdPolicy) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        for (name, id) in [("orderId", self.order_id.as_str()), ("lastNodeId", self.last_node_id.as_str())] {
+            if !id.is_empty() {
+                validator.policed_id(name, id, policy);
+            }
+        }
+        validator.elements("nodeStates", &self.node_states, |validator, node| validator.policed_id("nodeId", &node.node_id, policy));
+        validator.elements("edgeStates", &self.edge_states, |validator, edge| validator.policed_id("edgeId", &edge.edge_id, policy));
+        validator.elements("actionStates", &self.action_states, |validator, action| validator.policed_id("actionId", &action.action_id, policy));
+        validator.into_violations()
+    }
+
+    /// Returns all violations of the state with respect to `order`, which has been issued to the AGV, which are
+    /// - a `zoneSetId` different from the one of the order, if the order uses zones,
+    /// - node and edge states whose `sequenceId` is not used by the order or by a node or edge with another id,
+    /// - actions of the nodes and edges of the order without action state.
+    ///
+    /// The node, edge and action states are only checked if the state refers to the `orderId` and `orderUpdateId` of
+    /// `order`, as the AGV may not have accepted the order yet.
+    pub fn validate_against_order(&self, order: &Order) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        if let Some(zone_set_id) = order.zone_set_id.as_ref().filter(|zone_set_id| self.zone_set_id.as_ref() != Some(*zone_set_id)) {
+            validator.field("zoneSetId", |validator| {
+                validator.violation(RULE_INCONSISTENT, format!("zone set `{}` differs from the zone set `{zone_set_id}` of the order", self.zone_set_id.as_deref().unwrap_or_default()));
+            });
+        }
+        if self.order_id != order.order_id || self.order_update_id != order.order_update_id {
+            return validator.into_violations();
+        }
+        validator.elements("nodeStates", &self.node_states, |validator, node_state| {
+            match order.nodes.iter().find(|node| node.sequence_id == node_state.sequence_id) {
+                Some(node) if node.node_id != node_state.node_id => validator.field("nodeId", |validator| {
+                    validator.violation(RULE_INCONSISTENT, format!("node `{}` differs from the node `{}` of the order with sequence id {}", node_state.node_id, node.node_id, node.sequence_id));
+                }),
+                Some(_) => {}
+                None => validator.field("sequenceId", |validator| {
+                    validator.violation(RULE_INCONSISTENT, format!("sequence id {} is not used by a node of the order", node_state.sequence_id));
+                }),
+            }
+        });
+        validator.elements("edgeStates", &self.edge_states, |validator, edge_state| {
+            match order.edges.iter().find(|edge| edge.sequence_id == edge_state.sequence_id) {
+                Some(edge) if edge.edge_id != edge_state.edge_id => validator.field("edgeId", |validator| {
+                    validator.violation(RULE_INCONSISTENT, format!("edge `{}` differs from the edge `{}` of the order with sequence id {}", edge_state.edge_id, edge.edge_id, edge.sequence_id));
+                }),
+                Some(_) => {}
+                None => validator.field("sequenceId", |validator| {
+                    validator.violation(RULE_INCONSISTENT, format!("sequence id {} is not used by an edge of the order", edge_state.sequence_id));
+                }),
+            }
+        });
+        let actions = order.nodes.iter().flat_map(|node| node.actions.iter()).chain(order.edges.iter().flat_map(|edge| edge.actions.iter()));
+        for action in actions.filter(|action| !self.action_states.iter().any(|action_state| action_state.action_id == action.action_id)) {
+            validator.field("actionStates", |validator| {
+                validator.violation(RULE_INCONSISTENT, format!("action `{}` of the order has no action state", action.action_id));
+            });
+        }
+        validator.into_violations()
+    }
+}
+
+impl Validate for Visualization {
+
+    fn validate(&self) -> Vec<Violation> {
+        let mut validator = Validator::new();
+        check_header(&mut validator, &self.version, &self.manufacturer, &self.serial_number);
+        validator.into_violations()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::action::{Action, ActionParameter, ActionParameterValue, BlockingType};
+    use crate::checked::checked;
+    use crate::common::Trajectory;
+    use crate::factsheet::{ActionParameter as FactsheetActionParameter, ActionScope, AgvAction, AgvGeometry, Envelopes2d, Factsheet, MaxArrayLens, MaxStringLens, PhysicalParameters, PolygonPoint, Position, ProtocolFeatures, ProtocolLimits, Timing, ValueDataType, WheelDefinition, WheelType};
+    use crate::finite::double;
+    use crate::header::Header;
+    use crate::instant_actions::InstantActions;
+    use crate::order::{Edge, Node, Order};
+    use crate::state::{ActionState, ActionStatus, BatteryState, EdgeState, EStop, NodeState, OperatingMode, SafetyState, State};
+    use crate::timestamp::from_unix_millis;
+    use crate::trajectory::tests::{quarter_circle, trajectory};
+    use super::{RULE_ACTION_SCOPE, RULE_DISCONNECTED, RULE_DUPLICATE_ID, RULE_EMPTY_ID, RULE_FUTURE_TIMESTAMP, RULE_INCONSISTENT, RULE_INVALID_CHARACTER, RULE_INVALID_POLYGON, RULE_INVALID_VERSION, RULE_MALFORMED_TRAJECTORY, RULE_MISSING_FIELD, RULE_MISSING_PARAMETER, RULE_NO_BASE_NODE, RULE_NO_DRIVEN_WHEEL, RULE_NOT_NUMERICAL, RULE_OUT_OF_RANGE, RULE_PARAMETER_TYPE, RULE_PHYSICAL_LIMIT, RULE_RELEASED_AFTER_HORIZON, RULE_SEQUENCE_GAP, RULE_SEQUENCE_ORDER, RULE_SEQUENCE_PARITY, RULE_TOO_LONG, RULE_TOO_MANY_ELEMENTS, RULE_TOPIC_UNSAFE, RULE_UNKNOWN_NODE, RULE_UNSUPPORTED_ACTION, MAX_TIMESTAMP_AHEAD_MILLIS, IdPolicy, Validate, Violation, validate_header};
+
+    fn action(action_id: &str) -> Action {
+        Action {
+            action_type: "pick".into(),
+            action_id: action_id.into(),
+            action_description: None,
+            blocking_type: BlockingType::Hard,
+            action_parameters: vec![],
+        }
+    }
+
+    fn node(node_id: &str, sequence_id: u64, actions: Vec<Action>) -> Node {
+        Node {
+            node_id: node_id.into(),
+            sequence_id,
+            node_description: None,
+            released: true,
+            node_position: None,
+            actions,
+        }
+    }
+
+    fn edge(edge_id: &str, sequence_id: u64, start_node_id: &str, end_node_id: &str) -> Edge {
+        Edge {
+            edge_id: edge_id.into(),
+            sequence_id,
+            edge_description: None,
+            released: true,
+            start_node_id: start_node_id.into(),
+            end_node_id: end_node_id.into(),
+            max_speed: None,
+            max_height: None,
+            min_height: None,
+            orientation: None,
+            orientation_type: None,
+            direction: None,
+            rotation_allowed: None,
+            max_rotation_speed: None,
+            length: None,
+            trajectory: None,
+            actions: vec![],
+        }
+    }
+
+    fn order(nodes: Vec<Node>, edges: Vec<Edge>) -> Order {
+        Order {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            order_id: "o1".into(),
+            order_update_id: 0,
+            zone_set_id: None,
+            nodes,
+            edges,
+        }
+    }
+
+    fn node_state(node_id: &str, sequence_id: u64) -> NodeState {
+        NodeState { node_id: node_id.into(), sequence_id, node_description: None, node_position: None, released: true }
+    }
+
+    fn edge_state(edge_id: &str, sequence_id: u64) -> EdgeState {
+        EdgeState { edge_id: edge_id.into(), sequence_id, edge_description: None, released: true, trajectory: None }
+    }
+
+    fn action_state(action_id: &str) -> ActionState {
+        ActionState { action_id: action_id.into(), action_type: None, action_description: None, action_status: ActionStatus::Waiting, result_description: None }
+    }
+
+    fn wheel(wheel_type: WheelType, is_active_driven: bool, theta: Option<f32>, diameter: f32, width: f32) -> WheelDefinition {
+        WheelDefinition {
+            wheel_type,
+            is_active_driven,
+            is_active_steered: false,
+            position: Position { x: checked(0.0), y: checked(0.0), theta: theta.map(checked) },
+            diameter: checked(diameter),
+            width: checked(width),
+            center_displacement: None,
+            constraints: None,
+        }
+    }
+
+    fn protocol_limits() -> ProtocolLimits {
+        ProtocolLimits {
+            max_string_lens: MaxStringLens {
+                msg_len: None,
+                topic_serial_len: None,
+                topic_elem_len: None,
+                id_len: None,
+                id_numerical_only: None,
+                enum_len: None,
+                load_id_len: None,
+            },
+            max_array_lens: MaxArrayLens {
+                order_nodes: 0,
+                order_edges: 0,
+                node_actions: 0,
+                edge_actions: 0,
+                actions_actions_parameters: 0,
+                instant_actions: 0,
+                trajectory_knot_vector: 0,
+                trajectory_control_points: 0,
+                state_node_states: 0,
+                state_edge_states: 0,
+                state_loads: 0,
+                state_action_states: 0,
+                state_errors: 0,
+                state_information: 0,
+                error_error_references: 0,
+                information_info_references: 0,
+            },
+            timing: Timing {
+                min_order_interval: checked(1.0),
+                min_state_interval: checked(1.0),
+                default_state_interval: None,
+                visualization_interval: None,
+            },
+        }
+    }
+
+    fn state(battery_charge: f32, battery_health: Option<u32>) -> State {
+        State {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            order_id: Default::default(),
+            order_update_id: 0,
+            zone_set_id: None,
+            last_node_id: Default::default(),
+            last_node_sequence_id: 0,
+            driving: false,
+            paused: None,
+            new_base_request: None,
+            distance_since_last_node: None,
+            operating_mode: OperatingMode::Automatic,
+            node_states: vec![],
+            edge_states: vec![],
+            agv_position: None,
+            velocity: None,
+            loads: vec![],
+            action_states: vec![],
+            battery_state: BatteryState {
+                battery_charge: checked(battery_charge),
+                battery_voltage: None,
+                battery_health,
+                charging: false,
+                reach: None,
+            },
+            errors: vec![],
+            information: vec![],
+            safety_state: SafetyState { e_stop: EStop::None, field_violation: false },
+        }
+    }
+
+    #[rstest]
+    fn test_Order_validate_reports_paths_of_empty_ids() {
+        let order = order(
+            vec![node("n1", 0, vec![]), node("", 2, vec![action("a1"), action("")])],
+            vec![edge("e1", 1, "n1", "")],
+        );
+
+        assert_that!(order.validate(), elements_are![
+            eq(&Violation { path: "/nodes/1/nodeId".into(), rule: RULE_EMPTY_ID, message: "must not be empty".into() }),
+            eq(&Violation { path: "/nodes/1/actions/1/actionId".into(), rule: RULE_EMPTY_ID, message: "must not be empty".into() }),
+            eq(&Violation { path: "/edges/0/endNodeId".into(), rule: RULE_EMPTY_ID, message: "must not be empty".into() }),
+        ]);
+    }
+
+    #[rstest]
+    #[case::single_node(vec![node("n1", 0, vec![])], vec![])]
+    #[case::revisited_node(vec![node("n1", 0, vec![]), node("n2", 2, vec![]), node("n1", 4, vec![])], vec![edge("e1", 1, "n1", "n2"), edge("e2", 3, "n2", "n1")])]
+    #[case::unordered(vec![node("n2", 2, vec![]), node("n1", 0, vec![])], vec![edge("e1", 1, "n1", "n2")])]
+    fn test_Order_validate_structure_accepts_path(#[case] nodes: Vec<Node>, #[case] edges: Vec<Edge>) {
+        assert_that!(order(nodes, edges).validate_structure(), empty());
+    }
+
+    #[rstest]
+    #[case::unknown_node(vec![node("n1", 0, vec![]), node("n2", 2, vec![])], vec![edge("e1", 1, "n1", "n3")],
+        vec![("/edges/0/endNodeId", RULE_UNKNOWN_NODE), ("/edges/0/endNodeId", RULE_DISCONNECTED)])]
+    #[case::duplicate_node(vec![node("n1", 0, vec![]), node("n1", 0, vec![])], vec![],
+        vec![("/nodes/1/nodeId", RULE_DUPLICATE_ID), ("/nodes/1", RULE_DISCONNECTED)])]
+    #[case::duplicate_edge(vec![node("n1", 0, vec![]), node("n2", 2, vec![]), node("n3", 4, vec![])], vec![edge("e1", 1, "n1", "n2"), edge("e1", 3, "n2", "n3")],
+        vec![("/edges/1/edgeId", RULE_DUPLICATE_ID)])]
+    #[case::missing_edge(vec![node("n1", 0, vec![]), node("n2", 2, vec![])], vec![],
+        vec![("/nodes/1", RULE_DISCONNECTED)])]
+    #[case::dangling_edge(vec![node("n1", 0, vec![])], vec![edge("e1", 1, "n1", "n1")],
+        vec![("/edges/0", RULE_DISCONNECTED)])]
+    #[case::reversed_edge(vec![node("n1", 0, vec![]), node("n2", 2, vec![])], vec![edge("e1", 1, "n2", "n1")],
+        vec![("/edges/0/startNodeId", RULE_DISCONNECTED), ("/edges/0/endNodeId", RULE_DISCONNECTED)])]
+    fn test_Order_validate_structure_reports_violations(#[case] nodes: Vec<Node>, #[case] edges: Vec<Edge>, #[case] expected: Vec<(&str, &str)>) {
+        let violations = order(nodes, edges).validate_structure();
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&expected));
+    }
+
+    #[rstest]
+    #[case::odd_node(vec![node("n1", 1, vec![])], vec![], vec![("/nodes/0/sequenceId", RULE_SEQUENCE_PARITY)])]
+    #[case::even_edge(vec![node("n1", 0, vec![]), node("n2", 4, vec![])], vec![edge("e1", 2, "n1", "n2")],
+        vec![("/edges/0/sequenceId", RULE_SEQUENCE_PARITY), ("/edges/0/sequenceId", RULE_SEQUENCE_GAP), ("/nodes/1/sequenceId", RULE_SEQUENCE_GAP)])]
+    #[case::decreasing(vec![node("n2", 2, vec![]), node("n1", 0, vec![])], vec![edge("e1", 1, "n1", "n2")], vec![("/nodes/1/sequenceId", RULE_SEQUENCE_ORDER)])]
+    #[case::repeated(vec![node("n1", 0, vec![]), node("n1", 0, vec![])], vec![], vec![("/nodes/1/sequenceId", RULE_SEQUENCE_ORDER)])]
+    #[case::gap(vec![node("n1", 0, vec![]), node("n2", 2, vec![]), node("n3", 6, vec![])], vec![edge("e1", 1, "n1", "n2"), edge("e2", 5, "n2", "n3")],
+        vec![("/edges/1/sequenceId", RULE_SEQUENCE_GAP)])]
+    fn test_Order_validate_sequence_reports_violations(#[case] nodes: Vec<Node>, #[case] edges: Vec<Edge>, #[case] expected: Vec<(&str, &str)>) {
+        let violations = order(nodes, edges).validate_sequence();
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&expected));
+    }
+
+    #[rstest]
+    fn test_Order_validate_sequence_ignores_gaps_in_horizon() {
+        let mut horizon = order(
+            vec![node("n1", 0, vec![]), node("n2", 2, vec![]), node("n3", 6, vec![])],
+            vec![edge("e1", 1, "n1", "n2"), edge("e2", 5, "n2", "n3")],
+        );
+        horizon.nodes[2].released = false;
+        horizon.edges[1].released = false;
+
+        assert_that!(horizon.validate_sequence(), empty());
+    }
+
+    #[rstest]
+    #[case::base_only(&[true, true, true], &[true, true], vec![])]
+    #[case::horizon(&[true, false, false], &[false, false], vec![])]
+    #[case::released_after_horizon(&[true, false, true], &[true, false], vec![("/nodes/2/released", RULE_RELEASED_AFTER_HORIZON)])]
+    #[case::no_base_node(&[false, false, false], &[false, false], vec![("/nodes", RULE_NO_BASE_NODE)])]
+    fn test_Order_validate_base(#[case] nodes: &[bool], #[case] edges: &[bool], #[case] expected: Vec<(&str, &str)>) {
+        let mut order = order(
+            vec![node("n1", 0, vec![]), node("n2", 2, vec![]), node("n3", 4, vec![])],
+            vec![edge("e1", 1, "n1", "n2"), edge("e2", 3, "n2", "n3")],
+        );
+        order.nodes.iter_mut().zip(nodes).for_each(|(node, released)| node.released = *released);
+        order.edges.iter_mut().zip(edges).for_each(|(edge, released)| edge.released = *released);
+
+        let violations = order.validate_base();
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&expected));
+    }
+
+    #[rstest]
+    #[case::valid(trajectory(2, vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0], &[(1.0, 0.0, None), (1.0, 1.0, None), (0.0, 1.0, None)]), vec![])]
+    #[case::degree(trajectory(0, vec![0.0, 0.0, 1.0], &[(1.0, 0.0, None), (0.0, 1.0, None)]), vec!["/degree"])]
+    #[case::control_points(trajectory(1, vec![0.0, 0.0, 1.0], &[(1.0, 0.0, None)]), vec!["/controlPoints"])]
+    #[case::knot_count(trajectory(1, vec![0.0, 0.0, 1.0], &[(1.0, 0.0, None), (0.0, 1.0, None)]), vec!["/knotVector"])]
+    #[case::knot_order(trajectory(1, vec![0.0, 1.0, 0.5, 1.0], &[(1.0, 0.0, None), (0.0, 1.0, None)]), vec!["/knotVector/2"])]
+    fn test_Trajectory_validate(#[case] trajectory: Trajectory, #[case] expected: Vec<&str>) {
+        let violations = trajectory.validate();
+
+        assert_that!(violations.iter().map(|violation| violation.path.as_str()).collect::<Vec<_>>(), eq(&expected));
+        assert_that!(violations.iter().all(|violation| violation.rule == RULE_MALFORMED_TRAJECTORY), eq(true));
+    }
+
+    #[rstest]
+    fn test_Order_validate_reports_malformed_trajectory() {
+        let mut order = order(vec![node("n1", 0, vec![]), node("n2", 2, vec![])], vec![edge("e1", 1, "n1", "n2")]);
+        order.edges[0].trajectory = Some(trajectory(3, vec![0.0, 1.0], &[(0.0, 0.0, None), (1.0, 1.0, None)]));
+
+        assert_that!(order.validate(), elements_are![
+            matches_pattern!(Violation { path: eq("/edges/0/trajectory/knotVector"), rule: eq(&RULE_MALFORMED_TRAJECTORY) }),
+        ]);
+    }
+
+    #[cfg(not(feature = "checked-types"))]
+    #[rstest]
+    fn test_Order_validate_reports_angles_out_of_range() {
+        let mut order = order(vec![node("n1", 0, vec![]), node("n2", 2, vec![])], vec![edge("e1", 1, "n1", "n2")]);
+        order.nodes[1].node_position = Some(crate::common::NodePosition {
+            x: checked(0.0),
+            y: checked(0.0),
+            theta: Some(checked(4.0)),
+            allowed_deviation_xy: None,
+            allowed_deviation_theta: None,
+            map_id: "map".into(),
+            map_description: None,
+        });
+        order.edges[0].orientation = Some(checked(-3.0));
+        order.edges[0].trajectory = Some(trajectory(1, vec![0.0, 0.0, 1.0, 1.0], &[(0.0, 0.0, None), (1.0, 1.0, None)]));
+        order.edges[0].trajectory.as_mut().unwrap().control_points[1].orientation = Some(checked(7.0));
+
+        let violations = order.validate();
+
+        assert_that!(violations.iter().map(|violation| violation.path.as_str()).collect::<Vec<_>>(), eq(&vec![
+            "/nodes/1/nodePosition/theta",
+            "/edges/0/trajectory/controlPoints/1/orientation",
+        ]));
+        assert_that!(violations.iter().all(|violation| violation.rule == RULE_OUT_OF_RANGE), eq(true));
+    }
+
+    #[rstest]
+    fn test_Order_check_limits_accepts_unlimited() {
+        let order = order(vec![node("n1", 0, vec![action("a1")]), node("n2", 2, vec![])], vec![edge("e1", 1, "n1", "n2")]);
+
+        assert_that!(order.check_limits(&protocol_limits()), empty());
+    }
+
+    #[rstest]
+    fn test_Order_check_limits_reports_violations() {
+        let order = order(vec![node("1", 0, vec![action("a1"), action("2")]), node("22", 2, vec![])], vec![edge("333", 1, "1", "22")]);
+        let mut limits = protocol_limits();
+        limits.max_array_lens.order_nodes = 1;
+        limits.max_array_lens.node_actions = 2;
+        limits.max_string_lens.id_len = Some(2);
+        limits.max_string_lens.id_numerical_only = Some(true);
+        limits.max_string_lens.topic_serial_len = Some(0);
+
+        let violations = order.check_limits(&limits);
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![
+            ("/orderId", RULE_NOT_NUMERICAL),
+            ("/nodes", RULE_TOO_MANY_ELEMENTS),
+            ("/nodes/0/actions/0/actionId", RULE_NOT_NUMERICAL),
+            ("/edges/0/edgeId", RULE_TOO_LONG),
+        ]));
+    }
+
+    #[cfg(feature = "json")]
+    #[rstest]
+    fn test_Order_check_limits_reports_too_long_message() {
+        let mut limits = protocol_limits();
+        limits.max_string_lens.msg_len = Some(64);
+
+        let violations = order(vec![node("n1", 0, vec![])], vec![]).check_limits(&limits);
+
+        assert_that!(violations, elements_are![matches_pattern!(Violation { path: eq(""), rule: eq(&RULE_TOO_LONG) })]);
+    }
+
+    fn physical_parameters() -> PhysicalParameters {
+        PhysicalParameters {
+            speed_min: checked(0.1),
+            speed_max: checked(2.0),
+            acceleration_max: checked(1.0),
+            deceleration_max: checked(1.0),
+            height_min: None,
+            height_max: checked(2.0),
+            width: checked(0.6),
+            length: checked(0.8),
+        }
+    }
+
+    #[rstest]
+    #[case::within_limits(Some(1.0), Some(1.0), None, vec![])]
+    #[case::max_speed(Some(2.5), None, None, vec!["/edges/0/maxSpeed"])]
+    #[case::max_rotation_speed(None, Some(5.0), None, vec!["/edges/0/maxRotationSpeed"])]
+    #[case::slow_curve(Some(0.5), None, Some(quarter_circle()), vec![])]
+    #[case::fast_curve(Some(1.5), None, Some(quarter_circle()), vec!["/edges/0/trajectory"])]
+    #[case::curve_at_speed_max(None, None, Some(quarter_circle()), vec!["/edges/0/trajectory"])]
+    fn test_Order_check_physical_parameters(#[case] max_speed: Option<f32>, #[case] max_rotation_speed: Option<f32>, #[case] trajectory: Option<Trajectory>, #[case] expected: Vec<&str>) {
+        let mut order = order(vec![node("n1", 0, vec![]), node("n2", 2, vec![])], vec![edge("e1", 1, "n1", "n2")]);
+        order.edges[0].max_speed = max_speed.map(checked);
+        order.edges[0].max_rotation_speed = max_rotation_speed.map(checked);
+        order.edges[0].trajectory = trajectory;
+
+        let violations = order.check_physical_parameters(&physical_parameters());
+
+        assert_that!(violations.iter().map(|violation| violation.path.as_str()).collect::<Vec<_>>(), eq(&expected));
+        assert_that!(violations.iter().all(|violation| violation.rule == RULE_PHYSICAL_LIMIT), eq(true));
+    }
+
+    #[rstest]
+    fn test_Order_check_physical_parameters_ignores_zero_parameters() {
+        let mut order = order(vec![node("n1", 0, vec![]), node("n2", 2, vec![])], vec![edge("e1", 1, "n1", "n2")]);
+        order.edges[0].max_speed = Some(checked(5.0));
+        order.edges[0].trajectory = Some(quarter_circle());
+        let mut parameters = physical_parameters();
+        parameters.speed_max = checked(0.0);
+        parameters.acceleration_max = checked(0.0);
+
+        assert_that!(order.check_physical_parameters(&parameters), empty());
+    }
+
+    #[rstest]
+    fn test_Order_check_ids() {
+        let order = order(vec![node("n-1", 0, vec![action("a_1")]), node("n2", 2, vec![])], vec![edge("e1", 1, "n-1", "n2")]);
+        let policy = IdPolicy { max_len: Some(2), numerical_only: false, allowed_chars: Some("abcdefghijklmnopqrstuvwxyz0123456789-".into()) };
+
+        let violations = order.check_ids(&policy);
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![
+            ("/nodes/0/nodeId", RULE_TOO_LONG),
+            ("/nodes/0/actions/0/actionId", RULE_TOO_LONG),
+            ("/nodes/0/actions/0/actionId", RULE_INVALID_CHARACTER),
+            ("/edges/0/startNodeId", RULE_TOO_LONG),
+        ]));
+    }
+
+    #[rstest]
+    #[case::unlimited(None, None, "o-1", vec![])]
+    #[case::too_long(Some(2), None, "123", vec![RULE_TOO_LONG])]
+    #[case::numerical(Some(0), Some(true), "12", vec![])]
+    #[case::not_numerical(None, Some(true), "o1", vec![RULE_NOT_NUMERICAL])]
+    fn test_IdPolicy_from_MaxStringLens(#[case] id_len: Option<u64>, #[case] id_numerical_only: Option<bool>, #[case] id: &str, #[case] expected: Vec<&str>) {
+        let mut lens = protocol_limits().max_string_lens;
+        lens.id_len = id_len;
+        lens.id_numerical_only = id_numerical_only;
+
+        let violations = IdPolicy::from(&lens).check(id);
+
+        assert_that!(violations.iter().map(|violation| violation.rule).collect::<Vec<_>>(), eq(&expected));
+    }
+
+    #[rstest]
+    fn test_Order_check_actions() {
+        let features = ProtocolFeatures {
+            optional_parameters: vec![],
+            agv_actions: vec![AgvAction {
+                action_type: "pick".into(),
+                action_description: None,
+                action_scopes: vec![ActionScope::Node],
+                action_parameters: vec![
+                    FactsheetActionParameter { key: "stationType".into(), value_data_type: ValueDataType::String, description: None, is_optional: None },
+                    FactsheetActionParameter { key: "height".into(), value_data_type: ValueDataType::Float, description: None, is_optional: Some(true) },
+                ],
+                result_description: None,
+            }],
+        };
+        let mut order = order(vec![node("n1", 0, vec![action("a1")]), node("n2", 2, vec![])], vec![edge("e1", 1, "n1", "n2")]);
+        order.nodes[0].actions[0].action_parameters.push(ActionParameter { key: "stationType".into(), value: ActionParameterValue::String("floor".into()) });
+        order.nodes[1].actions.push(action("a2"));
+        order.edges[0].actions.push(action("a3"));
+        order.edges[0].actions[0].action_parameters.push(ActionParameter { key: "stationType".into(), value: ActionParameterValue::String("floor".into()) });
+        order.edges[0].actions.push(Action { action_type: "drop".into(), ..action("a4") });
+
+        let violations = order.check_actions(&features);
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![
+            ("/nodes/1/actions/0/actionParameters", RULE_MISSING_PARAMETER),
+            ("/edges/0/actions/0/actionType", RULE_ACTION_SCOPE),
+            ("/edges/0/actions/1/actionType", RULE_UNSUPPORTED_ACTION),
+        ]));
+    }
+
+    #[rstest]
+    #[case::bool(ValueDataType::Bool, ActionParameterValue::Boolean(true), false, true)]
+    #[case::number_integer(ValueDataType::Number, ActionParameterValue::Integer(1), false, true)]
+    #[case::number_float(ValueDataType::Number, ActionParameterValue::Float(double(1.5)), false, true)]
+    #[case::float_integer_coerced(ValueDataType::Float, ActionParameterValue::Integer(1), true, true)]
+    #[case::float_integer_strict(ValueDataType::Float, ActionParameterValue::Integer(1), false, false)]
+    #[case::integer_float(ValueDataType::Integer, ActionParameterValue::Float(double(1.0)), true, false)]
+    #[case::string_bool(ValueDataType::String, ActionParameterValue::Boolean(false), true, false)]
+    #[case::bool_null(ValueDataType::Bool, ActionParameterValue::Null, true, false)]
+    #[case::object(ValueDataType::Object, ActionParameterValue::String("{}".into()), false, true)]
+    fn test_Action_check_parameter_types(#[case] value_data_type: ValueDataType, #[case] value: ActionParameterValue, #[case] coerce_integers: bool, #[case] valid: bool) {
+        let definition = AgvAction {
+            action_type: "pick".into(),
+            action_description: None,
+            action_scopes: vec![ActionScope::Node],
+            action_parameters: vec![FactsheetActionParameter { key: "p".into(), value_data_type, description: None, is_optional: None }],
+            result_description: None,
+        };
+        let mut action = action("a1");
+        action.action_parameters.push(ActionParameter { key: "undefined".into(), value: ActionParameterValue::Null });
+        action.action_parameters.push(ActionParameter { key: "p".into(), value });
+
+        let violations = action.check_parameter_types(&definition, coerce_integers);
+
+        if valid {
+            assert_that!(violations, empty());
+        }
+        else {
+            assert_that!(violations, elements_are![matches_pattern!(Violation { path: eq("/actionParameters/1/value"), rule: eq(&RULE_PARAMETER_TYPE) })]);
+        }
+    }
+
+    #[rstest]
+    #[case::square(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)], vec![])]
+    #[case::explicitly_closed(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)], vec![])]
+    #[case::concave(&[(0.0, 0.0), (2.0, 0.0), (1.0, 1.0), (2.0, 2.0), (0.0, 2.0)], vec![])]
+    #[case::too_few_points(&[(0.0, 0.0), (1.0, 0.0), (0.0, 0.0)], vec!["/polygonPoints"])]
+    #[case::repeated_point(&[(0.0, 0.0), (1.0, 0.0), (1.0, 0.0), (0.0, 1.0)], vec!["/polygonPoints/2"])]
+    #[case::bow_tie(&[(0.0, 0.0), (1.0, 1.0), (1.0, 0.0), (0.0, 1.0)], vec!["/polygonPoints/0"])]
+    #[case::touching(&[(0.0, 0.0), (2.0, 0.0), (1.0, 0.0), (1.0, 1.0)], vec!["/polygonPoints/0"])]
+    fn test_Envelopes2d_validate(#[case] points: &[(f32, f32)], #[case] expected: Vec<&str>) {
+        let envelope = Envelopes2d {
+            set: "default".into(),
+            polygon_points: points.iter().map(|(x, y)| PolygonPoint { x: checked(*x), y: checked(*y) }).collect(),
+            description: None,
+        };
+
+        let violations = envelope.validate();
+
+        assert_that!(violations.iter().map(|violation| violation.path.as_str()).collect::<Vec<_>>(), eq(&expected));
+        assert_that!(violations.iter().all(|violation| violation.rule == RULE_INVALID_POLYGON), eq(true));
+    }
+
+    #[rstest]
+    #[case::drive(WheelType::Drive, None, 0.2, 0.1, vec![])]
+    #[case::fixed(WheelType::Fixed, Some(0.0), 0.2, 0.1, vec![])]
+    #[case::fixed_without_theta(WheelType::Fixed, None, 0.2, 0.1, vec![("/position/theta", RULE_MISSING_FIELD)])]
+    #[case::dimensions(WheelType::Caster, None, 0.0, -0.1, vec![("/diameter", RULE_OUT_OF_RANGE), ("/width", RULE_OUT_OF_RANGE)])]
+    fn test_WheelDefinition_validate(#[case] wheel_type: WheelType, #[case] theta: Option<f32>, #[case] diameter: f32, #[case] width: f32, #[case] expected: Vec<(&str, &str)>) {
+        let violations = wheel(wheel_type, false, theta, diameter, width).validate();
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&expected));
+    }
+
+    #[rstest]
+    #[case::driven(vec![wheel(WheelType::Drive, true, None, 0.2, 0.1), wheel(WheelType::Caster, false, None, 0.1, 0.1)], vec![])]
+    #[case::no_wheels(vec![], vec![])]
+    #[case::not_driven(vec![wheel(WheelType::Caster, false, None, 0.1, 0.1)], vec![("/agvGeometry/wheelDefinitions", RULE_NO_DRIVEN_WHEEL)])]
+    fn test_Factsheet_validate_wheel_definitions(#[case] wheel_definitions: Vec<WheelDefinition>, #[case] expected: Vec<(&str, &str)>) {
+        let factsheet = Factsheet {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            type_specification: None,
+            physical_parameters: None,
+            protocol_limits: None,
+            protocol_features: None,
+            agv_geometry: Some(AgvGeometry { wheel_definitions, envelopes2d: vec![], envelopes3d: vec![] }),
+            load_specification: None,
+            localization_parameters: None,
+        };
+
+        let violations = factsheet.validate();
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&expected));
+    }
+
+    #[rstest]
+    fn test_InstantActions_validate_accepts_valid_message() {
+        assert_that!(instant_actions(vec![action("a1")]).validate(), empty());
+    }
+
+    fn instant_actions(actions: Vec<Action>) -> InstantActions {
+        InstantActions {
+            header_id: 1,
+            timestamp: from_unix_millis(0).unwrap(),
+            version: "2.0.0".into(),
+            manufacturer: "Fubar Co.".into(),
+            serial_number: "1234".into(),
+            instant_actions: actions,
+        }
+    }
+
+    #[rstest]
+    fn test_InstantActions_push_checked() {
+        let mut limits = protocol_limits();
+        limits.max_array_lens.instant_actions = 1;
+        let mut instant_actions = InstantActions::single(Header::new("Fubar Co.", "1234", 1, from_unix_millis(0).unwrap()), action("a1"));
+
+        assert_that!(instant_actions.push_checked(action("a2"), &limits), err(elements_are![
+            matches_pattern!(Violation { path: eq("/instantActions"), rule: eq(&RULE_TOO_MANY_ELEMENTS) })
+        ]));
+        assert_that!(instant_actions.instant_actions, len(eq(1)));
+        assert_that!(instant_actions.push_checked(action("a2"), &protocol_limits()), ok(anything()));
+        assert_that!(instant_actions.instant_actions, len(eq(2)));
+    }
+
+    #[rstest]
+    fn test_InstantActions_check_limits_accepts_unlimited() {
+        assert_that!(instant_actions(vec![action("a1")]).check_limits(&protocol_limits()), empty());
+    }
+
+    #[rstest]
+    fn test_InstantActions_check_limits_reports_violations() {
+        let mut instant_actions = instant_actions(vec![action("1"), action("a2"), action("333")]);
+        instant_actions.instant_actions[0].action_parameters.push(ActionParameter { key: "stationType".into(), value: ActionParameterValue::Boolean(true) });
+        let mut limits = protocol_limits();
+        limits.max_array_lens.instant_actions = 2;
+        limits.max_string_lens.id_len = Some(2);
+        limits.max_string_lens.id_numerical_only = Some(true);
+        limits.max_string_lens.enum_len = Some(4);
+
+        let violations = instant_actions.check_limits(&limits);
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![
+            ("/instantActions", RULE_TOO_MANY_ELEMENTS),
+            ("/instantActions/0/actionParameters/0/key", RULE_TOO_LONG),
+            ("/instantActions/1/actionId", RULE_NOT_NUMERICAL),
+            ("/instantActions/2/actionId", RULE_TOO_LONG),
+        ]));
+    }
+
+    #[cfg(feature = "json")]
+    #[rstest]
+    fn test_InstantActions_check_limits_reports_too_long_message() {
+        let mut limits = protocol_limits();
+        limits.max_string_lens.msg_len = Some(64);
+
+        let violations = instant_actions(vec![action("a1")]).check_limits(&limits);
+
+        assert_that!(violations, elements_are![matches_pattern!(Violation { path: eq(""), rule: eq(&RULE_TOO_LONG) })]);
+    }
+
+    #[rstest]
+    fn test_InstantActions_check_actions() {
+        let features = ProtocolFeatures {
+            optional_parameters: vec![],
+            agv_actions: vec![
+                AgvAction { action_type: "pick".into(), action_description: None, action_scopes: vec![ActionScope::Node], action_parameters: vec![], result_description: None },
+                AgvAction {
+                    action_type: "cancelOrder".into(),
+                    action_description: None,
+                    action_scopes: vec![ActionScope::Instant],
+                    action_parameters: vec![],
+                    result_description: None,
+                },
+            ],
+        };
+        let instant_actions = instant_actions(vec![
+            action("a1"),
+            Action { action_type: "cancelOrder".into(), ..action("a2") },
+            Action { action_type: "drop".into(), ..action("a3") },
+        ]);
+
+        let violations = instant_actions.check_actions(&features);
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![
+            ("/instantActions/0/actionType", RULE_ACTION_SCOPE),
+            ("/instantActions/2/actionType", RULE_UNSUPPORTED_ACTION),
+        ]));
+    }
+
+    #[cfg(not(feature = "checked-types"))]
+    #[rstest]
+    #[case::valid(state(80.0, Some(100)), vec![])]
+    #[case::battery_charge(state(100.5, None), vec!["/batteryState/batteryCharge"])]
+    #[case::battery_health(state(-1.0, Some(101)), vec!["/batteryState/batteryCharge", "/batteryState/batteryHealth"])]
+    fn test_State_validate_battery_state(#[case] state: State, #[case] expected: Vec<&str>) {
+        let violations = state.validate();
+
+        assert_that!(violations.iter().map(|violation| violation.path.as_str()).collect::<Vec<_>>(), eq(&expected));
+        assert_that!(violations.iter().all(|violation| violation.rule == RULE_OUT_OF_RANGE), eq(true));
+    }
+
+    #[cfg(not(feature = "checked-types"))]
+    #[rstest]
+    #[case::valid(Some(1.0), Some(0.0), vec![])]
+    #[case::absent(None, None, vec![])]
+    #[case::localization_score(Some(1.5), None, vec!["/agvPosition/localizationScore"])]
+    #[case::deviation_range(Some(0.0), Some(-0.1), vec!["/agvPosition/deviationRange"])]
+    fn test_State_validate_agv_position(#[case] localization_score: Option<f32>, #[case] deviation_range: Option<f32>, #[case] expected: Vec<&str>) {
+        let mut state = state(80.0, None);
+        state.agv_position = Some(crate::common::AgvPosition {
+            x: checked(0.0),
+            y: checked(0.0),
+            theta: checked(0.0),
+            map_id: "map".into(),
+            map_description: None,
+            position_initialized: true,
+            localization_score: localization_score.map(checked),
+            deviation_range: deviation_range.map(checked),
+        });
+
+        let violations = state.validate();
+
+        assert_that!(violations.iter().map(|violation| violation.path.as_str()).collect::<Vec<_>>(), eq(&expected));
+        assert_that!(violations.iter().all(|violation| violation.rule == RULE_OUT_OF_RANGE), eq(true));
+    }
+
+    #[rstest]
+    fn test_State_validate_reports_inconsistencies() {
+        let mut state = state(80.0, None);
+        state.last_node_id = "n2".into();
+        state.last_node_sequence_id = 2;
+        state.node_states = vec![node_state("n2", 2), node_state("n3", 4), node_state("n3", 4)];
+        state.edge_states = vec![edge_state("e2", 3), edge_state("e3", 3)];
+        state.action_states = vec![action_state("a1"), action_state("a2"), action_state("a1")];
+        state.driving = true;
+        state.safety_state.e_stop = EStop::Manual;
+
+        let violations = state.validate();
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![
+            ("/nodeStates/0/sequenceId", RULE_INCONSISTENT),
+            ("/nodeStates/2/sequenceId", RULE_DUPLICATE_ID),
+            ("/edgeStates/1/sequenceId", RULE_DUPLICATE_ID),
+            ("/actionStates/2/actionId", RULE_DUPLICATE_ID),
+            ("/driving", RULE_INCONSISTENT),
+        ]));
+    }
+
+    #[rstest]
+    fn test_State_validate_accepts_consistent_state() {
+        let mut state = state(80.0, None);
+        state.last_node_id = "n1".into();
+        state.last_node_sequence_id = 0;
+        state.node_states = vec![node_state("n2", 2), node_state("n3", 4)];
+        state.edge_states = vec![edge_state("e1", 1), edge_state("e2", 3)];
+        state.action_states = vec![action_state("a1"), action_state("a2")];
+        state.driving = true;
+
+        assert_that!(state.validate(), empty());
+    }
+
+    #[rstest]
+    #[case::same(Some("z1"), Some("z1"), vec![])]
+    #[case::order_without_zones(Some("z1"), None, vec![])]
+    #[case::different(Some("z1"), Some("z2"), vec![("/zoneSetId", RULE_INCONSISTENT)])]
+    #[case::state_without_zones(None, Some("z1"), vec![("/zoneSetId", RULE_INCONSISTENT)])]
+    fn test_State_validate_against_order_zone_set(#[case] state_zone_set_id: Option<&str>, #[case] order_zone_set_id: Option<&str>, #[case] expected: Vec<(&str, &str)>) {
+        let mut state = state(80.0, None);
+        state.zone_set_id = state_zone_set_id.map(From::from);
+        let mut order = order(vec![node("n1", 0, vec![])], vec![]);
+        order.zone_set_id = order_zone_set_id.map(From::from);
+
+        let violations = state.validate_against_order(&order);
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&expected));
+    }
+
+    #[rstest]
+    fn test_State_validate_against_order_reports_desynchronized_states() {
+        let order = order(vec![node("n1", 0, vec![action("a1")]), node("n2", 2, vec![action("a2")]), node("n3", 4, vec![])], vec![edge("e1", 1, "n1", "n2"), edge("e2", 3, "n2", "n3")]);
+        let mut state = state(80.0, None);
+        state.order_id = order.order_id.clone();
+        state.node_states = vec![node_state("n2", 2), node_state("n4", 4), node_state("n5", 6)];
+        state.edge_states = vec![edge_state("e1", 1), edge_state("e3", 3)];
+        state.action_states = vec![action_state("a2")];
+
+        let violations = state.validate_against_order(&order);
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&vec![
+            ("/nodeStates/1/nodeId", RULE_INCONSISTENT),
+            ("/nodeStates/2/sequenceId", RULE_INCONSISTENT),
+            ("/edgeStates/1/edgeId", RULE_INCONSISTENT),
+            ("/actionStates", RULE_INCONSISTENT),
+        ]));
+    }
+
+    #[rstest]
+    #[case::other_order("o2", 0)]
+    #[case::other_order_update("o1", 1)]
+    fn test_State_validate_against_order_ignores_states_of_other_orders(#[case] order_id: &str, #[case] order_update_id: u64) {
+        let order = order(vec![node("n1", 0, vec![action("a1")])], vec![]);
+        let mut state = state(80.0, None);
+        state.order_id = order_id.into();
+        state.order_update_id = order_update_id;
+        state.node_states = vec![node_state("n4", 4)];
+
+        assert_that!(state.validate_against_order(&order), empty());
+    }
+
+    #[rstest]
+    #[case::valid("2.0.0", "Fubar Co.", "1234", 0, vec![])]
+    #[case::pre_release("2.1.0-rc.1+build.5", "Fubar Co.", "1234", 0, vec![])]
+    #[case::version_too_short("2.0", "Fubar Co.", "1234", 0, vec![("/version", RULE_INVALID_VERSION)])]
+    #[case::version_leading_zero("2.01.0", "Fubar Co.", "1234", 0, vec![("/version", RULE_INVALID_VERSION)])]
+    #[case::version_empty_pre_release("2.0.0-", "Fubar Co.", "1234", 0, vec![("/version", RULE_INVALID_VERSION)])]
+    #[case::empty_manufacturer("2.0.0", "", "1234", 0, vec![("/manufacturer", RULE_EMPTY_ID)])]
+    #[case::topic_unsafe("2.0.0", "Fubar/Co.", "#1", 0, vec![("/manufacturer", RULE_TOPIC_UNSAFE), ("/serialNumber", RULE_TOPIC_UNSAFE)])]
+    #[case::slightly_ahead("2.0.0", "Fubar Co.", "1234", MAX_TIMESTAMP_AHEAD_MILLIS, vec![])]
+    #[case::far_ahead("2.0.0", "Fubar Co.", "1234", MAX_TIMESTAMP_AHEAD_MILLIS + 1, vec![("/timestamp", RULE_FUTURE_TIMESTAMP)])]
+    fn test_validate_header(#[case] version: &str, #[case] manufacturer: &str, #[case] serial_number: &str, #[case] ahead: i64, #[case] expected: Vec<(&str, &str)>) {
+        let now = from_unix_millis(1_492_256_403_120).unwrap();
+        let timestamp = from_unix_millis(1_492_256_403_120 + ahead).unwrap();
+
+        let violations = validate_header(version, manufacturer, serial_number, &timestamp, &now);
+
+        assert_that!(violations.iter().map(|violation| (violation.path.as_str(), violation.rule)).collect::<Vec<_>>(), eq(&expected));
+    }
+
+    #[rstest]
+    fn test_Violation_display() {
+        let violation = Violation { path: "/orderId".into(), rule: RULE_EMPTY_ID, message: "must not be empty".into() };
+
+        assert_that!(violation.to_string(), eq("/orderId: must not be empty (empty-id)"));
+    }
+}
@@ -0,0 +1,99 @@
+//!
+//! Speed of an AGV derived from its velocity, and scaling and limiting of velocities.
+//!
+use crate::checked::Raw;
+use crate::common::Velocity;
+
+/// Returns the velocity component scaled by `factor`, or the unchanged component if the result is not finite.
+fn scale_field<T: Raw>(component: T, factor: f32) -> T {
+    T::try_from_raw(component.raw() * factor).unwrap_or(component)
+}
+
+impl Velocity {
+
+    /// Returns the translational speed, i.e. the magnitude of `vx` and `vy`, where an unknown component is
+    /// considered to be zero.
+    pub fn speed(&self) -> f32 {
+        let vx = self.vx.map_or(0.0, Raw::raw);
+        let vy = self.vy.map_or(0.0, Raw::raw);
+        libm::hypotf(vx, vy)
+    }
+
+    /// Returns `true` if neither the translational speed nor the absolute turning speed exceed `epsilon`.
+    pub fn is_stationary(&self, epsilon: f32) -> bool {
+        self.speed() <= epsilon && self.omega.map_or(0.0, |omega| libm::fabsf(omega.raw())) <= epsilon
+    }
+
+    /// Returns the velocity with all known components multiplied by `factor`.
+    pub fn scaled(&self, factor: f32) -> Velocity {
+        Velocity {
+            vx: self.vx.map(|vx| scale_field(vx, factor)),
+            vy: self.vy.map(|vy| scale_field(vy, factor)),
+            omega: self.omega.map(|omega| scale_field(omega, factor)),
+        }
+    }
+
+    /// Returns the velocity with its translational speed reduced to `max_speed` if it exceeds `max_speed`, keeping
+    /// the direction of travel and the turning speed.
+    pub fn limited(&self, max_speed: f32) -> Velocity {
+        let speed = self.speed();
+        if speed <= max_speed {
+            return self.clone();
+        }
+        let factor = max_speed.max(0.0) / speed;
+        Velocity {
+            vx: self.vx.map(|vx| scale_field(vx, factor)),
+            vy: self.vy.map(|vy| scale_field(vy, factor)),
+            omega: self.omega,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::{checked, Raw};
+    use crate::common::Velocity;
+
+    fn velocity(vx: Option<f32>, vy: Option<f32>, omega: Option<f32>) -> Velocity {
+        Velocity { vx: vx.map(checked), vy: vy.map(checked), omega: omega.map(checked) }
+    }
+
+    fn components(velocity: &Velocity) -> (Option<f32>, Option<f32>, Option<f32>) {
+        (velocity.vx.map(Raw::raw), velocity.vy.map(Raw::raw), velocity.omega.map(Raw::raw))
+    }
+
+    #[rstest]
+    #[case::unknown(velocity(None, None, None), 0.0)]
+    #[case::forward(velocity(Some(2.0), None, None), 2.0)]
+    #[case::diagonal(velocity(Some(3.0), Some(-4.0), Some(1.0)), 5.0)]
+    fn test_Velocity_speed(#[case] velocity: Velocity, #[case] expected: f32) {
+        assert_that!(velocity.speed(), eq(expected));
+    }
+
+    #[rstest]
+    #[case::unknown(velocity(None, None, None), true)]
+    #[case::creeping(velocity(Some(0.005), Some(0.005), Some(-0.005)), true)]
+    #[case::driving(velocity(Some(0.5), None, None), false)]
+    #[case::turning(velocity(Some(0.0), Some(0.0), Some(-0.5)), false)]
+    fn test_Velocity_is_stationary(#[case] velocity: Velocity, #[case] expected: bool) {
+        assert_that!(velocity.is_stationary(0.01), eq(expected));
+    }
+
+    #[rstest]
+    fn test_Velocity_scaled() {
+        let velocity = velocity(Some(1.0), None, Some(-0.5)).scaled(2.0);
+
+        assert_that!(components(&velocity), eq((Some(2.0), None, Some(-1.0))));
+    }
+
+    #[rstest]
+    #[case::below_limit(velocity(Some(1.0), Some(1.0), Some(1.0)), (Some(1.0), Some(1.0), Some(1.0)))]
+    #[case::above_limit(velocity(Some(6.0), Some(-8.0), Some(1.0)), (Some(3.0), Some(-4.0), Some(1.0)))]
+    fn test_Velocity_limited(#[case] velocity: Velocity, #[case] expected: (Option<f32>, Option<f32>, Option<f32>)) {
+        assert_that!(components(&velocity.limited(5.0)), eq(expected));
+    }
+}
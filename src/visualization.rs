@@ -1,16 +1,24 @@
 use alloc::string::String;
 use crate::common::{AgvPosition, HeaderId, Timestamp, Velocity};
+use crate::header::AgvIdentity;
 
 /// AGV position and/or velocity for visualization purposes. Can be published at a higher rate if wanted. Since bandwidth may be expensive depening on the update rate for this topic, all fields are optional.
-#[cfg_attr(feature = "fmt", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(any(test, feature = "fmt"), derive(Debug))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[cfg_attr(feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
 pub struct Visualization {
     /// header_id of the message. The header_id is defined per topic and incremented by 1 with each sent (but not necessarily received) message.
+    #[cfg_attr(feature = "ts-rs", ts(as = "f64"))]
     pub header_id: HeaderId,
     /// Timestamp (ISO8601, UTC); YYYY-MM-DDTHH:mm:ss.ssZ; e.g. 2017-04-15T11:40:03.12Z
+    #[cfg_attr(feature = "defmt", defmt(Display2Format))]
+    #[cfg_attr(feature = "serde", serde(with = "crate::timestamp"))]
+    #[cfg_attr(feature = "ts-rs", ts(as = "String"))]
     pub timestamp: Timestamp,
     /// Version of the protocol [Major].[Minor].[Patch], e.g. 1.3.2
     pub version: String,
@@ -23,3 +31,57 @@ pub struct Visualization {
     /// The AGVs velocity in vehicle coordinates.
     pub velocity: Option<Velocity>
 }
+
+impl Visualization {
+
+    /// Returns the message `header_id` of the AGV `identity` at `agv_position` moving with `velocity`.
+    pub fn from_pose(identity: &AgvIdentity, header_id: HeaderId, timestamp: Timestamp, agv_position: AgvPosition, velocity: Option<Velocity>) -> Self {
+        let header = identity.header(header_id, timestamp);
+        Visualization {
+            header_id: header.header_id,
+            timestamp: header.timestamp,
+            version: header.version,
+            manufacturer: header.manufacturer,
+            serial_number: header.serial_number,
+            agv_position: Some(agv_position),
+            velocity,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use googletest::prelude::*;
+    use rstest::rstest;
+
+    use crate::checked::checked;
+    use crate::common::AgvPosition;
+    use crate::header::AgvIdentity;
+    use crate::timestamp::from_unix_millis;
+    use super::Visualization;
+
+    #[rstest]
+    fn test_Visualization_from_pose() {
+        let agv_position = AgvPosition {
+            x: checked(1.0),
+            y: checked(2.0),
+            theta: checked(0.5),
+            map_id: "map".into(),
+            map_description: None,
+            position_initialized: true,
+            localization_score: None,
+            deviation_range: None,
+        };
+
+        let visualization = Visualization::from_pose(&AgvIdentity::new("Fubar Co.", "1234"), 3, from_unix_millis(0).unwrap(), agv_position, None);
+
+        assert_that!(visualization, matches_pattern!(Visualization {
+            header_id: eq(&3),
+            manufacturer: eq("Fubar Co."),
+            serial_number: eq("1234"),
+            agv_position: some(matches_pattern!(AgvPosition { map_id: eq("map") })),
+            velocity: none()
+        }));
+    }
+}
@@ -0,0 +1,45 @@
+//!
+//! JavaScript bindings for decoding and encoding messages in the browser via wasm-bindgen.
+//!
+use alloc::string::String;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::state::State;
+use crate::visualization::Visualization;
+
+fn decode<T: DeserializeOwned + Serialize>(json: &str) -> Result<JsValue, JsError> {
+    let message = serde_json::from_str::<T>(json)?;
+    Ok(message.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+}
+
+fn encode<T: DeserializeOwned + Serialize>(value: JsValue) -> Result<String, JsError> {
+    let message = serde_wasm_bindgen::from_value::<T>(value)?;
+    Ok(serde_json::to_string(&message)?)
+}
+
+/// Decodes a state from JSON into a plain JavaScript object.
+#[wasm_bindgen(js_name = decodeState)]
+pub fn decode_state(json: &str) -> Result<JsValue, JsError> {
+    decode::<State>(json)
+}
+
+/// Encodes a state given as plain JavaScript object into JSON.
+#[wasm_bindgen(js_name = encodeState)]
+pub fn encode_state(value: JsValue) -> Result<String, JsError> {
+    encode::<State>(value)
+}
+
+/// Decodes a visualization message from JSON into a plain JavaScript object.
+#[wasm_bindgen(js_name = decodeVisualization)]
+pub fn decode_visualization(json: &str) -> Result<JsValue, JsError> {
+    decode::<Visualization>(json)
+}
+
+/// Encodes a visualization message given as plain JavaScript object into JSON.
+#[wasm_bindgen(js_name = encodeVisualization)]
+pub fn encode_visualization(value: JsValue) -> Result<String, JsError> {
+    encode::<Visualization>(value)
+}